@@ -0,0 +1,51 @@
+// Micro-benchmark for the sharded internal ledger. Run `npm run build`
+// first, then `node bench-balances.js`. Verifies that getBalances stays
+// O(tokens held by the user) regardless of how many other users exist, and
+// that hot-path credit/debit throughput holds up with a wide account map.
+const { ConfigService } = require('@nestjs/config');
+const { TokensService } = require('./dist/tokens/tokens.service');
+const { BalancesService } = require('./dist/balances/balances.service');
+
+const USERS = Number(process.env.BENCH_USERS || 10_000);
+const TOKENS_PER_USER = Number(process.env.BENCH_TOKENS || 5);
+const HOT_OPS = Number(process.env.BENCH_OPS || 100_000);
+
+function time(label, fn) {
+  const start = process.hrtime.bigint();
+  const result = fn();
+  const elapsedMs = Number(process.hrtime.bigint() - start) / 1e6;
+  console.log(`${label}: ${elapsedMs.toFixed(2)}ms${result !== undefined ? ` (${result})` : ''}`);
+}
+
+function main() {
+  const balances = new BalancesService(new TokensService(new ConfigService({})));
+
+  time(`seed ${USERS} users x ${TOKENS_PER_USER} tokens`, () => {
+    for (let u = 0; u < USERS; u += 1) {
+      for (let t = 0; t < TOKENS_PER_USER; t += 1) {
+        balances.credit(`user-${u}`, `token-${t}`, 1000);
+      }
+    }
+  });
+
+  // list_balances for one user must not scale with total user count.
+  time(`getBalances for one user (${USERS} users in map)`, () => {
+    let entries = 0;
+    for (let i = 0; i < 1000; i += 1) {
+      entries = balances.getBalances(`user-${i % USERS}`).length;
+    }
+    return `${entries} tokens`;
+  });
+
+  // Hot-path mutations against a single busy user.
+  time(`${HOT_OPS} credit/debit ops on one user`, () => {
+    for (let i = 0; i < HOT_OPS; i += 1) {
+      balances.credit('user-0', 'token-0', 1);
+      balances.debit('user-0', 'token-0', 1);
+    }
+  });
+
+  time('full snapshot()', () => `${balances.snapshot().length} entries`);
+}
+
+main();
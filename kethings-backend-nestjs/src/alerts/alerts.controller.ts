@@ -0,0 +1,34 @@
+import { Body, Controller, Delete, Get, HttpCode, Param, Post, Query } from '@nestjs/common';
+
+import { AlertsService } from './alerts.service';
+import { NotificationsService } from '../notifications/notifications.service';
+import { CreateAlertDto } from './dto/create-alert.dto';
+
+@Controller('alerts')
+export class AlertsController {
+  constructor(
+    private readonly alerts: AlertsService,
+    private readonly notifications: NotificationsService,
+  ) {}
+
+  @Get()
+  list(@Query('user_address') userAddress: string) {
+    return { alerts: this.alerts.listAlerts(userAddress) };
+  }
+
+  @Post()
+  create(@Body() body: CreateAlertDto) {
+    return this.alerts.createAlert(body);
+  }
+
+  @Delete(':alertId')
+  @HttpCode(204)
+  remove(@Param('alertId') alertId: string, @Query('user_address') userAddress: string) {
+    this.alerts.deleteAlert(userAddress, alertId);
+  }
+
+  @Get('notifications/feed')
+  feed(@Query('user_address') userAddress: string, @Query('limit') limit?: string) {
+    return { notifications: this.notifications.list(userAddress, limit ? Number(limit) : undefined) };
+  }
+}
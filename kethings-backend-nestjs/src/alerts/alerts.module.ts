@@ -0,0 +1,21 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+import { AlertsService } from './alerts.service';
+import { AlertsController } from './alerts.controller';
+import { BotHooksService } from './bot-hooks.service';
+import { BotHooksController } from './bot-hooks.controller';
+import { OpsHooksService } from './ops-hooks.service';
+import { OpsHooksController } from './ops-hooks.controller';
+import { AdminGuard } from '../common/admin.guard';
+import { EngineModule } from '../engine/engine.module';
+import { PoolsModule } from '../pools/pools.module';
+import { NotificationsModule } from '../notifications/notifications.module';
+import { BalancesModule } from '../balances/balances.module';
+
+@Module({
+  imports: [ConfigModule, EngineModule, PoolsModule, NotificationsModule, BalancesModule],
+  providers: [AlertsService, BotHooksService, OpsHooksService, AdminGuard],
+  controllers: [AlertsController, BotHooksController, OpsHooksController],
+  exports: [OpsHooksService],
+})
+export class AlertsModule {}
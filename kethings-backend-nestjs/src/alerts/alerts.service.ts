@@ -0,0 +1,118 @@
+import { BadRequestException, Injectable, Logger, NotFoundException, OnModuleDestroy, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { randomUUID } from 'crypto';
+
+import { EngineService } from '../engine/engine.service';
+import { PoolsService } from '../pools/pools.service';
+import { NotificationsService } from '../notifications/notifications.service';
+
+export type AlertSource = 'pool_spot' | 'market_last';
+export type AlertDirection = 'above' | 'below';
+
+export interface PriceAlert {
+  id: string;
+  user_address: string;
+  source: AlertSource;
+  /** Pool id for pool_spot alerts; market pair for market_last alerts. */
+  pool_id?: string;
+  market?: string;
+  direction: AlertDirection;
+  threshold: number;
+  created_at: string;
+  triggered_at?: string;
+}
+
+const DEFAULT_MAX_ALERTS_PER_USER = 20;
+const DEFAULT_EVALUATION_INTERVAL_MS = 5_000;
+
+@Injectable()
+export class AlertsService implements OnModuleInit, OnModuleDestroy {
+  private readonly logger = new Logger(AlertsService.name);
+  private readonly alerts = new Map<string, PriceAlert>();
+  private timer?: ReturnType<typeof setInterval>;
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly engine: EngineService,
+    private readonly pools: PoolsService,
+    private readonly notifications: NotificationsService,
+  ) {}
+
+  onModuleInit(): void {
+    const intervalMs = Number(this.config.get<string>('ALERT_EVALUATION_INTERVAL_MS')) || DEFAULT_EVALUATION_INTERVAL_MS;
+    this.timer = setInterval(() => this.evaluate(), intervalMs);
+  }
+
+  onModuleDestroy(): void {
+    if (this.timer) {
+      clearInterval(this.timer);
+    }
+  }
+
+  listAlerts(userAddress: string): PriceAlert[] {
+    return Array.from(this.alerts.values()).filter((alert) => alert.user_address === userAddress);
+  }
+
+  createAlert(input: Omit<PriceAlert, 'id' | 'created_at' | 'triggered_at'>): PriceAlert {
+    if (input.source === 'pool_spot' && !input.pool_id) {
+      throw new BadRequestException('pool_id is required for pool_spot alerts');
+    }
+    if (input.source === 'market_last' && !input.market) {
+      throw new BadRequestException('market is required for market_last alerts');
+    }
+
+    const maxPerUser = Number(this.config.get<string>('ALERTS_MAX_PER_USER')) || DEFAULT_MAX_ALERTS_PER_USER;
+    if (this.listAlerts(input.user_address).length >= maxPerUser) {
+      throw new BadRequestException(`Alert limit of ${maxPerUser} per user reached`);
+    }
+
+    const alert: PriceAlert = {
+      ...input,
+      id: randomUUID(),
+      created_at: new Date().toISOString(),
+    };
+    this.alerts.set(alert.id, alert);
+    return alert;
+  }
+
+  deleteAlert(userAddress: string, alertId: string): void {
+    const alert = this.alerts.get(alertId);
+    if (!alert || alert.user_address !== userAddress) {
+      throw new NotFoundException(`Alert ${alertId} not found`);
+    }
+    this.alerts.delete(alertId);
+  }
+
+  /** Evaluate all pending alerts against current prices; one-shot delivery. */
+  evaluate(): void {
+    for (const alert of this.alerts.values()) {
+      if (alert.triggered_at) continue;
+
+      const price = this.currentPrice(alert);
+      if (price === undefined) continue;
+
+      const crossed = alert.direction === 'above' ? price >= alert.threshold : price <= alert.threshold;
+      if (!crossed) continue;
+
+      alert.triggered_at = new Date().toISOString();
+      const subject = alert.source === 'pool_spot' ? `pool ${alert.pool_id}` : `market ${alert.market}`;
+      this.notifications.push(alert.user_address, 'price_alert', `Price for ${subject} crossed ${alert.direction} ${alert.threshold} (now ${price})`, {
+        alert_id: alert.id,
+        price,
+      });
+      this.logger.log(`Alert ${alert.id} triggered for ${alert.user_address} at price ${price}`);
+    }
+  }
+
+  private currentPrice(alert: PriceAlert): number | undefined {
+    if (alert.source === 'market_last') {
+      return this.engine.getLastPrice(alert.market!);
+    }
+    try {
+      const pool = this.pools.getPool(alert.pool_id!);
+      return pool.reserveA > 0 ? pool.reserveB / pool.reserveA : undefined;
+    } catch {
+      return undefined;
+    }
+  }
+}
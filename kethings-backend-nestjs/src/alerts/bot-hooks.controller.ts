@@ -0,0 +1,31 @@
+import { Body, Controller, Delete, Get, HttpCode, Param, Post, Query } from '@nestjs/common';
+
+import { BotHooksService } from './bot-hooks.service';
+import { CreateBotHookDto } from './dto/create-bot-hook.dto';
+
+@Controller('bot-hooks')
+export class BotHooksController {
+  constructor(private readonly botHooks: BotHooksService) {}
+
+  @Get()
+  list(@Query('user_address') userAddress: string) {
+    return { hooks: this.botHooks.listHooks(userAddress) };
+  }
+
+  @Post()
+  create(@Body() body: CreateBotHookDto) {
+    const { secret, ...input } = body;
+    return this.botHooks.createHook(input, secret);
+  }
+
+  @Delete(':hookId')
+  @HttpCode(204)
+  remove(@Param('hookId') hookId: string, @Query('user_address') userAddress: string) {
+    this.botHooks.deleteHook(userAddress, hookId);
+  }
+
+  @Get(':hookId/deliveries')
+  deliveries(@Param('hookId') hookId: string) {
+    return { deliveries: this.botHooks.deliveryLog(hookId) };
+  }
+}
@@ -0,0 +1,211 @@
+import { BadRequestException, Injectable, Logger, NotFoundException, OnModuleDestroy, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { createHmac, randomUUID } from 'crypto';
+
+import { BalancesService } from '../balances/balances.service';
+import { EngineService } from '../engine/engine.service';
+import { PoolsService } from '../pools/pools.service';
+
+export type BotTriggerType = 'price_cross' | 'balance_change';
+export type CrossDirection = 'above' | 'below';
+
+export interface BotHook {
+  id: string;
+  user_address: string;
+  trigger: BotTriggerType;
+  /** price_cross fields */
+  market?: string;
+  pool_id?: string;
+  direction?: CrossDirection;
+  threshold?: number;
+  /** balance_change field */
+  token?: string;
+  url: string;
+  created_at: string;
+  last_fired_at?: string;
+}
+
+interface HookDelivery {
+  hook_id: string;
+  event: string;
+  status: 'delivered' | 'failed';
+  status_code?: number;
+  error?: string;
+  at: string;
+}
+
+const DEFAULT_EVALUATION_INTERVAL_MS = 5_000;
+const DEFAULT_MAX_HOOKS_PER_USER = 10;
+const MAX_DELIVERY_LOG = 200;
+
+/**
+ * Paper-trading automation hooks: users register a condition and a webhook
+ * URL, and the backend fires a signed callback when it hits. Price-cross
+ * conditions re-arm once the price returns to the other side of the
+ * threshold; balance-change conditions fire on any delta for the watched
+ * token. Payloads are HMAC-signed with the per-hook secret so bots can
+ * authenticate the source without polling.
+ */
+@Injectable()
+export class BotHooksService implements OnModuleInit, OnModuleDestroy {
+  private readonly logger = new Logger(BotHooksService.name);
+  private readonly hooks = new Map<string, BotHook>();
+  private readonly secrets = new Map<string, string>();
+  /** Arm state for price_cross; last seen balance for balance_change. */
+  private readonly state = new Map<string, number | boolean>();
+  private readonly deliveries: HookDelivery[] = [];
+  private timer?: ReturnType<typeof setInterval>;
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly engine: EngineService,
+    private readonly pools: PoolsService,
+    private readonly balances: BalancesService,
+  ) {}
+
+  onModuleInit(): void {
+    const intervalMs = Number(this.config.get<string>('BOT_HOOK_EVALUATION_INTERVAL_MS')) || DEFAULT_EVALUATION_INTERVAL_MS;
+    this.timer = setInterval(() => this.evaluate(), intervalMs);
+  }
+
+  onModuleDestroy(): void {
+    if (this.timer) {
+      clearInterval(this.timer);
+    }
+  }
+
+  listHooks(userAddress: string): BotHook[] {
+    return Array.from(this.hooks.values()).filter((hook) => hook.user_address === userAddress);
+  }
+
+  createHook(input: Omit<BotHook, 'id' | 'created_at' | 'last_fired_at'>, secret: string): BotHook {
+    if (input.trigger === 'price_cross') {
+      if (!input.market && !input.pool_id) {
+        throw new BadRequestException('market or pool_id is required for price_cross hooks');
+      }
+      if (input.direction === undefined || input.threshold === undefined) {
+        throw new BadRequestException('direction and threshold are required for price_cross hooks');
+      }
+    }
+    if (input.trigger === 'balance_change' && !input.token) {
+      throw new BadRequestException('token is required for balance_change hooks');
+    }
+
+    const maxPerUser = Number(this.config.get<string>('BOT_HOOKS_MAX_PER_USER')) || DEFAULT_MAX_HOOKS_PER_USER;
+    if (this.listHooks(input.user_address).length >= maxPerUser) {
+      throw new BadRequestException(`Bot hook limit of ${maxPerUser} per user reached`);
+    }
+
+    const hook: BotHook = {
+      ...input,
+      id: randomUUID(),
+      created_at: new Date().toISOString(),
+    };
+    this.hooks.set(hook.id, hook);
+    this.secrets.set(hook.id, secret);
+    return hook;
+  }
+
+  deleteHook(userAddress: string, hookId: string): void {
+    const hook = this.hooks.get(hookId);
+    if (!hook || hook.user_address !== userAddress) {
+      throw new NotFoundException(`Bot hook ${hookId} not found`);
+    }
+    this.hooks.delete(hookId);
+    this.secrets.delete(hookId);
+    this.state.delete(hookId);
+  }
+
+  deliveryLog(hookId: string): HookDelivery[] {
+    return this.deliveries.filter((delivery) => delivery.hook_id === hookId);
+  }
+
+  evaluate(): void {
+    for (const hook of this.hooks.values()) {
+      if (hook.trigger === 'price_cross') {
+        this.evaluatePriceCross(hook);
+      } else {
+        this.evaluateBalanceChange(hook);
+      }
+    }
+  }
+
+  private evaluatePriceCross(hook: BotHook): void {
+    const price = this.currentPrice(hook);
+    if (price === undefined) return;
+
+    const crossed = hook.direction === 'above' ? price >= hook.threshold! : price <= hook.threshold!;
+    const armed = this.state.get(hook.id) !== false;
+    if (crossed && armed) {
+      this.state.set(hook.id, false);
+      this.fire(hook, 'price_cross', { price: price.toString(), direction: hook.direction, threshold: hook.threshold!.toString() });
+    } else if (!crossed) {
+      this.state.set(hook.id, true);
+    }
+  }
+
+  private evaluateBalanceChange(hook: BotHook): void {
+    const balance = this.balances.getBalance(hook.user_address, hook.token!);
+    const total = balance.available + balance.reserved;
+    const previous = this.state.get(hook.id);
+    this.state.set(hook.id, total);
+    if (typeof previous === 'number' && previous !== total) {
+      this.fire(hook, 'balance_change', {
+        token: hook.token,
+        previous: previous.toString(),
+        current: total.toString(),
+      });
+    }
+  }
+
+  private currentPrice(hook: BotHook): number | undefined {
+    if (hook.market) {
+      return this.engine.getLastPrice(hook.market);
+    }
+    try {
+      const pool = this.pools.getPool(hook.pool_id!);
+      return pool.reserveA > 0 ? pool.reserveB / pool.reserveA : undefined;
+    } catch {
+      return undefined;
+    }
+  }
+
+  private fire(hook: BotHook, event: string, data: Record<string, unknown>): void {
+    hook.last_fired_at = new Date().toISOString();
+    const body = JSON.stringify({ hook_id: hook.id, event, at: hook.last_fired_at, ...data });
+    const secret = this.secrets.get(hook.id) ?? '';
+    const signature = createHmac('sha256', secret).update(body).digest('hex');
+
+    void fetch(hook.url, {
+      method: 'POST',
+      headers: {
+        'Content-Type': 'application/json',
+        'X-Bot-Event': event,
+        'X-Bot-Signature': signature,
+      },
+      body,
+    })
+      .then((response) => {
+        this.logDelivery({ hook_id: hook.id, event, status: response.ok ? 'delivered' : 'failed', status_code: response.status, at: new Date().toISOString() });
+      })
+      .catch((error: unknown) => {
+        this.logDelivery({
+          hook_id: hook.id,
+          event,
+          status: 'failed',
+          error: error instanceof Error ? error.message : 'request failed',
+          at: new Date().toISOString(),
+        });
+      });
+  }
+
+  private logDelivery(delivery: HookDelivery): void {
+    this.deliveries.push(delivery);
+    if (this.deliveries.length > MAX_DELIVERY_LOG) {
+      this.deliveries.splice(0, this.deliveries.length - MAX_DELIVERY_LOG);
+    }
+    if (delivery.status === 'failed') {
+      this.logger.warn(`Bot hook ${delivery.hook_id} delivery failed${delivery.error ? `: ${delivery.error}` : ''}`);
+    }
+  }
+}
@@ -0,0 +1,26 @@
+import { Type } from 'class-transformer';
+import { IsIn, IsNumber, IsOptional, IsPositive, IsString } from 'class-validator';
+
+export class CreateAlertDto {
+  @IsString()
+  user_address!: string;
+
+  @IsIn(['pool_spot', 'market_last'])
+  source!: 'pool_spot' | 'market_last';
+
+  @IsOptional()
+  @IsString()
+  pool_id?: string;
+
+  @IsOptional()
+  @IsString()
+  market?: string;
+
+  @IsIn(['above', 'below'])
+  direction!: 'above' | 'below';
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  threshold!: number;
+}
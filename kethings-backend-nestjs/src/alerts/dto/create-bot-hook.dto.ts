@@ -0,0 +1,38 @@
+import { Type } from 'class-transformer';
+import { IsIn, IsNumber, IsOptional, IsPositive, IsString, IsUrl } from 'class-validator';
+
+export class CreateBotHookDto {
+  @IsString()
+  user_address!: string;
+
+  @IsIn(['price_cross', 'balance_change'])
+  trigger!: 'price_cross' | 'balance_change';
+
+  @IsOptional()
+  @IsString()
+  market?: string;
+
+  @IsOptional()
+  @IsString()
+  pool_id?: string;
+
+  @IsOptional()
+  @IsIn(['above', 'below'])
+  direction?: 'above' | 'below';
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  threshold?: number;
+
+  @IsOptional()
+  @IsString()
+  token?: string;
+
+  @IsUrl({ require_tld: false })
+  url!: string;
+
+  @IsString()
+  secret!: string;
+}
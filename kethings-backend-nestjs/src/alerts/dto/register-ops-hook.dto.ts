@@ -0,0 +1,22 @@
+import { ArrayNotEmpty, IsArray, IsIn, IsOptional, IsString, IsUrl } from 'class-validator';
+
+import { OpsEvent } from '../ops-hooks.service';
+
+const OPS_EVENTS: OpsEvent[] = ['drift_exceeded', 'pool_auto_paused', 'pool_escalated'];
+
+export class RegisterOpsHookDto {
+  @IsUrl({ require_tld: false })
+  url!: string;
+
+  @IsString()
+  channel!: string;
+
+  @IsArray()
+  @ArrayNotEmpty()
+  @IsIn(OPS_EVENTS, { each: true })
+  events!: OpsEvent[];
+
+  @IsOptional()
+  @IsString()
+  secret?: string;
+}
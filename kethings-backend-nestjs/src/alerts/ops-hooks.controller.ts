@@ -0,0 +1,32 @@
+import { Body, Controller, Delete, Get, HttpCode, Param, Post, UseGuards } from '@nestjs/common';
+
+import { OpsHooksService } from './ops-hooks.service';
+import { RegisterOpsHookDto } from './dto/register-ops-hook.dto';
+import { AdminGuard } from '../common/admin.guard';
+
+@Controller('admin/ops-hooks')
+@UseGuards(AdminGuard)
+export class OpsHooksController {
+  constructor(private readonly opsHooks: OpsHooksService) {}
+
+  @Get()
+  list() {
+    return { hooks: this.opsHooks.listHooks() };
+  }
+
+  @Post()
+  register(@Body() body: RegisterOpsHookDto) {
+    return this.opsHooks.register(body.url, body.channel, body.events, body.secret);
+  }
+
+  @Delete(':hookId')
+  @HttpCode(204)
+  remove(@Param('hookId') hookId: string) {
+    this.opsHooks.remove(hookId);
+  }
+
+  @Get(':hookId/deliveries')
+  deliveries(@Param('hookId') hookId: string) {
+    return { deliveries: this.opsHooks.deliveryLog(hookId) };
+  }
+}
@@ -0,0 +1,109 @@
+import { Injectable, Logger, NotFoundException } from '@nestjs/common';
+import { createHmac, randomUUID } from 'crypto';
+
+export type OpsEvent = 'drift_exceeded' | 'pool_auto_paused' | 'pool_escalated';
+
+export interface OpsHook {
+  id: string;
+  url: string;
+  /** Free-form routing label, e.g. an ops channel name, echoed in payloads. */
+  channel: string;
+  events: OpsEvent[];
+  created_at: string;
+  last_fired_at?: string;
+}
+
+interface OpsHookDelivery {
+  hook_id: string;
+  event: OpsEvent;
+  status: 'delivered' | 'failed';
+  status_code?: number;
+  error?: string;
+  at: string;
+}
+
+const MAX_DELIVERY_LOG = 200;
+
+/**
+ * Operator alerting hooks. Unlike bot hooks these are admin-registered and
+ * fire on infrastructure events — drift threshold breaches and automatic
+ * pool pauses — so the on-call channel hears about a diverging pool before
+ * users do. Payloads are HMAC-signed when a secret is registered.
+ */
+@Injectable()
+export class OpsHooksService {
+  private readonly logger = new Logger(OpsHooksService.name);
+  private readonly hooks = new Map<string, OpsHook>();
+  private readonly secrets = new Map<string, string>();
+  private readonly deliveries: OpsHookDelivery[] = [];
+
+  listHooks(): OpsHook[] {
+    return Array.from(this.hooks.values());
+  }
+
+  register(url: string, channel: string, events: OpsEvent[], secret?: string): OpsHook {
+    const hook: OpsHook = {
+      id: randomUUID(),
+      url,
+      channel,
+      events,
+      created_at: new Date().toISOString(),
+    };
+    this.hooks.set(hook.id, hook);
+    if (secret) {
+      this.secrets.set(hook.id, secret);
+    }
+    return hook;
+  }
+
+  remove(hookId: string): void {
+    if (!this.hooks.delete(hookId)) {
+      throw new NotFoundException(`Ops hook ${hookId} not found`);
+    }
+    this.secrets.delete(hookId);
+  }
+
+  deliveryLog(hookId?: string): OpsHookDelivery[] {
+    return this.deliveries.filter((delivery) => !hookId || delivery.hook_id === hookId);
+  }
+
+  fire(event: OpsEvent, data: Record<string, unknown>): void {
+    for (const hook of this.hooks.values()) {
+      if (!hook.events.includes(event)) continue;
+      hook.last_fired_at = new Date().toISOString();
+      const body = JSON.stringify({ event, channel: hook.channel, at: hook.last_fired_at, ...data });
+      const secret = this.secrets.get(hook.id);
+      const headers: Record<string, string> = {
+        'Content-Type': 'application/json',
+        'X-Ops-Event': event,
+      };
+      if (secret) {
+        headers['X-Ops-Signature'] = createHmac('sha256', secret).update(body).digest('hex');
+      }
+
+      void fetch(hook.url, { method: 'POST', headers, body })
+        .then((response) => {
+          this.logDelivery({ hook_id: hook.id, event, status: response.ok ? 'delivered' : 'failed', status_code: response.status, at: new Date().toISOString() });
+        })
+        .catch((error: unknown) => {
+          this.logDelivery({
+            hook_id: hook.id,
+            event,
+            status: 'failed',
+            error: error instanceof Error ? error.message : 'request failed',
+            at: new Date().toISOString(),
+          });
+        });
+    }
+  }
+
+  private logDelivery(delivery: OpsHookDelivery): void {
+    this.deliveries.push(delivery);
+    if (this.deliveries.length > MAX_DELIVERY_LOG) {
+      this.deliveries.splice(0, this.deliveries.length - MAX_DELIVERY_LOG);
+    }
+    if (delivery.status === 'failed') {
+      this.logger.warn(`Ops hook ${delivery.hook_id} delivery failed${delivery.error ? `: ${delivery.error}` : ''}`);
+    }
+  }
+}
@@ -0,0 +1,36 @@
+import { Controller, Get, Headers, Param, Query } from '@nestjs/common';
+
+import { AnalyticsService } from './analytics.service';
+import { MarketsService } from '../engine/markets.service';
+import { AuthService } from '../auth/auth.service';
+
+@Controller('analytics')
+export class AnalyticsController {
+  constructor(
+    private readonly analytics: AnalyticsService,
+    private readonly markets: MarketsService,
+    private readonly auth: AuthService,
+  ) {}
+
+  @Get(':base/:quote')
+  marketAnalytics(
+    @Param('base') base: string,
+    @Param('quote') quote: string,
+    @Query('user_address') userAddress?: string,
+    @Headers('x-session-token') sessionToken?: string,
+  ) {
+    this.markets.assertVisible(`${base}/${quote}`, this.viewer(userAddress, sessionToken));
+    return this.analytics.getAnalytics(`${base}/${quote}`);
+  }
+
+  /** A verified session wins over a bare user_address claim. */
+  private viewer(userAddress?: string, sessionToken?: string): string | undefined {
+    if (sessionToken) {
+      const session = this.auth.getSession(sessionToken);
+      if (session) {
+        return session.user_address;
+      }
+    }
+    return userAddress;
+  }
+}
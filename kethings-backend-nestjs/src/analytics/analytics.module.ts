@@ -0,0 +1,13 @@
+import { Module } from '@nestjs/common';
+import { AnalyticsService } from './analytics.service';
+import { AnalyticsController } from './analytics.controller';
+import { EngineModule } from '../engine/engine.module';
+import { AuthModule } from '../auth/auth.module';
+
+@Module({
+  imports: [EngineModule, AuthModule],
+  providers: [AnalyticsService],
+  controllers: [AnalyticsController],
+  exports: [AnalyticsService],
+})
+export class AnalyticsModule {}
@@ -0,0 +1,136 @@
+import { Injectable } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { Subject } from 'rxjs';
+
+import { EngineService } from '../engine/engine.service';
+
+export interface MarketAnalytics {
+  market: string;
+  /** (bid depth − ask depth) / total depth over the top levels, in [−1, 1]. */
+  imbalance?: string;
+  mid_price?: string;
+  /** Size-weighted mid: leans toward the thinner side's quote. */
+  micro_price?: string;
+  /** Std dev of log returns over the rolling trade window. */
+  realized_volatility?: string;
+  trade_count: number;
+  window_ms: number;
+  computed_at: string;
+}
+
+export interface AnalyticsEvent {
+  market: string;
+  metrics: MarketAnalytics;
+}
+
+const DEFAULT_DEPTH_LEVELS = 10;
+const DEFAULT_VOL_WINDOW_MS = 300_000;
+
+/**
+ * Derived order-flow analytics for quant consumers. Metrics are recomputed
+ * incrementally on every book change and trade rather than on request:
+ * imbalance and micro-price read the live book, realized volatility folds
+ * each trade into a rolling log-return window. Results fan out on the
+ * `analytics:{market}` WS channel and are served point-in-time over REST.
+ */
+@Injectable()
+export class AnalyticsService {
+  /** Rolling trade window per market, oldest first. */
+  private readonly trades = new Map<string, Array<{ at: number; price: number }>>();
+  private readonly latest = new Map<string, MarketAnalytics>();
+  readonly events$ = new Subject<AnalyticsEvent>();
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly engine: EngineService,
+  ) {
+    this.engine.events$.subscribe((event) => {
+      if (event.type === 'trade') {
+        this.recordTrade(event.market, event.price);
+      }
+      this.recompute(event.market);
+    });
+  }
+
+  getAnalytics(market: string): MarketAnalytics {
+    return this.latest.get(market) ?? this.recompute(market);
+  }
+
+  private recordTrade(market: string, price: number): void {
+    const window = this.trades.get(market) ?? [];
+    window.push({ at: Date.now(), price });
+    this.trades.set(market, window);
+  }
+
+  private recompute(market: string): MarketAnalytics {
+    const windowMs = Number(this.config.get<string>('ANALYTICS_VOL_WINDOW_MS')) || DEFAULT_VOL_WINDOW_MS;
+    const depthLevels = Number(this.config.get<string>('ANALYTICS_DEPTH_LEVELS')) || DEFAULT_DEPTH_LEVELS;
+    const cutoff = Date.now() - windowMs;
+    const window = (this.trades.get(market) ?? []).filter((trade) => trade.at >= cutoff);
+    this.trades.set(market, window);
+
+    const book = this.engine.getBook(market);
+    const metrics: MarketAnalytics = {
+      market,
+      trade_count: window.length,
+      window_ms: windowMs,
+      computed_at: new Date().toISOString(),
+    };
+
+    const bidLevels = this.aggregateLevels(book.bids, depthLevels);
+    const askLevels = this.aggregateLevels(book.asks, depthLevels);
+    const bidDepth = bidLevels.reduce((sum, level) => sum + level.quantity, 0);
+    const askDepth = askLevels.reduce((sum, level) => sum + level.quantity, 0);
+    if (bidDepth + askDepth > 0) {
+      metrics.imbalance = ((bidDepth - askDepth) / (bidDepth + askDepth)).toString();
+    }
+    if (bidLevels.length > 0 && askLevels.length > 0) {
+      const bestBid = bidLevels[0];
+      const bestAsk = askLevels[0];
+      metrics.mid_price = ((bestBid.price + bestAsk.price) / 2).toString();
+      const topSize = bestBid.quantity + bestAsk.quantity;
+      if (topSize > 0) {
+        metrics.micro_price = ((bestBid.price * bestAsk.quantity + bestAsk.price * bestBid.quantity) / topSize).toString();
+      }
+    }
+
+    const volatility = this.realizedVolatility(window);
+    if (volatility !== undefined) {
+      metrics.realized_volatility = volatility.toString();
+    }
+
+    this.latest.set(market, metrics);
+    this.events$.next({ market, metrics });
+    return metrics;
+  }
+
+  private aggregateLevels(
+    orders: Array<{ price: number; remaining: number }>,
+    depthLevels: number,
+  ): Array<{ price: number; quantity: number }> {
+    const levels = new Map<number, number>();
+    for (const order of orders) {
+      if (levels.size >= depthLevels && !levels.has(order.price)) break;
+      levels.set(order.price, (levels.get(order.price) ?? 0) + order.remaining);
+    }
+    return Array.from(levels.entries()).map(([price, quantity]) => ({ price, quantity }));
+  }
+
+  private realizedVolatility(window: Array<{ price: number }>): number | undefined {
+    if (window.length < 3) {
+      return undefined;
+    }
+    const returns: number[] = [];
+    for (let index = 1; index < window.length; index += 1) {
+      if (window[index - 1].price > 0 && window[index].price > 0) {
+        returns.push(Math.log(window[index].price / window[index - 1].price));
+      }
+    }
+    if (returns.length < 2) {
+      return undefined;
+    }
+    const mean = returns.reduce((sum, value) => sum + value, 0) / returns.length;
+    const variance = returns.reduce((sum, value) => sum + (value - mean) ** 2, 0) / (returns.length - 1);
+    return Math.sqrt(variance);
+  }
+}
@@ -1,13 +1,88 @@
-import { Module } from '@nestjs/common';
+import { MiddlewareConsumer, Module, NestModule } from '@nestjs/common';
 import { ConfigModule } from '@nestjs/config';
+import { APP_INTERCEPTOR } from '@nestjs/core';
+import { TracingMiddleware } from './common/tracing.middleware';
+import { ApiVersionMiddleware } from './common/api-version.middleware';
+import { RateLimitMiddleware } from './common/rate-limit.middleware';
+import { AuditMiddleware } from './common/audit.middleware';
+import { TimeoutInterceptor } from './common/timeout.interceptor';
 import { LedgerModule } from './ledger/ledger.module';
 import { MarketDataModule } from './market-data/market-data.module';
+import { TokensModule } from './tokens/tokens.module';
+import { BalancesModule } from './balances/balances.module';
+import { PoolsModule } from './pools/pools.module';
+import { EngineModule } from './engine/engine.module';
+import { InvariantsModule } from './invariants/invariants.module';
+import { RfqModule } from './rfq/rfq.module';
+import { NotificationsModule } from './notifications/notifications.module';
+import { AlertsModule } from './alerts/alerts.module';
+import { ReconciliationModule } from './reconciliation/reconciliation.module';
+import { SettlementModule } from './settlement/settlement.module';
+import { RewardsModule } from './rewards/rewards.module';
+import { PricesModule } from './prices/prices.module';
+import { IntentsModule } from './intents/intents.module';
+import { AuctionsModule } from './auctions/auctions.module';
+import { WsModule } from './ws/ws.module';
+import { RetentionModule } from './retention/retention.module';
+import { KeetaModule } from './keeta/keeta.module';
+import { AuthModule } from './auth/auth.module';
+import { UsersModule } from './users/users.module';
+import { TradesModule } from './trades/trades.module';
+import { ShutdownModule } from './shutdown/shutdown.module';
+import { WithdrawalsModule } from './withdrawals/withdrawals.module';
+import { DepositsModule } from './deposits/deposits.module';
+import { AuditModule } from './audit/audit.module';
+import { AnalyticsModule } from './analytics/analytics.module';
+import { TenantsModule } from './tenants/tenants.module';
+import { TenantMiddleware } from './tenants/tenant.middleware';
+import { PersistenceModule } from './persistence/persistence.module';
+import { PortfolioModule } from './portfolio/portfolio.module';
+import { RelayModule } from './relay/relay.module';
+import { ReadOnlyMiddleware } from './persistence/read-only.middleware';
+import { DevModule } from './dev/dev.module';
 
 @Module({
   imports: [
     ConfigModule.forRoot({ isGlobal: true }),
     LedgerModule,
     MarketDataModule,
+    TokensModule,
+    BalancesModule,
+    PoolsModule,
+    EngineModule,
+    InvariantsModule,
+    RfqModule,
+    NotificationsModule,
+    AlertsModule,
+    ReconciliationModule,
+    SettlementModule,
+    RewardsModule,
+    PricesModule,
+    IntentsModule,
+    AuctionsModule,
+    WsModule,
+    RetentionModule,
+    KeetaModule,
+    AuthModule,
+    WithdrawalsModule,
+    DepositsModule,
+    UsersModule,
+    TradesModule,
+    AuditModule,
+    AnalyticsModule,
+    TenantsModule,
+    PersistenceModule,
+    PortfolioModule,
+    RelayModule,
+    DevModule,
+    ShutdownModule,
   ],
+  providers: [{ provide: APP_INTERCEPTOR, useClass: TimeoutInterceptor }],
 })
-export class AppModule {}
+export class AppModule implements NestModule {
+  configure(consumer: MiddlewareConsumer): void {
+    consumer
+      .apply(TracingMiddleware, ApiVersionMiddleware, ReadOnlyMiddleware, TenantMiddleware, RateLimitMiddleware, AuditMiddleware)
+      .forRoutes('*');
+  }
+}
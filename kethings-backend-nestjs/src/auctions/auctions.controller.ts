@@ -0,0 +1,37 @@
+import { Body, Controller, Get, Param, Post, UseGuards } from '@nestjs/common';
+
+import { AuctionsService } from './auctions.service';
+import { AdminGuard } from '../common/admin.guard';
+import { CreateAuctionDto } from './dto/create-auction.dto';
+import { PlaceBidDto } from './dto/place-bid.dto';
+
+@Controller('auctions')
+export class AuctionsController {
+  constructor(private readonly auctions: AuctionsService) {}
+
+  @Get()
+  list() {
+    return { auctions: this.auctions.listAuctions() };
+  }
+
+  @Post()
+  @UseGuards(AdminGuard)
+  create(@Body() body: CreateAuctionDto) {
+    return this.auctions.createAuction(body);
+  }
+
+  @Get(':auctionId')
+  get(@Param('auctionId') auctionId: string) {
+    return this.auctions.getAuction(auctionId);
+  }
+
+  @Get(':auctionId/bids')
+  bids(@Param('auctionId') auctionId: string) {
+    return { bids: this.auctions.getBids(auctionId) };
+  }
+
+  @Post(':auctionId/bids')
+  placeBid(@Param('auctionId') auctionId: string, @Body() body: PlaceBidDto) {
+    return this.auctions.placeBid(auctionId, body.user_address, body.price, body.quantity);
+  }
+}
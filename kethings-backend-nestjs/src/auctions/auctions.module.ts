@@ -0,0 +1,15 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+import { AuctionsService } from './auctions.service';
+import { AuctionsController } from './auctions.controller';
+import { AdminGuard } from '../common/admin.guard';
+import { BalancesModule } from '../balances/balances.module';
+import { SettlementModule } from '../settlement/settlement.module';
+
+@Module({
+  imports: [ConfigModule, BalancesModule, SettlementModule],
+  providers: [AuctionsService, AdminGuard],
+  controllers: [AuctionsController],
+  exports: [AuctionsService],
+})
+export class AuctionsModule {}
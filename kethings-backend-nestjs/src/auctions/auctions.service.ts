@@ -0,0 +1,206 @@
+import { BadRequestException, Injectable, Logger, NotFoundException } from '@nestjs/common';
+import { randomUUID } from 'crypto';
+
+import { BalancesService } from '../balances/balances.service';
+import { SettlementQueueService } from '../settlement/settlement-queue.service';
+
+export type AuctionStatus = 'open' | 'settled' | 'cancelled';
+
+export interface AuctionBid {
+  id: string;
+  user_address: string;
+  price: number;
+  quantity: number;
+  placed_at: string;
+  /** Filled quantity, set at settlement. */
+  allocation?: number;
+}
+
+export interface Auction {
+  id: string;
+  issuer: string;
+  token: string;
+  quote_token: string;
+  quantity: number;
+  min_price?: number;
+  opens_at: string;
+  closes_at: string;
+  status: AuctionStatus;
+  clearing_price?: number;
+  total_allocated?: number;
+  created_at: string;
+}
+
+/**
+ * Batch auction price discovery for token launches: bids collect during the
+ * window, a uniform clearing price is computed at close, and fills are
+ * allocated pro-rata at the margin. Issuer proceeds go through the
+ * settlement queue like any other withdrawal.
+ */
+@Injectable()
+export class AuctionsService {
+  private readonly logger = new Logger(AuctionsService.name);
+  private readonly auctions = new Map<string, Auction>();
+  private readonly bids = new Map<string, AuctionBid[]>();
+  private readonly closeTimers = new Map<string, ReturnType<typeof setTimeout>>();
+
+  constructor(
+    private readonly balances: BalancesService,
+    private readonly settlementQueue: SettlementQueueService,
+  ) {}
+
+  listAuctions(): Auction[] {
+    return Array.from(this.auctions.values());
+  }
+
+  getAuction(auctionId: string): Auction {
+    const auction = this.auctions.get(auctionId);
+    if (!auction) {
+      throw new NotFoundException(`Auction ${auctionId} not found`);
+    }
+    return auction;
+  }
+
+  getBids(auctionId: string): AuctionBid[] {
+    this.getAuction(auctionId);
+    return this.bids.get(auctionId) ?? [];
+  }
+
+  createAuction(input: Omit<Auction, 'id' | 'status' | 'created_at' | 'clearing_price' | 'total_allocated'>): Auction {
+    const closesAtMs = Date.parse(input.closes_at);
+    if (!Number.isFinite(closesAtMs) || closesAtMs <= Date.now()) {
+      throw new BadRequestException('closes_at must be a future timestamp');
+    }
+    // A past opens_at is allowed (the auction simply opens immediately),
+    // but it must parse and leave a bidding window before the close.
+    const opensAtMs = Date.parse(input.opens_at);
+    if (!Number.isFinite(opensAtMs)) {
+      throw new BadRequestException('opens_at must be a valid timestamp');
+    }
+    if (opensAtMs >= closesAtMs) {
+      throw new BadRequestException('opens_at must be before closes_at');
+    }
+
+    // The issuer escrows the full launch quantity up front.
+    this.balances.debit(input.issuer, input.token, input.quantity);
+
+    const auction: Auction = {
+      ...input,
+      id: randomUUID(),
+      status: 'open',
+      created_at: new Date().toISOString(),
+    };
+    this.auctions.set(auction.id, auction);
+    this.bids.set(auction.id, []);
+    this.closeTimers.set(
+      auction.id,
+      setTimeout(() => this.settle(auction.id), closesAtMs - Date.now()),
+    );
+    this.logger.log(`Opened auction ${auction.id} for ${auction.quantity} ${auction.token}`);
+    return auction;
+  }
+
+  placeBid(auctionId: string, userAddress: string, price: number, quantity: number): AuctionBid {
+    const auction = this.getAuction(auctionId);
+    if (Date.parse(auction.opens_at) > Date.now()) {
+      throw new BadRequestException(`Auction ${auctionId} does not open for bidding until ${auction.opens_at}`);
+    }
+    if (auction.status !== 'open' || Date.parse(auction.closes_at) <= Date.now()) {
+      throw new BadRequestException(`Auction ${auctionId} is not accepting bids`);
+    }
+    if (auction.min_price !== undefined && price < auction.min_price) {
+      throw new BadRequestException(`Bid price ${price} is below the auction minimum ${auction.min_price}`);
+    }
+
+    // Reserve the full bid notional until settlement.
+    this.balances.reserve(userAddress, auction.quote_token, price * quantity);
+
+    const bid: AuctionBid = {
+      id: randomUUID(),
+      user_address: userAddress,
+      price,
+      quantity,
+      placed_at: new Date().toISOString(),
+    };
+    this.bids.get(auctionId)!.push(bid);
+    return bid;
+  }
+
+  /** Compute the clearing price and allocate fills pro-rata at the margin. */
+  settle(auctionId: string): Auction {
+    const auction = this.getAuction(auctionId);
+    if (auction.status !== 'open') {
+      return auction;
+    }
+    const timer = this.closeTimers.get(auctionId);
+    if (timer) {
+      clearTimeout(timer);
+      this.closeTimers.delete(auctionId);
+    }
+
+    const sorted = [...(this.bids.get(auctionId) ?? [])].sort((a, b) => b.price - a.price);
+    let remaining = auction.quantity;
+    let clearingPrice: number | undefined;
+
+    for (const bid of sorted) {
+      if (remaining <= 0) break;
+      clearingPrice = bid.price;
+      remaining -= bid.quantity;
+    }
+
+    if (clearingPrice === undefined) {
+      // No bids: return the escrowed supply to the issuer.
+      this.balances.credit(auction.issuer, auction.token, auction.quantity);
+      auction.status = 'cancelled';
+      return auction;
+    }
+
+    // Pro-rata scaling for bids at exactly the clearing price when demand at
+    // the margin exceeds remaining supply.
+    const aboveClearing = sorted.filter((bid) => bid.price > clearingPrice!);
+    const atClearing = sorted.filter((bid) => bid.price === clearingPrice!);
+    const allocatedAbove = aboveClearing.reduce((sum, bid) => sum + bid.quantity, 0);
+    const leftForMargin = Math.max(0, auction.quantity - allocatedAbove);
+    const marginDemand = atClearing.reduce((sum, bid) => sum + bid.quantity, 0);
+    const marginRatio = marginDemand > 0 ? Math.min(1, leftForMargin / marginDemand) : 0;
+
+    let totalAllocated = 0;
+    let proceeds = 0;
+    for (const bid of sorted) {
+      const allocation =
+        bid.price > clearingPrice ? bid.quantity : bid.price === clearingPrice ? bid.quantity * marginRatio : 0;
+      bid.allocation = allocation;
+
+      const reserved = bid.price * bid.quantity;
+      const cost = clearingPrice * allocation;
+      this.balances.consumeReserved(bid.user_address, auction.quote_token, reserved);
+      // Refund the unspent part of the reservation.
+      if (reserved - cost > 0) {
+        this.balances.credit(bid.user_address, auction.quote_token, reserved - cost);
+      }
+      if (allocation > 0) {
+        this.balances.credit(bid.user_address, auction.token, allocation);
+      }
+      totalAllocated += allocation;
+      proceeds += cost;
+    }
+
+    // Unsold supply returns to the issuer; proceeds settle via the queue.
+    if (auction.quantity - totalAllocated > 0) {
+      this.balances.credit(auction.issuer, auction.token, auction.quantity - totalAllocated);
+    }
+    this.balances.credit(auction.issuer, auction.quote_token, proceeds);
+    this.settlementQueue.enqueue('withdraw', {
+      user_address: auction.issuer,
+      token: auction.quote_token,
+      amount: proceeds,
+      reason: `auction ${auction.id} proceeds`,
+    });
+
+    auction.status = 'settled';
+    auction.clearing_price = clearingPrice;
+    auction.total_allocated = totalAllocated;
+    this.logger.log(`Settled auction ${auctionId} at ${clearingPrice}; allocated ${totalAllocated} ${auction.token}`);
+    return auction;
+  }
+}
@@ -0,0 +1,30 @@
+import { Type } from 'class-transformer';
+import { IsNumber, IsOptional, IsPositive, IsString } from 'class-validator';
+
+export class CreateAuctionDto {
+  @IsString()
+  issuer!: string;
+
+  @IsString()
+  token!: string;
+
+  @IsString()
+  quote_token!: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  quantity!: number;
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  min_price?: number;
+
+  @IsString()
+  opens_at!: string;
+
+  @IsString()
+  closes_at!: string;
+}
@@ -0,0 +1,17 @@
+import { Type } from 'class-transformer';
+import { IsNumber, IsPositive, IsString } from 'class-validator';
+
+export class PlaceBidDto {
+  @IsString()
+  user_address!: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  price!: number;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  quantity!: number;
+}
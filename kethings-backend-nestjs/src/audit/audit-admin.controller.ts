@@ -0,0 +1,24 @@
+import { Controller, Get, NotFoundException, Param, UseGuards } from '@nestjs/common';
+
+import { AuditHashService } from './audit-hash.service';
+import { AdminGuard } from '../common/admin.guard';
+
+@Controller('admin/audit')
+@UseGuards(AdminGuard)
+export class AuditAdminController {
+  constructor(private readonly audit: AuditHashService) {}
+
+  @Get('chains')
+  chains() {
+    return { chains: this.audit.chainHeads() };
+  }
+
+  @Get('chains/:stream')
+  chain(@Param('stream') stream: string) {
+    const head = this.audit.chainHead(stream);
+    if (!head) {
+      throw new NotFoundException(`No audit chain for stream ${stream}`);
+    }
+    return head;
+  }
+}
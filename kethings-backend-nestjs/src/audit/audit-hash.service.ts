@@ -0,0 +1,77 @@
+import { Injectable } from '@nestjs/common';
+import { createHash } from 'crypto';
+
+export interface ChainHead {
+  stream: string;
+  length: number;
+  head_hash: string;
+  updated_at: string;
+}
+
+export interface ChainedRecord {
+  stream: string;
+  seq: number;
+  record_hash: string;
+  chain_hash: string;
+}
+
+/**
+ * Serialize a value to canonical JSON: object keys sorted recursively,
+ * arrays kept in order, so the same logical record always hashes to the
+ * same bytes regardless of property insertion order.
+ */
+export function canonicalJson(value: unknown): string {
+  if (value === null || typeof value !== 'object') {
+    return JSON.stringify(value);
+  }
+  if (Array.isArray(value)) {
+    return `[${value.map((item) => canonicalJson(item)).join(',')}]`;
+  }
+  const entries = Object.entries(value as Record<string, unknown>)
+    .filter(([, item]) => item !== undefined)
+    .sort(([a], [b]) => (a < b ? -1 : a > b ? 1 : 0));
+  return `{${entries.map(([key, item]) => `${JSON.stringify(key)}:${canonicalJson(item)}`).join(',')}}`;
+}
+
+/** sha256 hex digest of the canonical JSON form. */
+export function contentHash(value: unknown): string {
+  return createHash('sha256').update(canonicalJson(value)).digest('hex');
+}
+
+const GENESIS_HASH = '0'.repeat(64);
+
+/**
+ * Tamper-evident hash chains over audit record streams. Each appended record
+ * is content-hashed canonically and folded into the stream's running chain
+ * hash (`sha256(prev_chain_hash || record_hash)`), so altering, dropping or
+ * reordering any historical record changes the chain head. External auditors
+ * replay the records they hold and compare against the published head.
+ */
+@Injectable()
+export class AuditHashService {
+  private readonly chains = new Map<string, ChainHead>();
+
+  append(stream: string, record: unknown): ChainedRecord {
+    const prev = this.chains.get(stream);
+    const recordHash = contentHash(record);
+    const chainHash = createHash('sha256')
+      .update(`${prev?.head_hash ?? GENESIS_HASH}${recordHash}`)
+      .digest('hex');
+    const head: ChainHead = {
+      stream,
+      length: (prev?.length ?? 0) + 1,
+      head_hash: chainHash,
+      updated_at: new Date().toISOString(),
+    };
+    this.chains.set(stream, head);
+    return { stream, seq: head.length, record_hash: recordHash, chain_hash: chainHash };
+  }
+
+  chainHeads(): ChainHead[] {
+    return Array.from(this.chains.values());
+  }
+
+  chainHead(stream: string): ChainHead | undefined {
+    return this.chains.get(stream);
+  }
+}
@@ -0,0 +1,149 @@
+import { Injectable, Logger, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { appendFileSync, existsSync, mkdirSync, readFileSync } from 'fs';
+import { dirname } from 'path';
+
+import { AuditHashService } from './audit-hash.service';
+
+export type AuditCategory = 'ledger' | 'engine' | 'pool' | 'settlement' | 'admin';
+
+export interface AuditLogRecord {
+  seq: number;
+  at: string;
+  category: AuditCategory;
+  action: string;
+  /** Who did it: a user address, maker id, or 'admin' for keyed admin calls. */
+  actor?: string;
+  /** What it happened to: a market, pool id, settlement op id, account. */
+  subject?: string;
+  before?: unknown;
+  after?: unknown;
+  data?: Record<string, unknown>;
+  /** Head of the tamper-evident chain after this record was folded in. */
+  chain_hash: string;
+}
+
+export interface AuditQuery {
+  category?: AuditCategory;
+  action?: string;
+  actor?: string;
+  subject?: string;
+  since?: string;
+  until?: string;
+  limit?: number;
+}
+
+const AUDIT_STREAM = 'audit-log';
+const DEFAULT_LOG_PATH = 'data/audit.log';
+const DEFAULT_QUERY_LIMIT = 100;
+const MAX_QUERY_LIMIT = 1000;
+const MAX_MEMORY_RECORDS = 10_000;
+
+/**
+ * Append-only audit trail over every state mutation that touches user funds:
+ * ledger postings, order placement and cancels, pool operations, settlement
+ * transitions and admin actions, each with actor, timestamp and before/after
+ * values. Records are folded into the 'audit-log' hash chain as they are
+ * written and persisted to an on-disk log replayed at startup; the in-memory
+ * window is bounded, the file is not.
+ */
+@Injectable()
+export class AuditLogService implements OnModuleInit {
+  private readonly logger = new Logger(AuditLogService.name);
+  private readonly records: AuditLogRecord[] = [];
+  private logPath = DEFAULT_LOG_PATH;
+  private nextSeq = 1;
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly hashes: AuditHashService,
+  ) {}
+
+  onModuleInit(): void {
+    this.logPath = this.config.get<string>('AUDIT_LOG_PATH') || DEFAULT_LOG_PATH;
+    this.load();
+  }
+
+  record(input: Omit<AuditLogRecord, 'seq' | 'at' | 'chain_hash'>): AuditLogRecord {
+    const base = { seq: this.nextSeq, at: new Date().toISOString(), ...input };
+    const chained = this.hashes.append(AUDIT_STREAM, base);
+    const record: AuditLogRecord = { ...base, chain_hash: chained.chain_hash };
+    this.nextSeq += 1;
+    this.records.push(record);
+    if (this.records.length > MAX_MEMORY_RECORDS) {
+      this.records.splice(0, this.records.length - MAX_MEMORY_RECORDS);
+    }
+    this.append(record);
+    return record;
+  }
+
+  /** Newest-first slice of the trail matching every supplied filter. */
+  query(filters: AuditQuery): AuditLogRecord[] {
+    const limit = Math.min(filters.limit && filters.limit > 0 ? filters.limit : DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT);
+    const matches: AuditLogRecord[] = [];
+    for (let index = this.records.length - 1; index >= 0 && matches.length < limit; index -= 1) {
+      const record = this.records[index];
+      if (filters.category && record.category !== filters.category) continue;
+      if (filters.action && record.action !== filters.action) continue;
+      if (filters.actor && record.actor !== filters.actor) continue;
+      if (filters.subject && record.subject !== filters.subject) continue;
+      if (filters.since && record.at < filters.since) continue;
+      if (filters.until && record.at > filters.until) continue;
+      matches.push(record);
+    }
+    return matches;
+  }
+
+  recordCount(): number {
+    return this.records.length;
+  }
+
+  private append(record: AuditLogRecord): void {
+    try {
+      const dir = dirname(this.logPath);
+      if (dir && dir !== '.' && !existsSync(dir)) {
+        mkdirSync(dir, { recursive: true });
+      }
+      appendFileSync(this.logPath, `${JSON.stringify(record)}\n`);
+    } catch (error) {
+      this.logger.error('Failed to append audit log record', error);
+    }
+  }
+
+  private load(): void {
+    if (!existsSync(this.logPath)) {
+      return;
+    }
+    try {
+      const lines = readFileSync(this.logPath, 'utf8').split('\n').filter(Boolean);
+      const loaded: AuditLogRecord[] = [];
+      for (const line of lines) {
+        let record: AuditLogRecord;
+        try {
+          record = JSON.parse(line) as AuditLogRecord;
+        } catch {
+          this.logger.warn('Skipping corrupt audit log line');
+          continue;
+        }
+        // Re-fold every persisted record into the chain — the full file, not
+        // just the in-memory window — so new records after a restart extend
+        // the original chain instead of forking from a fresh genesis (same
+        // as the settlement journal replay). The stored head doubles as a
+        // startup verification of the log on disk.
+        const { chain_hash, ...base } = record;
+        const chained = this.hashes.append(AUDIT_STREAM, base);
+        if (chained.chain_hash !== chain_hash) {
+          this.logger.warn(`Audit chain mismatch at seq ${record.seq}: the persisted log was altered or truncated`);
+        }
+        loaded.push(record);
+      }
+      this.records.push(...loaded.slice(-MAX_MEMORY_RECORDS));
+      if (loaded.length > 0) {
+        this.nextSeq = loaded[loaded.length - 1].seq + 1;
+      }
+      this.logger.log(`Loaded ${loaded.length} audit log records (${this.records.length} in memory)`);
+    } catch (error) {
+      this.logger.error(`Failed to read audit log at ${this.logPath}`, error);
+    }
+  }
+}
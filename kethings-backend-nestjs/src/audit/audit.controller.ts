@@ -0,0 +1,38 @@
+import { BadRequestException, Controller, Get, Query, UseGuards } from '@nestjs/common';
+
+import { AuditCategory, AuditLogService } from './audit-log.service';
+import { AdminGuard } from '../common/admin.guard';
+
+const CATEGORIES: AuditCategory[] = ['ledger', 'engine', 'pool', 'settlement', 'admin'];
+
+@Controller('audit')
+@UseGuards(AdminGuard)
+export class AuditController {
+  constructor(private readonly auditLog: AuditLogService) {}
+
+  @Get()
+  query(
+    @Query('category') category?: string,
+    @Query('action') action?: string,
+    @Query('actor') actor?: string,
+    @Query('subject') subject?: string,
+    @Query('since') since?: string,
+    @Query('until') until?: string,
+    @Query('limit') limit?: string,
+  ) {
+    if (category !== undefined && !CATEGORIES.includes(category as AuditCategory)) {
+      throw new BadRequestException(`category must be one of ${CATEGORIES.join(', ')}`);
+    }
+    return {
+      records: this.auditLog.query({
+        category: category as AuditCategory | undefined,
+        action,
+        actor,
+        subject,
+        since,
+        until,
+        limit: limit ? Number(limit) : undefined,
+      }),
+    };
+  }
+}
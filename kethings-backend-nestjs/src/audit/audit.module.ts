@@ -0,0 +1,16 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+
+import { AuditHashService } from './audit-hash.service';
+import { AuditLogService } from './audit-log.service';
+import { AuditAdminController } from './audit-admin.controller';
+import { AuditController } from './audit.controller';
+import { AdminGuard } from '../common/admin.guard';
+
+@Module({
+  imports: [ConfigModule],
+  providers: [AuditHashService, AuditLogService, AdminGuard],
+  controllers: [AuditAdminController, AuditController],
+  exports: [AuditHashService, AuditLogService],
+})
+export class AuditModule {}
@@ -0,0 +1,26 @@
+import { Body, Controller, Delete, HttpCode, Param, Post } from '@nestjs/common';
+
+import { AuthService } from './auth.service';
+import { ChallengeRequestDto } from './dto/challenge-request.dto';
+import { CreateSessionDto } from './dto/create-session.dto';
+
+@Controller('auth')
+export class AuthController {
+  constructor(private readonly auth: AuthService) {}
+
+  @Post('challenge')
+  challenge(@Body() body: ChallengeRequestDto) {
+    return this.auth.issueChallenge(body.user_address);
+  }
+
+  @Post('session')
+  createSession(@Body() body: CreateSessionDto) {
+    return this.auth.createSession(body.user_address, body.nonce, body.signature, body.public_key);
+  }
+
+  @Delete('session/:token')
+  @HttpCode(204)
+  revoke(@Param('token') token: string) {
+    this.auth.revokeSession(token);
+  }
+}
@@ -0,0 +1,134 @@
+import { Injectable, Logger, UnauthorizedException } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { createPublicKey, randomUUID, verify as verifySignature } from 'crypto';
+
+import { KeetaSdkService } from '../keeta/keeta-sdk.service';
+
+export interface AuthChallenge {
+  nonce: string;
+  user_address: string;
+  expires_at: string;
+}
+
+export interface Session {
+  token: string;
+  user_address: string;
+  created_at: string;
+  expires_at: string;
+}
+
+const DEFAULT_NONCE_TTL_MS = 300_000;
+const DEFAULT_SESSION_TTL_MS = 86_400_000;
+/** SPKI DER prefix for a raw 32-byte ed25519 public key. */
+const ED25519_SPKI_PREFIX = Buffer.from('302a300506032b6570032100', 'hex');
+
+/**
+ * Challenge/response authentication. A challenge hands out a single-use
+ * nonce; creating a session requires an ed25519 signature of that nonce
+ * verified against the key encoded in the user's address — a signature from
+ * an unrelated keypair must not mint a session for someone else's address.
+ * Nonces expire and are consumed on first use, so a captured signature
+ * cannot be replayed into a second session.
+ */
+@Injectable()
+export class AuthService {
+  private readonly logger = new Logger(AuthService.name);
+  private readonly challenges = new Map<string, AuthChallenge>();
+  private readonly sessions = new Map<string, Session>();
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly keeta: KeetaSdkService,
+  ) {}
+
+  issueChallenge(userAddress: string): AuthChallenge {
+    const ttlMs = Number(this.config.get<string>('AUTH_NONCE_TTL_MS')) || DEFAULT_NONCE_TTL_MS;
+    const challenge: AuthChallenge = {
+      nonce: randomUUID(),
+      user_address: userAddress,
+      expires_at: new Date(Date.now() + ttlMs).toISOString(),
+    };
+    this.challenges.set(challenge.nonce, challenge);
+    return challenge;
+  }
+
+  async createSession(userAddress: string, nonce: string, signatureBase64: string, publicKeyBase64: string): Promise<Session> {
+    const challenge = this.challenges.get(nonce);
+    if (!challenge || challenge.user_address !== userAddress) {
+      throw new UnauthorizedException({ code: 'UNKNOWN_NONCE', message: 'Challenge nonce is unknown or already used' });
+    }
+    // Single use: the nonce is consumed whether or not verification succeeds,
+    // so a failed attempt cannot be retried against the same challenge.
+    this.challenges.delete(nonce);
+
+    if (Date.parse(challenge.expires_at) <= Date.now()) {
+      throw new UnauthorizedException({ code: 'NONCE_EXPIRED', message: 'Challenge nonce has expired; request a new one' });
+    }
+    if (!this.verify(nonce, signatureBase64, publicKeyBase64)) {
+      throw new UnauthorizedException({ code: 'INVALID_SIGNATURE', message: 'Signature does not verify against the provided public key' });
+    }
+    // The key must be the one encoded in the address: without this binding
+    // any keypair could mint a session for any address. A failed lookup
+    // rejects rather than waves through.
+    let keyBound = false;
+    try {
+      keyBound = await this.keeta.addressMatchesPublicKey(userAddress, publicKeyBase64);
+    } catch (error) {
+      this.logger.warn(
+        `Key binding check failed for ${userAddress}: ${error instanceof Error ? error.message : 'unknown error'}`,
+      );
+    }
+    if (!keyBound) {
+      throw new UnauthorizedException({ code: 'KEY_ADDRESS_MISMATCH', message: 'Public key is not the key for the requested address' });
+    }
+
+    const ttlMs = Number(this.config.get<string>('AUTH_SESSION_TTL_MS')) || DEFAULT_SESSION_TTL_MS;
+    const session: Session = {
+      token: randomUUID(),
+      user_address: userAddress,
+      created_at: new Date().toISOString(),
+      expires_at: new Date(Date.now() + ttlMs).toISOString(),
+    };
+    this.sessions.set(session.token, session);
+    return session;
+  }
+
+  getSession(token: string): Session | undefined {
+    const session = this.sessions.get(token);
+    if (!session) {
+      return undefined;
+    }
+    if (Date.parse(session.expires_at) <= Date.now()) {
+      this.sessions.delete(token);
+      return undefined;
+    }
+    return session;
+  }
+
+  revokeSession(token: string): void {
+    this.sessions.delete(token);
+  }
+
+  private verify(nonce: string, signatureBase64: string, publicKeyBase64: string): boolean {
+    return this.verifyPayloadSignature(nonce, signatureBase64, publicKeyBase64);
+  }
+
+  /** Verify an ed25519 signature over an arbitrary payload (e.g. an intent digest). */
+  verifyPayloadSignature(payload: string, signatureBase64: string, publicKeyBase64: string): boolean {
+    try {
+      const rawKey = Buffer.from(publicKeyBase64, 'base64');
+      if (rawKey.length !== 32) {
+        return false;
+      }
+      const publicKey = createPublicKey({
+        key: Buffer.concat([ED25519_SPKI_PREFIX, rawKey]),
+        format: 'der',
+        type: 'spki',
+      });
+      return verifySignature(null, Buffer.from(payload, 'utf8'), publicKey, Buffer.from(signatureBase64, 'base64'));
+    } catch (error) {
+      this.logger.warn(`Signature verification errored: ${error instanceof Error ? error.message : 'unknown error'}`);
+      return false;
+    }
+  }
+}
@@ -0,0 +1,6 @@
+import { IsString } from 'class-validator';
+
+export class ChallengeRequestDto {
+  @IsString()
+  user_address!: string;
+}
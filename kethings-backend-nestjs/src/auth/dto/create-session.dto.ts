@@ -0,0 +1,17 @@
+import { IsString } from 'class-validator';
+
+export class CreateSessionDto {
+  @IsString()
+  user_address!: string;
+
+  @IsString()
+  nonce!: string;
+
+  /** Base64 ed25519 signature over the nonce. */
+  @IsString()
+  signature!: string;
+
+  /** Base64 raw 32-byte ed25519 public key. */
+  @IsString()
+  public_key!: string;
+}
@@ -0,0 +1,13 @@
+import { Module } from '@nestjs/common';
+import { BalancesService } from './balances.service';
+import { VaultController } from './vault.controller';
+import { TokensModule } from '../tokens/tokens.module';
+import { AuditModule } from '../audit/audit.module';
+
+@Module({
+  imports: [TokensModule, AuditModule],
+  providers: [BalancesService],
+  controllers: [VaultController],
+  exports: [BalancesService],
+})
+export class BalancesModule {}
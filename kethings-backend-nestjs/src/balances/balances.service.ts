@@ -0,0 +1,442 @@
+import { Injectable, Logger } from '@nestjs/common';
+
+import { TokensService } from '../tokens/tokens.service';
+import { AuditLogService } from '../audit/audit-log.service';
+
+export interface UserBalance {
+  token: string;
+  available: number;
+  reserved: number;
+  /** Funds parked in the savings vault; not spendable until swept back. */
+  vault: number;
+  /** Exact decimal strings derived from the base-unit bookkeeping. */
+  available_exact?: string;
+  reserved_exact?: string;
+  vault_exact?: string;
+}
+
+interface RawBalance {
+  token: string;
+  availableRaw: bigint;
+  reservedRaw: bigint;
+  vaultRaw: bigint;
+}
+
+const MAX_VAULT_MOVEMENTS = 200;
+
+export interface VaultMovement {
+  at: string;
+  token: string;
+  direction: 'deposit' | 'withdraw' | 'auto_sweep';
+  amount: string;
+}
+
+export interface AutoSweepRule {
+  /** When false the vault never tops up trading balances automatically. */
+  enabled: boolean;
+  /** Restrict auto-sweep to these tokens; undefined means any token. */
+  tokens?: string[];
+  /** Cap on a single automatic draw, in token units. */
+  max_per_draw?: number;
+}
+
+/**
+ * Staged view of the ledger inside a transaction(). Postings validate
+ * against committed balances plus earlier postings in the same transaction,
+ * and nothing is applied until the callback returns without throwing.
+ */
+export interface LedgerTransaction {
+  credit(user: string, token: string, amount: number): void;
+  debit(user: string, token: string, amount: number): void;
+  reserve(user: string, token: string, amount: number): void;
+  release(user: string, token: string, amount: number): void;
+  consumeReserved(user: string, token: string, amount: number): void;
+}
+
+/**
+ * Internal ledger of off-chain user balances used by the trading modules.
+ * Amounts here are bookkeeping entries only — actual funds stay in user and
+ * storage accounts on Keeta per the zero-custody architecture.
+ *
+ * Balances are stored as integer base units (bigint) scaled by each token's
+ * decimals from the registry, so repeated credits/debits never accumulate
+ * float rounding drift and sufficiency checks are exact. Floats only appear
+ * at the API boundary, rounded to the token's smallest unit on the way in.
+ *
+ * Storage is sharded per user — an outer map keyed by user, inner maps
+ * keyed by token — so getBalances is O(tokens the user holds) and activity
+ * on one account never touches another user's shard. bench-balances.js at
+ * the package root exercises both properties.
+ */
+@Injectable()
+export class BalancesService {
+  private readonly logger = new Logger(BalancesService.name);
+  private readonly accounts = new Map<string, Map<string, RawBalance>>();
+  private readonly autoSweepRules = new Map<string, AutoSweepRule>();
+  /** Vault movement history per user, newest first, bounded. */
+  private readonly vaultMovements = new Map<string, VaultMovement[]>();
+
+  constructor(
+    private readonly tokens: TokensService,
+    private readonly auditLog: AuditLogService,
+  ) {}
+
+  getBalances(user: string): UserBalance[] {
+    const tokens = this.accounts.get(user);
+    if (!tokens) {
+      return [];
+    }
+    return Array.from(tokens.values()).map((balance) => this.toView(balance));
+  }
+
+  getBalance(user: string, token: string): UserBalance {
+    const raw = this.accounts.get(user)?.get(token);
+    return raw
+      ? this.toView(raw)
+      : { token, available: 0, reserved: 0, vault: 0, available_exact: '0', reserved_exact: '0', vault_exact: '0' };
+  }
+
+  credit(user: string, token: string, amount: number): void {
+    const raw = this.toRaw(token, amount);
+    if (raw <= 0n) {
+      throw new Error(`Credit amount must be positive: ${amount}`);
+    }
+    const before = this.getBalance(user, token);
+    const balance = this.ensure(user, token);
+    balance.availableRaw += raw;
+    this.audit('credit', user, token, amount, before);
+  }
+
+  debit(user: string, token: string, amount: number): void {
+    const raw = this.toRaw(token, amount);
+    if (raw <= 0n) {
+      throw new Error(`Debit amount must be positive: ${amount}`);
+    }
+    const balance = this.ensure(user, token);
+    if (balance.availableRaw < raw) {
+      this.sweepFromVault(user, token, raw - balance.availableRaw);
+    }
+    if (balance.availableRaw < raw) {
+      throw new Error(`Insufficient ${token} balance: have ${this.formatRaw(token, balance.availableRaw)}, need ${amount}`);
+    }
+    const before = this.getBalance(user, token);
+    balance.availableRaw -= raw;
+    this.audit('debit', user, token, amount, before);
+  }
+
+  reserve(user: string, token: string, amount: number): void {
+    const raw = this.toRaw(token, amount);
+    const balance = this.ensure(user, token);
+    if (balance.availableRaw < raw) {
+      this.sweepFromVault(user, token, raw - balance.availableRaw);
+    }
+    if (balance.availableRaw < raw) {
+      throw new Error(`Insufficient ${token} balance to reserve: have ${this.formatRaw(token, balance.availableRaw)}, need ${amount}`);
+    }
+    const before = this.getBalance(user, token);
+    balance.availableRaw -= raw;
+    balance.reservedRaw += raw;
+    this.audit('reserve', user, token, amount, before);
+  }
+
+  release(user: string, token: string, amount: number): void {
+    const raw = this.toRaw(token, amount);
+    const balance = this.ensure(user, token);
+    if (balance.reservedRaw < raw) {
+      throw new Error(
+        `Cannot release more than reserved for ${token}: reserved ${this.formatRaw(token, balance.reservedRaw)}, requested ${amount}`,
+      );
+    }
+    const before = this.getBalance(user, token);
+    balance.reservedRaw -= raw;
+    balance.availableRaw += raw;
+    this.audit('release', user, token, amount, before);
+  }
+
+  /** Spend funds previously set aside with reserve(), e.g. when an order fills. */
+  consumeReserved(user: string, token: string, amount: number): void {
+    const raw = this.toRaw(token, amount);
+    const balance = this.ensure(user, token);
+    if (balance.reservedRaw < raw) {
+      throw new Error(
+        `Cannot consume more than reserved for ${token}: reserved ${this.formatRaw(token, balance.reservedRaw)}, requested ${amount}`,
+      );
+    }
+    const before = this.getBalance(user, token);
+    balance.reservedRaw -= raw;
+    this.audit('consume_reserved', user, token, amount, before);
+  }
+
+  /**
+   * Run several postings all-or-nothing. Deltas are staged against the live
+   * balances and applied only when the callback completes; any throw —
+   * including an insufficient-funds error partway through — discards every
+   * staged posting, so callers never have to hand-roll compensation.
+   */
+  transaction<T>(work: (tx: LedgerTransaction) => T): T {
+    const deltas = new Map<string, { token: string; user: string; available: bigint; reserved: bigint }>();
+
+    const delta = (user: string, token: string) => {
+      const key = `${user}|${token}`;
+      let entry = deltas.get(key);
+      if (!entry) {
+        entry = { user, token, available: 0n, reserved: 0n };
+        deltas.set(key, entry);
+      }
+      return entry;
+    };
+    const effectiveAvailable = (user: string, token: string): bigint =>
+      (this.accounts.get(user)?.get(token)?.availableRaw ?? 0n) + delta(user, token).available;
+    const effectiveReserved = (user: string, token: string): bigint =>
+      (this.accounts.get(user)?.get(token)?.reservedRaw ?? 0n) + delta(user, token).reserved;
+
+    const tx: LedgerTransaction = {
+      credit: (user, token, amount) => {
+        const raw = this.toRaw(token, amount);
+        if (raw <= 0n) {
+          throw new Error(`Credit amount must be positive: ${amount}`);
+        }
+        delta(user, token).available += raw;
+      },
+      debit: (user, token, amount) => {
+        const raw = this.toRaw(token, amount);
+        if (raw <= 0n) {
+          throw new Error(`Debit amount must be positive: ${amount}`);
+        }
+        if (effectiveAvailable(user, token) < raw) {
+          // Safe to apply immediately even if the transaction later aborts:
+          // the sweep only moves the user's own vault funds into available.
+          this.sweepFromVault(user, token, raw - effectiveAvailable(user, token));
+        }
+        if (effectiveAvailable(user, token) < raw) {
+          throw new Error(`Insufficient ${token} balance: have ${this.formatRaw(token, effectiveAvailable(user, token))}, need ${amount}`);
+        }
+        delta(user, token).available -= raw;
+      },
+      reserve: (user, token, amount) => {
+        const raw = this.toRaw(token, amount);
+        if (effectiveAvailable(user, token) < raw) {
+          this.sweepFromVault(user, token, raw - effectiveAvailable(user, token));
+        }
+        if (effectiveAvailable(user, token) < raw) {
+          throw new Error(
+            `Insufficient ${token} balance to reserve: have ${this.formatRaw(token, effectiveAvailable(user, token))}, need ${amount}`,
+          );
+        }
+        const entry = delta(user, token);
+        entry.available -= raw;
+        entry.reserved += raw;
+      },
+      release: (user, token, amount) => {
+        const raw = this.toRaw(token, amount);
+        if (effectiveReserved(user, token) < raw) {
+          throw new Error(
+            `Cannot release more than reserved for ${token}: reserved ${this.formatRaw(token, effectiveReserved(user, token))}, requested ${amount}`,
+          );
+        }
+        const entry = delta(user, token);
+        entry.reserved -= raw;
+        entry.available += raw;
+      },
+      consumeReserved: (user, token, amount) => {
+        const raw = this.toRaw(token, amount);
+        if (effectiveReserved(user, token) < raw) {
+          throw new Error(
+            `Cannot consume more than reserved for ${token}: reserved ${this.formatRaw(token, effectiveReserved(user, token))}, requested ${amount}`,
+          );
+        }
+        delta(user, token).reserved -= raw;
+      },
+    };
+
+    const result = work(tx);
+
+    for (const entry of deltas.values()) {
+      const before = this.getBalance(entry.user, entry.token);
+      const balance = this.ensure(entry.user, entry.token);
+      balance.availableRaw += entry.available;
+      balance.reservedRaw += entry.reserved;
+      this.auditLog.record({
+        category: 'ledger',
+        action: 'transaction_posting',
+        actor: entry.user,
+        subject: entry.token,
+        before,
+        after: this.getBalance(entry.user, entry.token),
+      });
+    }
+    return result;
+  }
+
+  private audit(action: string, user: string, token: string, amount: number, before: UserBalance): void {
+    this.auditLog.record({
+      category: 'ledger',
+      action,
+      actor: user,
+      subject: token,
+      before,
+      after: this.getBalance(user, token),
+      data: { amount: amount.toString() },
+    });
+  }
+
+  /** Park available funds in the savings vault sub-balance. */
+  vaultDeposit(user: string, token: string, amount: number): UserBalance {
+    const raw = this.toRaw(token, amount);
+    if (raw <= 0n) {
+      throw new Error(`Vault deposit amount must be positive: ${amount}`);
+    }
+    const balance = this.ensure(user, token);
+    if (balance.availableRaw < raw) {
+      throw new Error(`Insufficient ${token} balance to vault: have ${this.formatRaw(token, balance.availableRaw)}, need ${amount}`);
+    }
+    const before = this.getBalance(user, token);
+    balance.availableRaw -= raw;
+    balance.vaultRaw += raw;
+    this.recordVaultMovement(user, token, 'deposit', raw);
+    this.audit('vault_deposit', user, token, amount, before);
+    return this.toView(balance);
+  }
+
+  /** Pull vault funds back into the spendable trading balance. */
+  vaultWithdraw(user: string, token: string, amount: number): UserBalance {
+    const raw = this.toRaw(token, amount);
+    if (raw <= 0n) {
+      throw new Error(`Vault withdrawal amount must be positive: ${amount}`);
+    }
+    const balance = this.ensure(user, token);
+    if (balance.vaultRaw < raw) {
+      throw new Error(`Insufficient ${token} in vault: have ${this.formatRaw(token, balance.vaultRaw)}, need ${amount}`);
+    }
+    const before = this.getBalance(user, token);
+    balance.vaultRaw -= raw;
+    balance.availableRaw += raw;
+    this.recordVaultMovement(user, token, 'withdraw', raw);
+    this.audit('vault_withdraw', user, token, amount, before);
+    return this.toView(balance);
+  }
+
+  getAutoSweepRule(user: string): AutoSweepRule {
+    // Auto-sweep is on by default: vaulted funds are still the user's
+    // liquidity, the vault just keeps them out of the day-to-day balance.
+    return this.autoSweepRules.get(user) ?? { enabled: true };
+  }
+
+  setAutoSweepRule(user: string, rule: AutoSweepRule): AutoSweepRule {
+    this.autoSweepRules.set(user, rule);
+    return rule;
+  }
+
+  listVaultMovements(user: string, token?: string): VaultMovement[] {
+    const all = this.vaultMovements.get(user) ?? [];
+    return token === undefined ? all : all.filter((movement) => movement.token === token);
+  }
+
+  /**
+   * Cover a shortfall in the spendable balance from the vault, within the
+   * user's auto-sweep rule. A partial top-up is still applied — the caller's
+   * own sufficiency check then produces the usual insufficient-funds error.
+   */
+  private sweepFromVault(user: string, token: string, shortfallRaw: bigint): void {
+    const rule = this.getAutoSweepRule(user);
+    if (!rule.enabled || (rule.tokens !== undefined && !rule.tokens.includes(token))) {
+      return;
+    }
+    const balance = this.ensure(user, token);
+    let draw = shortfallRaw < balance.vaultRaw ? shortfallRaw : balance.vaultRaw;
+    if (rule.max_per_draw !== undefined) {
+      const cap = this.toRaw(token, rule.max_per_draw);
+      if (draw > cap) {
+        draw = cap;
+      }
+    }
+    if (draw <= 0n) {
+      return;
+    }
+    const before = this.getBalance(user, token);
+    balance.vaultRaw -= draw;
+    balance.availableRaw += draw;
+    this.recordVaultMovement(user, token, 'auto_sweep', draw);
+    this.audit('vault_auto_sweep', user, token, this.fromRaw(token, draw), before);
+  }
+
+  private recordVaultMovement(user: string, token: string, direction: VaultMovement['direction'], raw: bigint): void {
+    const movements = this.vaultMovements.get(user) ?? [];
+    movements.unshift({ at: new Date().toISOString(), token, direction, amount: this.formatRaw(token, raw) });
+    if (movements.length > MAX_VAULT_MOVEMENTS) {
+      movements.length = MAX_VAULT_MOVEMENTS;
+    }
+    this.vaultMovements.set(user, movements);
+  }
+
+  /** Flat snapshot of every tracked balance entry, for reconciliation and invariant checks. */
+  snapshot(): Array<{ user: string; token: string; available: number; reserved: number; vault: number }> {
+    const entries: Array<{ user: string; token: string; available: number; reserved: number; vault: number }> = [];
+    for (const [user, tokens] of this.accounts) {
+      for (const balance of tokens.values()) {
+        entries.push({
+          user,
+          token: balance.token,
+          available: this.fromRaw(balance.token, balance.availableRaw),
+          reserved: this.fromRaw(balance.token, balance.reservedRaw),
+          vault: this.fromRaw(balance.token, balance.vaultRaw),
+        });
+      }
+    }
+    return entries;
+  }
+
+  private ensure(user: string, token: string): RawBalance {
+    let tokens = this.accounts.get(user);
+    if (!tokens) {
+      tokens = new Map();
+      this.accounts.set(user, tokens);
+    }
+    let balance = tokens.get(token);
+    if (!balance) {
+      balance = { token, availableRaw: 0n, reservedRaw: 0n, vaultRaw: 0n };
+      tokens.set(token, balance);
+    }
+    return balance;
+  }
+
+  private scale(token: string): bigint {
+    return 10n ** BigInt(this.tokens.getDisplayMetadata(token).decimals);
+  }
+
+  /** Round an API-boundary float to the token's smallest representable unit. */
+  private toRaw(token: string, amount: number): bigint {
+    if (!Number.isFinite(amount)) {
+      throw new Error(`Amount is not a finite number: ${amount}`);
+    }
+    return BigInt(Math.round(amount * Number(this.scale(token))));
+  }
+
+  private fromRaw(token: string, raw: bigint): number {
+    return Number(raw) / Number(this.scale(token));
+  }
+
+  private formatRaw(token: string, raw: bigint): string {
+    const scale = this.scale(token);
+    const negative = raw < 0n;
+    const abs = negative ? -raw : raw;
+    const whole = abs / scale;
+    const frac = (abs % scale)
+      .toString()
+      .padStart(scale.toString().length - 1, '0')
+      .replace(/0+$/, '');
+    return `${negative ? '-' : ''}${whole}${frac ? `.${frac}` : ''}`;
+  }
+
+  private toView(balance: RawBalance): UserBalance {
+    return {
+      token: balance.token,
+      available: this.fromRaw(balance.token, balance.availableRaw),
+      reserved: this.fromRaw(balance.token, balance.reservedRaw),
+      vault: this.fromRaw(balance.token, balance.vaultRaw),
+      available_exact: this.formatRaw(balance.token, balance.availableRaw),
+      reserved_exact: this.formatRaw(balance.token, balance.reservedRaw),
+      vault_exact: this.formatRaw(balance.token, balance.vaultRaw),
+    };
+  }
+}
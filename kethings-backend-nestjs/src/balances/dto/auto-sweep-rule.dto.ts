@@ -0,0 +1,21 @@
+import { Type } from 'class-transformer';
+import { IsArray, IsBoolean, IsNumber, IsOptional, IsPositive, IsString } from 'class-validator';
+
+export class AutoSweepRuleDto {
+  @IsString()
+  user_address!: string;
+
+  @IsBoolean()
+  enabled!: boolean;
+
+  @IsOptional()
+  @IsArray()
+  @IsString({ each: true })
+  tokens?: string[];
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  max_per_draw?: number;
+}
@@ -0,0 +1,15 @@
+import { Type } from 'class-transformer';
+import { IsNumber, IsPositive, IsString } from 'class-validator';
+
+export class VaultMoveDto {
+  @IsString()
+  user_address!: string;
+
+  @IsString()
+  token!: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  amount!: number;
+}
@@ -0,0 +1,54 @@
+import { Body, Controller, Get, Param, Post, Query } from '@nestjs/common';
+
+import { BalancesService } from './balances.service';
+import { VaultMoveDto } from './dto/vault-move.dto';
+import { AutoSweepRuleDto } from './dto/auto-sweep-rule.dto';
+
+/**
+ * Savings vault surface: park funds out of the day-to-day trading balance,
+ * pull them back manually, or let the auto-sweep rule top up the trading
+ * balance on demand when an order or swap comes up short. Every movement —
+ * manual or automatic — is recorded and queryable.
+ */
+@Controller('vault')
+export class VaultController {
+  constructor(private readonly balances: BalancesService) {}
+
+  @Get(':user')
+  overview(@Param('user') user: string) {
+    const balances = this.balances.getBalances(user).filter((balance) => balance.vault > 0);
+    return {
+      user_address: user,
+      balances: balances.map((balance) => ({
+        token: balance.token,
+        vault: balance.vault.toString(),
+        vault_exact: balance.vault_exact,
+      })),
+      auto_sweep: this.balances.getAutoSweepRule(user),
+    };
+  }
+
+  @Post('deposit')
+  deposit(@Body() body: VaultMoveDto) {
+    return this.balances.vaultDeposit(body.user_address, body.token, body.amount);
+  }
+
+  @Post('withdraw')
+  withdraw(@Body() body: VaultMoveDto) {
+    return this.balances.vaultWithdraw(body.user_address, body.token, body.amount);
+  }
+
+  @Get(':user/movements')
+  movements(@Param('user') user: string, @Query('token') token?: string) {
+    return { user_address: user, movements: this.balances.listVaultMovements(user, token) };
+  }
+
+  @Post('auto-sweep')
+  setAutoSweep(@Body() body: AutoSweepRuleDto) {
+    return this.balances.setAutoSweepRule(body.user_address, {
+      enabled: body.enabled,
+      ...(body.tokens !== undefined ? { tokens: body.tokens } : {}),
+      ...(body.max_per_draw !== undefined ? { max_per_draw: body.max_per_draw } : {}),
+    });
+  }
+}
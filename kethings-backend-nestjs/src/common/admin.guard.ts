@@ -0,0 +1,33 @@
+import { CanActivate, ExecutionContext, Injectable, Logger, UnauthorizedException } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+/**
+ * Guards owner/admin endpoints with a shared key supplied via the
+ * x-admin-key header. When ADMIN_API_KEY is unset the guard fails closed;
+ * set ADMIN_GUARD_ALLOW_UNSET=true (local development only) to allow
+ * unauthenticated admin requests with a logged warning.
+ */
+@Injectable()
+export class AdminGuard implements CanActivate {
+  private readonly logger = new Logger(AdminGuard.name);
+
+  constructor(private readonly config: ConfigService) {}
+
+  canActivate(context: ExecutionContext): boolean {
+    const configured = this.config.get<string>('ADMIN_API_KEY');
+    if (!configured) {
+      if (this.config.get<string>('ADMIN_GUARD_ALLOW_UNSET') === 'true') {
+        this.logger.warn('ADMIN_API_KEY is not configured; allowing admin request (ADMIN_GUARD_ALLOW_UNSET)');
+        return true;
+      }
+      this.logger.warn('ADMIN_API_KEY is not configured; rejecting admin request');
+      throw new UnauthorizedException('Admin access is not configured');
+    }
+    const request = context.switchToHttp().getRequest();
+    const provided = request.headers['x-admin-key'];
+    if (provided !== configured) {
+      throw new UnauthorizedException('Invalid admin key');
+    }
+    return true;
+  }
+}
@@ -0,0 +1,31 @@
+import { BadRequestException, Injectable, NestMiddleware } from '@nestjs/common';
+import type { NextFunction, Request, Response } from 'express';
+
+export const SUPPORTED_API_VERSIONS = ['1'] as const;
+export const CURRENT_API_VERSION = '1';
+
+/**
+ * API version negotiation for REST. Clients may pin a version with the
+ * Accept-Version header; requests without one get the current version.
+ * Unsupported versions are rejected up front with the list of versions the
+ * server speaks, instead of failing later on a payload mismatch. The resolved
+ * version is echoed on the response and stashed on the request so handlers
+ * can apply compatibility shims when payloads change.
+ */
+@Injectable()
+export class ApiVersionMiddleware implements NestMiddleware {
+  use(req: Request & { apiVersion?: string }, res: Response, next: NextFunction): void {
+    const requested = req.header('accept-version')?.trim();
+    const version = requested || CURRENT_API_VERSION;
+    if (!SUPPORTED_API_VERSIONS.includes(version as (typeof SUPPORTED_API_VERSIONS)[number])) {
+      throw new BadRequestException({
+        code: 'UNSUPPORTED_API_VERSION',
+        message: `API version ${version} is not supported; supported versions: ${SUPPORTED_API_VERSIONS.join(', ')}`,
+        supported_versions: SUPPORTED_API_VERSIONS,
+      });
+    }
+    req.apiVersion = version;
+    res.setHeader('api-version', version);
+    next();
+  }
+}
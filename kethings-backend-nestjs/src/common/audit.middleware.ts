@@ -0,0 +1,34 @@
+import { Injectable, NestMiddleware } from '@nestjs/common';
+
+import { AuditLogService } from '../audit/audit-log.service';
+
+/**
+ * Records every mutating admin request in the audit trail. The record is
+ * written when the response finishes so the outcome (status code) is part of
+ * it; the admin key itself never appears, only the fact that a keyed admin
+ * call was made, from where, and against which route.
+ */
+@Injectable()
+export class AuditMiddleware implements NestMiddleware {
+  constructor(private readonly auditLog: AuditLogService) {}
+
+  use(req: any, res: any, next: () => void): void {
+    const path: string = req.originalUrl ?? req.url ?? '';
+    if (!path.includes('/admin/') || req.method === 'GET') {
+      next();
+      return;
+    }
+    res.on('finish', () => {
+      this.auditLog.record({
+        category: 'admin',
+        action: `${req.method} ${path.split('?')[0]}`,
+        actor: 'admin',
+        data: {
+          status_code: res.statusCode,
+          request_id: req.requestId ?? 'unknown',
+        },
+      });
+    });
+    next();
+  }
+}
@@ -0,0 +1,38 @@
+/**
+ * Checked arithmetic for ledger and pool amounts. JS numbers silently turn
+ * into Infinity on overflow and go negative on underflow; these helpers turn
+ * both into loud errors so a bad amount can never be written into pool or
+ * balance state. Multiplication with wide headroom belongs in the bigint
+ * fixed-point module; these guards cover the plain-number edges.
+ */
+
+export class MathOverflowError extends Error {
+  constructor(operation: string, detail: string) {
+    super(`Arithmetic overflow in ${operation}: ${detail}`);
+    this.name = 'MathOverflowError';
+  }
+}
+
+function assertFinite(value: number, operation: string, detail: string): number {
+  if (!Number.isFinite(value)) {
+    throw new MathOverflowError(operation, detail);
+  }
+  return value;
+}
+
+export function checkedAdd(a: number, b: number, label = 'add'): number {
+  return assertFinite(a + b, label, `${a} + ${b}`);
+}
+
+/** Subtraction for amounts: the result must stay finite and non-negative. */
+export function checkedSub(a: number, b: number, label = 'sub'): number {
+  const result = assertFinite(a - b, label, `${a} - ${b}`);
+  if (result < 0) {
+    throw new MathOverflowError(label, `${a} - ${b} underflows below zero`);
+  }
+  return result;
+}
+
+export function checkedMul(a: number, b: number, label = 'mul'): number {
+  return assertFinite(a * b, label, `${a} * ${b}`);
+}
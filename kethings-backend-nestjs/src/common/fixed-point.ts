@@ -0,0 +1,211 @@
+/**
+ * Deterministic fixed-point math for pool curve calculations.
+ *
+ * Values are bigints scaled by 1e18 (WAD). All operations — including the
+ * transcendental ones (ln, exp, pow) — are computed purely in integer
+ * arithmetic, so results are bit-identical across platforms and runtimes and
+ * can be mirrored exactly by on-chain math. Floating point stays at the
+ * edges: convert in with `wadFromNumber`, out with `wadToNumber`.
+ *
+ * The module verifies itself against reference vectors at load time and
+ * refuses to boot if the implementation has drifted.
+ */
+
+export const WAD = 10n ** 18n;
+
+const LN_2_WAD = 693147180559945309n; // ln(2) * 1e18, truncated
+/** Guard digits used inside the series so per-term truncation stays sub-wei. */
+const GUARD = 10n ** 9n;
+
+export function mulWadDown(a: bigint, b: bigint): bigint {
+  return (a * b) / WAD;
+}
+
+export function mulWadUp(a: bigint, b: bigint): bigint {
+  const product = a * b;
+  return product === 0n ? 0n : (product - 1n) / WAD + 1n;
+}
+
+export function divWadDown(a: bigint, b: bigint): bigint {
+  if (b === 0n) {
+    throw new Error('Fixed-point division by zero');
+  }
+  return (a * WAD) / b;
+}
+
+export function divWadUp(a: bigint, b: bigint): bigint {
+  if (b === 0n) {
+    throw new Error('Fixed-point division by zero');
+  }
+  const product = a * WAD;
+  return product === 0n ? 0n : (product - 1n) / b + 1n;
+}
+
+/** floor(a * b / c) without intermediate overflow concerns (bigint). */
+export function mulDivDown(a: bigint, b: bigint, c: bigint): bigint {
+  if (c === 0n) {
+    throw new Error('Fixed-point division by zero');
+  }
+  return (a * b) / c;
+}
+
+/**
+ * Natural log of a positive WAD value. Range-reduces by powers of two
+ * (x = 2^k · m with m in [1, 2)) then evaluates ln(m) with the atanh series
+ * 2·(z + z³/3 + z⁵/5 + …) where z = (m−1)/(m+1), which converges fast on
+ * that interval.
+ */
+export function lnWad(x: bigint): bigint {
+  if (x <= 0n) {
+    throw new Error(`lnWad is undefined for ${x}`);
+  }
+  let k = 0n;
+  let m = x;
+  while (m >= 2n * WAD) {
+    m /= 2n;
+    k += 1n;
+  }
+  while (m < WAD) {
+    m *= 2n;
+    k -= 1n;
+  }
+  // z in guarded precision so series truncation stays below a wei.
+  const scale = WAD * GUARD;
+  const z = ((m - WAD) * scale) / (m + WAD);
+  const z2 = (z * z) / scale;
+  let term = z;
+  let sum = z;
+  for (let n = 3n; n <= 39n; n += 2n) {
+    term = (term * z2) / scale;
+    sum += term / n;
+  }
+  return k * LN_2_WAD + (2n * sum) / GUARD;
+}
+
+/**
+ * e^x for a WAD exponent. Range-reduces x = k·ln2 + r with |r| ≤ ln2/2,
+ * evaluates exp(r) by Taylor series, then shifts by 2^k. Underflows to 0 and
+ * rejects exponents that would overflow the practical range.
+ */
+export function expWad(x: bigint): bigint {
+  if (x < -42_139_678_854_452_767_551n) {
+    // exp(-42.139...) < 0.5e-18: rounds to zero in WAD.
+    return 0n;
+  }
+  if (x > 135_305_999_368_893_231_589n) {
+    // exp(135.305...) overflows 2^256; keep parity with on-chain limits.
+    throw new Error(`expWad overflow for ${x}`);
+  }
+  // Round-to-nearest multiple of ln2 keeps |r| ≤ ln2/2.
+  let k = (2n * x + (x >= 0n ? LN_2_WAD : -LN_2_WAD)) / (2n * LN_2_WAD);
+  const r = x - k * LN_2_WAD;
+  const scale = WAD * GUARD;
+  const rg = r * GUARD;
+  let term = scale;
+  let sum = scale;
+  for (let n = 1n; n <= 25n; n += 1n) {
+    term = (term * rg) / scale / n;
+    sum += term;
+  }
+  let result = sum;
+  while (k > 0n) {
+    result *= 2n;
+    k -= 1n;
+  }
+  while (k < 0n) {
+    result /= 2n;
+    k += 1n;
+  }
+  return result / GUARD;
+}
+
+/** x^y for WAD base and exponent, via exp(y · ln x). Requires x > 0. */
+export function powWad(x: bigint, y: bigint): bigint {
+  if (y === 0n) {
+    return WAD;
+  }
+  if (x === 0n) {
+    return 0n;
+  }
+  return expWad((y * lnWad(x)) / WAD);
+}
+
+/** Integer Newton square root of a WAD value, result in WAD. */
+export function sqrtWad(x: bigint): bigint {
+  if (x < 0n) {
+    throw new Error(`sqrtWad is undefined for ${x}`);
+  }
+  if (x === 0n) {
+    return 0n;
+  }
+  const scaled = x * WAD;
+  let guess = scaled;
+  let next = (guess + 1n) / 2n;
+  while (next < guess) {
+    guess = next;
+    next = (guess + scaled / guess) / 2n;
+  }
+  return guess;
+}
+
+/**
+ * Convert a finite non-negative JS number to WAD. Goes through the decimal
+ * string rendering so the conversion is deterministic for any IEEE-754 input.
+ */
+export function wadFromNumber(value: number): bigint {
+  if (!Number.isFinite(value) || value < 0) {
+    throw new Error(`Cannot convert ${value} to fixed point`);
+  }
+  const [whole, frac = ''] = value.toFixed(18).split('.');
+  return BigInt(whole) * WAD + BigInt(frac.padEnd(18, '0').slice(0, 18));
+}
+
+export function wadToNumber(value: bigint): number {
+  return Number(value) / 1e18;
+}
+
+interface ReferenceVector {
+  name: string;
+  actual: () => bigint;
+  /** Expected WAD result, from an independent high-precision computation. */
+  expected: bigint;
+}
+
+const REFERENCE_VECTORS: ReferenceVector[] = [
+  { name: 'ln(1)', actual: () => lnWad(WAD), expected: 0n },
+  { name: 'ln(2)', actual: () => lnWad(2n * WAD), expected: 693147180559945309n },
+  { name: 'ln(10)', actual: () => lnWad(10n * WAD), expected: 2302585092994045684n },
+  { name: 'ln(0.5)', actual: () => lnWad(WAD / 2n), expected: -693147180559945309n },
+  { name: 'exp(0)', actual: () => expWad(0n), expected: WAD },
+  { name: 'exp(1)', actual: () => expWad(WAD), expected: 2718281828459045235n },
+  { name: 'exp(-1)', actual: () => expWad(-WAD), expected: 367879441171442321n },
+  { name: 'exp(10)', actual: () => expWad(10n * WAD), expected: 22026465794806716516957n },
+  { name: '2^0.5', actual: () => powWad(2n * WAD, WAD / 2n), expected: 1414213562373095048n },
+  { name: '9^0.5', actual: () => powWad(9n * WAD, WAD / 2n), expected: 3n * WAD },
+  { name: '2^3', actual: () => powWad(2n * WAD, 3n * WAD), expected: 8n * WAD },
+  { name: '10^2.5', actual: () => powWad(10n * WAD, (5n * WAD) / 2n), expected: 316227766016837933199n },
+  { name: 'sqrt(2)', actual: () => sqrtWad(2n * WAD), expected: 1414213562373095048n },
+  { name: 'sqrt(1e12)', actual: () => sqrtWad(10n ** 12n * WAD), expected: 10n ** 6n * WAD },
+];
+
+/** Accepted error: 1e-9 relative plus a small absolute floor of 1000 wei. */
+function withinTolerance(actual: bigint, expected: bigint): boolean {
+  const diff = actual > expected ? actual - expected : expected - actual;
+  const magnitude = expected < 0n ? -expected : expected;
+  return diff <= magnitude / 10n ** 9n + 1000n;
+}
+
+/** Throws if any reference vector misses tolerance. Run at module load. */
+export function verifyReferenceVectors(): void {
+  for (const vector of REFERENCE_VECTORS) {
+    const actual = vector.actual();
+    if (!withinTolerance(actual, vector.expected)) {
+      throw new Error(
+        `Fixed-point self-test failed for ${vector.name}: got ${actual}, expected ${vector.expected}`,
+      );
+    }
+  }
+}
+
+// A deployment whose math has drifted must not serve quotes at all.
+verifyReferenceVectors();
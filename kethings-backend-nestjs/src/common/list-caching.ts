@@ -0,0 +1,50 @@
+import { createHash } from 'crypto';
+import type { Request, Response } from 'express';
+
+export interface KeysetPage<T> {
+  items: T[];
+  /** Cursor of the last item; pass back as ?after= to resume. Absent on the final page. */
+  next_cursor?: string;
+}
+
+const DEFAULT_PAGE_LIMIT = 50;
+const MAX_PAGE_LIMIT = 200;
+
+/**
+ * Keyset pagination over an already-ordered list: resume strictly after the
+ * cursor item rather than by offset, so pages stay stable while new items
+ * are appended between polls.
+ */
+export function keysetPage<T>(items: T[], cursorOf: (item: T) => string, after?: string, limit?: number): KeysetPage<T> {
+  const pageSize = Math.min(Math.max(1, limit ?? DEFAULT_PAGE_LIMIT), MAX_PAGE_LIMIT);
+  let start = 0;
+  if (after !== undefined) {
+    const index = items.findIndex((item) => cursorOf(item) === after);
+    // An unknown cursor (e.g. the item was evicted) restarts from the top
+    // rather than silently skipping everything.
+    start = index >= 0 ? index + 1 : 0;
+  }
+  const page = items.slice(start, start + pageSize);
+  const hasMore = start + pageSize < items.length;
+  return {
+    items: page,
+    ...(hasMore && page.length > 0 ? { next_cursor: cursorOf(page[page.length - 1]) } : {}),
+  };
+}
+
+/**
+ * Conditional-request handling for polled list endpoints. Sets a strong ETag
+ * derived from the payload; when the client's If-None-Match matches, sends
+ * an empty 304 so unchanged data is never re-transferred. Returns undefined
+ * after a 304 has been sent, otherwise the payload for normal serialization.
+ */
+export function respondWithEtag<T>(req: Request, res: Response, payload: T): T | undefined {
+  const etag = `"${createHash('sha256').update(JSON.stringify(payload)).digest('hex').slice(0, 32)}"`;
+  res.setHeader('ETag', etag);
+  res.setHeader('Cache-Control', 'private, no-cache');
+  if (req.headers['if-none-match'] === etag) {
+    res.status(304).end();
+    return undefined;
+  }
+  return payload;
+}
@@ -0,0 +1,122 @@
+import { HttpException, HttpStatus, Injectable, Logger, NestMiddleware } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import type { NextFunction, Request, Response } from 'express';
+
+import { AuthService } from '../auth/auth.service';
+
+interface RouteGroupLimit {
+  /** Matches when the request path (after the global prefix) starts with this. */
+  prefix: string;
+  capacity: number;
+  refillPerSecond: number;
+}
+
+interface TokenBucket {
+  tokens: number;
+  lastRefill: number;
+}
+
+/**
+ * Token-bucket rate limiter applied across all REST routes. Buckets are keyed
+ * by wallet address when the request carries a verified session
+ * (x-session-token header) and by client IP otherwise — an unauthenticated
+ * claim like x-wallet-address is never trusted, since rotating a made-up
+ * address per request would mint a fresh bucket every time, while honest
+ * authenticated wallets behind shared NATs are still not throttled together.
+ * Quote and withdrawal route groups carry tighter limits than the default;
+ * exhausted buckets return 429 with Retry-After. Env overrides:
+ * RATE_LIMIT_DEFAULT_CAPACITY, RATE_LIMIT_DEFAULT_REFILL,
+ * RATE_LIMIT_QUOTE_CAPACITY, RATE_LIMIT_WITHDRAWAL_CAPACITY,
+ * RATE_LIMIT_ENABLED=false to disable.
+ */
+@Injectable()
+export class RateLimitMiddleware implements NestMiddleware {
+  private readonly logger = new Logger(RateLimitMiddleware.name);
+  private readonly buckets = new Map<string, TokenBucket>();
+  private readonly enabled: boolean;
+  private readonly groups: RouteGroupLimit[];
+  private readonly defaultLimit: RouteGroupLimit;
+
+  constructor(
+    config: ConfigService,
+    private readonly auth: AuthService,
+  ) {
+    this.enabled = config.get<string>('RATE_LIMIT_ENABLED') !== 'false';
+    const defaultCapacity = Number(config.get<string>('RATE_LIMIT_DEFAULT_CAPACITY')) || 60;
+    const defaultRefill = Number(config.get<string>('RATE_LIMIT_DEFAULT_REFILL')) || 30;
+    const quoteCapacity = Number(config.get<string>('RATE_LIMIT_QUOTE_CAPACITY')) || 20;
+    const withdrawalCapacity = Number(config.get<string>('RATE_LIMIT_WITHDRAWAL_CAPACITY')) || 5;
+
+    this.defaultLimit = { prefix: '', capacity: defaultCapacity, refillPerSecond: defaultRefill };
+    this.groups = [
+      { prefix: '/pools/quote', capacity: quoteCapacity, refillPerSecond: quoteCapacity / 2 },
+      { prefix: '/pools/route', capacity: quoteCapacity, refillPerSecond: quoteCapacity / 2 },
+      { prefix: '/withdrawals', capacity: withdrawalCapacity, refillPerSecond: withdrawalCapacity / 10 },
+    ];
+  }
+
+  use(req: Request, res: Response, next: NextFunction): void {
+    if (!this.enabled) {
+      next();
+      return;
+    }
+
+    const path = (req.originalUrl ?? req.url).replace(/^\/api/, '').split('?')[0];
+    const limit = this.groups.find((group) => path.startsWith(group.prefix)) ?? this.defaultLimit;
+    const caller = this.callerKey(req);
+    const key = `${limit.prefix || 'default'}|${caller}`;
+
+    const now = Date.now();
+    let bucket = this.buckets.get(key);
+    if (!bucket) {
+      bucket = { tokens: limit.capacity, lastRefill: now };
+      this.buckets.set(key, bucket);
+      this.pruneBuckets();
+    }
+    const elapsedSeconds = (now - bucket.lastRefill) / 1000;
+    bucket.tokens = Math.min(limit.capacity, bucket.tokens + elapsedSeconds * limit.refillPerSecond);
+    bucket.lastRefill = now;
+
+    if (bucket.tokens < 1) {
+      const retryAfterSeconds = Math.ceil((1 - bucket.tokens) / limit.refillPerSecond);
+      res.setHeader('Retry-After', retryAfterSeconds.toString());
+      this.logger.warn(`Rate limited ${caller} on ${path} (retry after ${retryAfterSeconds}s)`);
+      throw new HttpException(
+        {
+          code: 'RATE_LIMITED',
+          message: `Too many requests; retry after ${retryAfterSeconds}s`,
+          retry_after_seconds: retryAfterSeconds,
+        },
+        HttpStatus.TOO_MANY_REQUESTS,
+      );
+    }
+
+    bucket.tokens -= 1;
+    next();
+  }
+
+  /**
+   * Verified session -> limits follow the account, not the network path.
+   * Everything else is keyed by IP; self-declared addresses are ignored
+   * because they cost nothing to rotate.
+   */
+  private callerKey(req: Request): string {
+    const token = req.header('x-session-token')?.trim();
+    if (token) {
+      const session = this.auth.getSession(token);
+      if (session) {
+        return `wallet:${session.user_address}`;
+      }
+    }
+    return `ip:${req.ip ?? req.socket?.remoteAddress ?? 'unknown'}`;
+  }
+
+  /** Bound the bucket map; the oldest entries are the stalest and refill on return. */
+  private pruneBuckets(): void {
+    const max = 10_000;
+    while (this.buckets.size > max) {
+      const oldest = this.buckets.keys().next().value as string;
+      this.buckets.delete(oldest);
+    }
+  }
+}
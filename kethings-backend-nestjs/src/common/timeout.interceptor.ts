@@ -0,0 +1,62 @@
+import {
+  CallHandler,
+  ExecutionContext,
+  GatewayTimeoutException,
+  Injectable,
+  Logger,
+  NestInterceptor,
+} from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { Observable, throwError } from 'rxjs';
+import { catchError, timeout } from 'rxjs/operators';
+
+const DEFAULT_BUDGET_MS = 30_000;
+
+/**
+ * Per-endpoint timeout budgets. Routes that fan out to the Keeta network
+ * (ledger reads, ACL verification, address existence checks) get a longer
+ * budget than pure in-memory endpoints; anything that exceeds its budget
+ * returns 504 carrying the request's correlation id so the hung upstream
+ * call can be found in the logs. Ledger writes happen through atomic
+ * `BalancesService.transaction` postings, so a response that times out after
+ * the handler committed leaves no dangling reservations — the client simply
+ * retries and observes the committed state.
+ */
+@Injectable()
+export class TimeoutInterceptor implements NestInterceptor {
+  private readonly logger = new Logger(TimeoutInterceptor.name);
+
+  constructor(private readonly config: ConfigService) {}
+
+  intercept(context: ExecutionContext, next: CallHandler): Observable<unknown> {
+    const request = context.switchToHttp().getRequest();
+    const budgetMs = this.budgetFor(request.originalUrl ?? request.url ?? '');
+    return next.handle().pipe(
+      timeout({ first: budgetMs }),
+      catchError((error) => {
+        if (error?.name !== 'TimeoutError') {
+          return throwError(() => error);
+        }
+        const requestId = request.requestId ?? 'unknown';
+        this.logger.warn(`Request ${requestId} exceeded its ${budgetMs}ms budget: ${request.method} ${request.originalUrl ?? request.url}`);
+        return throwError(
+          () =>
+            new GatewayTimeoutException({
+              code: 'HANDLER_TIMEOUT',
+              message: `Request exceeded its ${budgetMs}ms timeout budget`,
+              request_id: requestId,
+            }),
+        );
+      }),
+    );
+  }
+
+  /** Budget by route group; network-bound routes get the slow budget. */
+  private budgetFor(url: string): number {
+    const path = url.split('?')[0];
+    if (path.includes('/ledger/') || path.includes('/deposits/') || path.includes('/withdrawals')) {
+      return Number(this.config.get<string>('TIMEOUT_BUDGET_NETWORK_MS')) || 65_000;
+    }
+    return Number(this.config.get<string>('TIMEOUT_BUDGET_MS')) || DEFAULT_BUDGET_MS;
+  }
+}
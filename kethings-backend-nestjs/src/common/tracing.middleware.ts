@@ -0,0 +1,29 @@
+import { Injectable, Logger, NestMiddleware } from '@nestjs/common';
+import { randomUUID } from 'crypto';
+
+/**
+ * Accepts standard tracing headers (traceparent, x-request-id) from the
+ * Next.js frontend, assigns a request id when missing, and echoes both back
+ * on the response so log lines can be correlated across the monorepo.
+ */
+@Injectable()
+export class TracingMiddleware implements NestMiddleware {
+  private readonly logger = new Logger('Trace');
+
+  use(req: any, res: any, next: () => void): void {
+    const requestId = (req.headers['x-request-id'] as string) || randomUUID();
+    const traceparent = req.headers['traceparent'] as string | undefined;
+
+    req.requestId = requestId;
+    req.traceparent = traceparent;
+    res.setHeader('x-request-id', requestId);
+    if (traceparent) {
+      res.setHeader('traceparent', traceparent);
+    }
+
+    this.logger.log(
+      `${req.method} ${req.originalUrl ?? req.url} request_id=${requestId}${traceparent ? ` traceparent=${traceparent}` : ''}`,
+    );
+    next();
+  }
+}
@@ -0,0 +1,77 @@
+export interface AppConfig {
+  /** Interface the HTTP server binds to. */
+  host: string;
+  port: number;
+  /** Allowed CORS origins; '*' permits any origin. */
+  corsOrigins: string[] | '*';
+  reconcileIntervalMs: number;
+  defaultFeeRate: number;
+  settlementWorkers: number;
+}
+
+const DEFAULT_CORS_ORIGINS = ['http://localhost:3000'];
+const DEFAULT_HOST = '0.0.0.0';
+const DEFAULT_PORT = 8080;
+const DEFAULT_RECONCILE_INTERVAL_MS = 60_000;
+const DEFAULT_FEE_RATE = 0.003;
+const DEFAULT_SETTLEMENT_WORKERS = 1;
+
+/**
+ * Startup configuration, read from the environment and validated before the
+ * server binds. Invalid values fail fast with every problem listed at once,
+ * instead of booting with a half-broken config that only surfaces when the
+ * misconfigured subsystem is first hit.
+ */
+export function loadAppConfig(env: NodeJS.ProcessEnv = process.env): AppConfig {
+  const errors: string[] = [];
+
+  const host = env.BIND_HOST?.trim() || DEFAULT_HOST;
+
+  const port = env.PORT !== undefined ? Number(env.PORT) : DEFAULT_PORT;
+  if (!Number.isInteger(port) || port < 1 || port > 65535) {
+    errors.push(`PORT must be an integer between 1 and 65535, got "${env.PORT}"`);
+  }
+
+  let corsOrigins: string[] | '*' = DEFAULT_CORS_ORIGINS;
+  if (env.CORS_ORIGINS !== undefined) {
+    const raw = env.CORS_ORIGINS.trim();
+    if (raw === '*') {
+      corsOrigins = '*';
+    } else {
+      const origins = raw.split(',').map((origin) => origin.trim()).filter(Boolean);
+      if (origins.length === 0) {
+        errors.push('CORS_ORIGINS is set but contains no origins');
+      }
+      for (const origin of origins) {
+        try {
+          new URL(origin);
+        } catch {
+          errors.push(`CORS_ORIGINS entry is not a valid origin URL: "${origin}"`);
+        }
+      }
+      corsOrigins = origins;
+    }
+  }
+
+  const reconcileIntervalMs =
+    env.RECONCILIATION_ARCHIVE_INTERVAL_MS !== undefined ? Number(env.RECONCILIATION_ARCHIVE_INTERVAL_MS) : DEFAULT_RECONCILE_INTERVAL_MS;
+  if (!Number.isFinite(reconcileIntervalMs) || reconcileIntervalMs <= 0) {
+    errors.push(`RECONCILIATION_ARCHIVE_INTERVAL_MS must be a positive number, got "${env.RECONCILIATION_ARCHIVE_INTERVAL_MS}"`);
+  }
+
+  const defaultFeeRate = env.POOL_DEFAULT_FEE_RATE !== undefined ? Number(env.POOL_DEFAULT_FEE_RATE) : DEFAULT_FEE_RATE;
+  if (!Number.isFinite(defaultFeeRate) || defaultFeeRate < 0 || defaultFeeRate >= 1) {
+    errors.push(`POOL_DEFAULT_FEE_RATE must be in [0, 1), got "${env.POOL_DEFAULT_FEE_RATE}"`);
+  }
+
+  const settlementWorkers = env.SETTLEMENT_WORKER_COUNT !== undefined ? Number(env.SETTLEMENT_WORKER_COUNT) : DEFAULT_SETTLEMENT_WORKERS;
+  if (!Number.isInteger(settlementWorkers) || settlementWorkers < 1) {
+    errors.push(`SETTLEMENT_WORKER_COUNT must be a positive integer, got "${env.SETTLEMENT_WORKER_COUNT}"`);
+  }
+
+  if (errors.length > 0) {
+    throw new Error(`Invalid configuration:\n  - ${errors.join('\n  - ')}`);
+  }
+
+  return { host, port, corsOrigins, reconcileIntervalMs, defaultFeeRate, settlementWorkers };
+}
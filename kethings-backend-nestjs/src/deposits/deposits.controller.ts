@@ -0,0 +1,25 @@
+import { BadRequestException, Controller, Get, Query } from '@nestjs/common';
+
+import { DepositsService } from './deposits.service';
+
+@Controller('deposits')
+export class DepositsController {
+  constructor(private readonly deposits: DepositsService) {}
+
+  @Get('address')
+  depositAddress(
+    @Query('user_address') userAddress: string,
+    @Query('token') token?: string,
+    @Query('amount') amount?: string,
+    @Query('memo') memo?: string,
+  ) {
+    if (!userAddress) {
+      throw new BadRequestException('user_address query parameter is required');
+    }
+    const amountHint = amount !== undefined ? Number(amount) : undefined;
+    if (amountHint !== undefined && !Number.isFinite(amountHint)) {
+      throw new BadRequestException(`amount is not a number: ${amount}`);
+    }
+    return this.deposits.buildPaymentRequest(userAddress, token, amountHint, memo);
+  }
+}
@@ -0,0 +1,15 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+
+import { DepositsService } from './deposits.service';
+import { DepositsController } from './deposits.controller';
+import { TokensModule } from '../tokens/tokens.module';
+import { KeetaModule } from '../keeta/keeta.module';
+
+@Module({
+  imports: [ConfigModule, TokensModule, KeetaModule],
+  providers: [DepositsService],
+  controllers: [DepositsController],
+  exports: [DepositsService],
+})
+export class DepositsModule {}
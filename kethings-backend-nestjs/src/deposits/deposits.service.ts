@@ -0,0 +1,70 @@
+import { BadRequestException, Injectable } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+import { TokensService } from '../tokens/tokens.service';
+import { KeetaAclService } from '../keeta/keeta-acl.service';
+
+export interface DepositPaymentRequest {
+  address: string;
+  token?: string;
+  amount?: string;
+  memo?: string;
+  /** keeta: payment URI, also the string to encode into a QR code. */
+  payment_uri: string;
+  qr_string: string;
+}
+
+/**
+ * Deposit payment-request builder. Encodes the deposit account plus optional
+ * token, amount hint and memo into a `keeta:` URI so wallets and frontends
+ * render scannable deposit codes from one server-side implementation
+ * instead of each client re-inventing the encoding.
+ */
+@Injectable()
+export class DepositsService {
+  constructor(
+    private readonly config: ConfigService,
+    private readonly tokens: TokensService,
+    private readonly acl: KeetaAclService,
+  ) {}
+
+  async buildPaymentRequest(userAddress: string, token?: string, amount?: number, memo?: string): Promise<DepositPaymentRequest> {
+    const address = this.config.get<string>('DEX_DEPOSIT_ACCOUNT') || userAddress;
+    if (!address) {
+      throw new BadRequestException('No deposit address available; set DEX_DEPOSIT_ACCOUNT or pass user_address');
+    }
+    if (address !== userAddress) {
+      // Strict mode rejects here with the missing permissions listed;
+      // permissive mode counts the outcome and carries on.
+      await this.acl.verifyUserCanDeposit(userAddress, address);
+    }
+    if (amount !== undefined && !(amount > 0)) {
+      throw new BadRequestException('amount hint must be positive');
+    }
+
+    const params = new URLSearchParams();
+    if (token) {
+      // Resolves registry metadata so typos in the token address fail here
+      // rather than on the wallet that scans the code.
+      this.tokens.getDisplayMetadata(token);
+      params.set('token', token);
+    }
+    if (amount !== undefined) {
+      params.set('amount', amount.toString());
+    }
+    if (memo) {
+      params.set('memo', memo);
+    }
+
+    const query = params.toString();
+    const paymentUri = `keeta:${address}${query ? `?${query}` : ''}`;
+    return {
+      address,
+      token,
+      amount: amount?.toString(),
+      memo,
+      payment_uri: paymentUri,
+      qr_string: paymentUri,
+    };
+  }
+}
@@ -0,0 +1,108 @@
+import { Injectable, Logger, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { existsSync, readFileSync } from 'fs';
+
+import { BalancesService } from '../balances/balances.service';
+import { PoolsService } from '../pools/pools.service';
+import { RfqMakersService } from '../rfq/rfq-makers.service';
+import { RfqService } from '../rfq/rfq.service';
+import { RfqSide } from '../rfq/rfq.types';
+
+interface SeedFile {
+  balances?: Array<{ user: string; token: string; amount: number }>;
+  pools?: Array<{ token_a: string; token_b: string; reserve_a: number; reserve_b: number; storage_account: string }>;
+  makers?: Array<{ id: string; display_name?: string; verified?: boolean }>;
+  rfq_orders?: Array<{
+    pair: string;
+    side: RfqSide;
+    price: number;
+    size: number;
+    min_fill?: number;
+    expiry: string;
+    maker_id: string;
+  }>;
+}
+
+const DEFAULT_SEED_PATH = 'data/dev-seed.json';
+
+/**
+ * Dev-only startup seeding. When APP_ENV=dev and a seed file exists, pools,
+ * balances, makers and RFQ orders described in it are loaded through the
+ * regular service APIs on boot, so developers stop hand-recreating fixtures
+ * after every restart. A no-op outside dev or when the file is absent.
+ */
+@Injectable()
+export class DevSeedService implements OnModuleInit {
+  private readonly logger = new Logger(DevSeedService.name);
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly balances: BalancesService,
+    private readonly pools: PoolsService,
+    private readonly makers: RfqMakersService,
+    private readonly rfq: RfqService,
+  ) {}
+
+  onModuleInit(): void {
+    if (this.config.get<string>('APP_ENV') !== 'dev') {
+      return;
+    }
+    const seedPath = this.config.get<string>('DEV_SEED_PATH') || DEFAULT_SEED_PATH;
+    if (!existsSync(seedPath)) {
+      this.logger.log(`No dev seed file at ${seedPath}; skipping`);
+      return;
+    }
+
+    let seed: SeedFile;
+    try {
+      seed = JSON.parse(readFileSync(seedPath, 'utf8')) as SeedFile;
+    } catch (error) {
+      this.logger.error(`Failed to parse dev seed file at ${seedPath}`, error);
+      return;
+    }
+    this.apply(seed, seedPath);
+  }
+
+  private apply(seed: SeedFile, seedPath: string): void {
+    let applied = 0;
+    for (const entry of seed.balances ?? []) {
+      try {
+        this.balances.credit(entry.user, entry.token, entry.amount);
+        applied += 1;
+      } catch (error) {
+        this.logger.warn(`Skipping seed balance for ${entry.user}/${entry.token}: ${message(error)}`);
+      }
+    }
+    for (const entry of seed.pools ?? []) {
+      try {
+        this.pools.createPool(entry.token_a, entry.token_b, entry.reserve_a, entry.reserve_b, entry.storage_account);
+        applied += 1;
+      } catch (error) {
+        this.logger.warn(`Skipping seed pool ${entry.token_a}/${entry.token_b}: ${message(error)}`);
+      }
+    }
+    for (const entry of seed.makers ?? []) {
+      try {
+        const maker = this.makers.getOrRegister(entry.id);
+        if (entry.display_name) maker.display_name = entry.display_name;
+        if (entry.verified !== undefined) maker.verified = entry.verified;
+        applied += 1;
+      } catch (error) {
+        this.logger.warn(`Skipping seed maker ${entry.id}: ${message(error)}`);
+      }
+    }
+    for (const entry of seed.rfq_orders ?? []) {
+      try {
+        this.rfq.createOrder(entry);
+        applied += 1;
+      } catch (error) {
+        this.logger.warn(`Skipping seed RFQ order on ${entry.pair}: ${message(error)}`);
+      }
+    }
+    this.logger.log(`Applied ${applied} dev seed entries from ${seedPath}`);
+  }
+}
+
+function message(error: unknown): string {
+  return error instanceof Error ? error.message : 'unknown error';
+}
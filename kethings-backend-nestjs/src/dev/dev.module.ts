@@ -0,0 +1,13 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+
+import { DevSeedService } from './dev-seed.service';
+import { BalancesModule } from '../balances/balances.module';
+import { PoolsModule } from '../pools/pools.module';
+import { RfqModule } from '../rfq/rfq.module';
+
+@Module({
+  imports: [ConfigModule, BalancesModule, PoolsModule, RfqModule],
+  providers: [DevSeedService],
+})
+export class DevModule {}
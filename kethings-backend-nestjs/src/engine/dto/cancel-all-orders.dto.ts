@@ -0,0 +1,11 @@
+import { IsIn, IsOptional, IsString } from 'class-validator';
+
+export class CancelAllOrdersDto {
+  @IsOptional()
+  @IsString()
+  market?: string;
+
+  @IsOptional()
+  @IsIn(['buy', 'sell'])
+  side?: 'buy' | 'sell';
+}
@@ -0,0 +1,43 @@
+import { Type } from 'class-transformer';
+import { IsIn, IsNumber, IsOptional, IsPositive, IsString } from 'class-validator';
+
+export class PlaceOrderDto {
+  @IsString()
+  user_address!: string;
+
+  @IsString()
+  market!: string;
+
+  @IsIn(['buy', 'sell'])
+  side!: 'buy' | 'sell';
+
+  @IsIn(['limit', 'market'])
+  order_type!: 'limit' | 'market';
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  price?: number;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  quantity!: number;
+
+  @IsOptional()
+  @IsString()
+  activate_at?: string;
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  max_slippage?: number;
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  max_quote_spend?: number;
+}
@@ -0,0 +1,23 @@
+import { Type } from 'class-transformer';
+import { IsIn, IsNumber, IsOptional, IsPositive, IsString, Matches } from 'class-validator';
+
+export class RegisterMarketDto {
+  @IsString()
+  @Matches(/^[A-Za-z0-9]+\/[A-Za-z0-9]+$/, { message: 'market must be in BASE/QUOTE form' })
+  market!: string;
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  min_liquidity_notional?: number;
+
+  @IsOptional()
+  @IsIn(['public', 'token_gated'])
+  visibility?: 'public' | 'token_gated';
+
+  /** Token a viewer must hold to see a token-gated market's data. */
+  @IsOptional()
+  @IsString()
+  required_token?: string;
+}
@@ -0,0 +1,100 @@
+import { Injectable, Logger, ServiceUnavailableException } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+export type CommandPriority = 'low' | 'normal' | 'high';
+
+export interface EngineMetricsReport {
+  window_ms: number;
+  commands_in_window: number;
+  soft_limit: number;
+  hard_limit: number;
+  shedding_low_priority: boolean;
+  latency_ms: { p50: string; p95: string; p99: string };
+  rejections: Record<string, number>;
+}
+
+const WINDOW_MS = 1_000;
+const LATENCY_SAMPLES = 1_000;
+const DEFAULT_SOFT_LIMIT = 500;
+const DEFAULT_HARD_LIMIT = 1_000;
+
+/**
+ * Engine load visibility and graceful degradation. Every command is admitted
+ * through here: past the soft limit low-priority commands (quotes, book
+ * reads) are shed so order placement and cancels keep flowing; past the hard
+ * limit everything but cancels is rejected. Latency percentiles come from a
+ * rolling sample of recent command timings.
+ */
+@Injectable()
+export class EngineMetricsService {
+  private readonly logger = new Logger(EngineMetricsService.name);
+  private readonly commandTimestamps: number[] = [];
+  private readonly latencies: number[] = [];
+  private readonly rejections = new Map<string, number>();
+
+  constructor(private readonly config: ConfigService) {}
+
+  /** Throws when the current load does not admit the given priority. */
+  admit(priority: CommandPriority): void {
+    this.pruneWindow();
+    const load = this.commandTimestamps.length;
+    const soft = this.softLimit();
+    const hard = this.hardLimit();
+
+    if (priority !== 'high' && load >= hard) {
+      this.recordRejection('hard_limit');
+      throw new ServiceUnavailableException({ code: 'ENGINE_OVERLOADED', message: 'Engine is at hard capacity' });
+    }
+    if (priority === 'low' && load >= soft) {
+      this.recordRejection('soft_limit_shed');
+      throw new ServiceUnavailableException({ code: 'ENGINE_SHEDDING', message: 'Engine is shedding low-priority commands' });
+    }
+    this.commandTimestamps.push(Date.now());
+  }
+
+  /** Record how long a command took once processed. */
+  recordLatency(latencyMs: number): void {
+    this.latencies.push(latencyMs);
+    if (this.latencies.length > LATENCY_SAMPLES) {
+      this.latencies.splice(0, this.latencies.length - LATENCY_SAMPLES);
+    }
+  }
+
+  recordRejection(reason: string): void {
+    this.rejections.set(reason, (this.rejections.get(reason) ?? 0) + 1);
+  }
+
+  report(): EngineMetricsReport {
+    this.pruneWindow();
+    const sorted = [...this.latencies].sort((a, b) => a - b);
+    const percentile = (p: number) => (sorted.length > 0 ? sorted[Math.min(sorted.length - 1, Math.floor((p / 100) * sorted.length))] : 0);
+    return {
+      window_ms: WINDOW_MS,
+      commands_in_window: this.commandTimestamps.length,
+      soft_limit: this.softLimit(),
+      hard_limit: this.hardLimit(),
+      shedding_low_priority: this.commandTimestamps.length >= this.softLimit(),
+      latency_ms: {
+        p50: percentile(50).toString(),
+        p95: percentile(95).toString(),
+        p99: percentile(99).toString(),
+      },
+      rejections: Object.fromEntries(this.rejections),
+    };
+  }
+
+  private softLimit(): number {
+    return Number(this.config.get<string>('ENGINE_SOFT_CMD_LIMIT')) || DEFAULT_SOFT_LIMIT;
+  }
+
+  private hardLimit(): number {
+    return Number(this.config.get<string>('ENGINE_HARD_CMD_LIMIT')) || DEFAULT_HARD_LIMIT;
+  }
+
+  private pruneWindow(): void {
+    const cutoff = Date.now() - WINDOW_MS;
+    while (this.commandTimestamps.length > 0 && this.commandTimestamps[0] < cutoff) {
+      this.commandTimestamps.shift();
+    }
+  }
+}
@@ -0,0 +1,122 @@
+import { BadRequestException, Body, Controller, Delete, Get, Headers, Param, Post, Query, Req, UseGuards } from '@nestjs/common';
+import type { Request } from 'express';
+
+import { EngineService } from './engine.service';
+import { EngineMetricsService } from './engine-metrics.service';
+import { AdminGuard } from '../common/admin.guard';
+import { PreferencesService } from '../users/preferences.service';
+import { AuthService } from '../auth/auth.service';
+import { PlaceOrderDto } from './dto/place-order.dto';
+import { RegisterMarketDto } from './dto/register-market.dto';
+import { MarketsService } from './markets.service';
+import { DEFAULT_TENANT_ID, TenantsService } from '../tenants/tenants.service';
+
+@Controller('engine')
+export class EngineController {
+  constructor(
+    private readonly engine: EngineService,
+    private readonly metrics: EngineMetricsService,
+    private readonly preferences: PreferencesService,
+    private readonly markets: MarketsService,
+    private readonly auth: AuthService,
+    private readonly tenants: TenantsService,
+  ) {}
+
+  @Post('markets')
+  @UseGuards(AdminGuard)
+  registerMarket(@Body() body: RegisterMarketDto) {
+    return this.markets.register(body.market, {
+      minLiquidityNotional: body.min_liquidity_notional,
+      visibility: body.visibility,
+      requiredToken: body.required_token,
+    });
+  }
+
+  @Get('markets')
+  listMarkets(@Req() req: Request) {
+    // Listings are scoped to the requesting tenant's namespace so one
+    // white-label venue never sees another's markets.
+    const tenantId = (req as { tenantId?: string }).tenantId ?? DEFAULT_TENANT_ID;
+    return { markets: this.markets.list().filter((record) => this.tenants.scopesMarket(tenantId, record.market)) };
+  }
+
+  @Get('markets/:base/:quote')
+  getMarket(@Param('base') base: string, @Param('quote') quote: string) {
+    return this.markets.get(`${base}/${quote}`);
+  }
+
+  @Post('orders')
+  placeOrder(@Body() body: PlaceOrderDto) {
+    this.metrics.admit('normal');
+    const started = Date.now();
+    try {
+      if (body.order_type === 'market') {
+        return this.engine.placeMarketOrder(body.user_address, body.market, body.side, body.quantity, {
+          maxSlippage: body.max_slippage ?? this.preferences.defaultSlippage(body.user_address),
+          maxQuoteSpend: body.max_quote_spend,
+        });
+      }
+      if (body.price === undefined) {
+        throw new BadRequestException('price is required for limit orders');
+      }
+      return this.engine.placeLimitOrder(body.user_address, body.market, body.side, body.price, body.quantity, body.activate_at);
+    } finally {
+      this.metrics.recordLatency(Date.now() - started);
+    }
+  }
+
+  @Get('book/:base/:quote')
+  book(
+    @Param('base') base: string,
+    @Param('quote') quote: string,
+    @Query('user_address') userAddress?: string,
+    @Headers('x-session-token') sessionToken?: string,
+  ) {
+    this.metrics.admit('low');
+    this.markets.assertVisible(`${base}/${quote}`, this.viewer(userAddress, sessionToken));
+    const book = this.engine.getBook(`${base}/${quote}`);
+    const level = (orders: Array<{ price: number; remaining: number }>) =>
+      orders.map((order) => [order.price.toString(), order.remaining.toString()]);
+    return { market: `${base}/${quote}`, bids: level(book.bids), asks: level(book.asks) };
+  }
+
+  @Get('ticker/:base/:quote')
+  ticker(
+    @Param('base') base: string,
+    @Param('quote') quote: string,
+    @Query('user_address') userAddress?: string,
+    @Headers('x-session-token') sessionToken?: string,
+  ) {
+    this.metrics.admit('low');
+    this.markets.assertVisible(`${base}/${quote}`, this.viewer(userAddress, sessionToken));
+    return this.engine.ticker(`${base}/${quote}`);
+  }
+
+  @Delete('orders/:orderId')
+  cancelOrder(@Param('orderId') orderId: string, @Query('user_address') userAddress: string) {
+    this.metrics.admit('high');
+    const started = Date.now();
+    try {
+      return this.engine.cancelOrder(userAddress, orderId);
+    } finally {
+      this.metrics.recordLatency(Date.now() - started);
+    }
+  }
+
+  @Get('metrics')
+  @UseGuards(AdminGuard)
+  engineMetrics() {
+    return this.metrics.report();
+  }
+
+  /** A verified session wins over a bare user_address claim. */
+  private viewer(userAddress?: string, sessionToken?: string): string | undefined {
+    if (sessionToken) {
+      const session = this.auth.getSession(sessionToken);
+      if (session) {
+        return session.user_address;
+      }
+    }
+    return userAddress;
+  }
+}
@@ -0,0 +1,26 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+import { EngineService } from './engine.service';
+import { EngineMetricsService } from './engine-metrics.service';
+import { ExecutionReportsService } from './execution-reports.service';
+import { MarketsService } from './markets.service';
+import { AdminGuard } from '../common/admin.guard';
+import { EngineController } from './engine.controller';
+import { UsersOrdersController } from './users-orders.controller';
+import { OrdersController } from './orders.controller';
+import { BalancesModule } from '../balances/balances.module';
+import { PoolsModule } from '../pools/pools.module';
+import { UsersModule } from '../users/users.module';
+import { TradesModule } from '../trades/trades.module';
+import { SettlementModule } from '../settlement/settlement.module';
+import { AuthModule } from '../auth/auth.module';
+import { AuditModule } from '../audit/audit.module';
+import { TenantsModule } from '../tenants/tenants.module';
+
+@Module({
+  imports: [ConfigModule, BalancesModule, PoolsModule, UsersModule, TradesModule, SettlementModule, AuthModule, AuditModule, TenantsModule],
+  providers: [EngineService, EngineMetricsService, ExecutionReportsService, MarketsService, AdminGuard],
+  controllers: [EngineController, UsersOrdersController, OrdersController],
+  exports: [EngineService, MarketsService, ExecutionReportsService],
+})
+export class EngineModule {}
@@ -0,0 +1,803 @@
+import { BadRequestException, Injectable, Logger, NotFoundException } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { randomUUID } from 'crypto';
+import { Subject } from 'rxjs';
+
+import { BalancesService } from '../balances/balances.service';
+import { PoolsService } from '../pools/pools.service';
+import { TradesService } from '../trades/trades.service';
+import { NettingService } from '../settlement/netting.service';
+import { MarketsService } from './markets.service';
+import { AuditLogService } from '../audit/audit-log.service';
+import { ExecutionReportsService } from './execution-reports.service';
+
+export type OrderSide = 'buy' | 'sell';
+export type OrderStatus = 'scheduled' | 'open' | 'partially_filled' | 'filled' | 'cancelled';
+
+const TICKER_DEPTH_LEVELS = 5;
+
+export interface Order {
+  id: string;
+  user: string;
+  market: string;
+  side: OrderSide;
+  price: number;
+  quantity: number;
+  remaining: number;
+  status: OrderStatus;
+  createdAt: string;
+  /** ISO timestamp at which a scheduled order enters the book. */
+  activateAt?: string;
+  /** Worst acceptable execution price for a market order, derived from max slippage. */
+  priceCap?: number;
+  /** Remaining quote budget for a market buy capped by max_quote_spend. */
+  quoteBudget?: number;
+  /** Funds still held in reservation for a market order; released on completion. */
+  reservedRemaining?: number;
+}
+
+export interface MarketOrderOptions {
+  /** Maximum deviation from the reference price, as a fraction (0.01 = 1%). */
+  maxSlippage?: number;
+  /** For buys: the most quote currency the order may spend. */
+  maxQuoteSpend?: number;
+}
+
+export interface Fill {
+  price: number;
+  quantity: number;
+  source: 'book' | 'pool';
+}
+
+export interface FillReport {
+  order_id: string;
+  market: string;
+  side: OrderSide;
+  filled_quantity: string;
+  average_price: string;
+  residual: string;
+  fills: Array<{ price: string; quantity: string; source: 'book' | 'pool' }>;
+}
+
+interface OrderBook {
+  bids: Order[];
+  asks: Order[];
+}
+
+export interface DepthLevel {
+  price: string;
+  quantity: string;
+}
+
+export interface MarketTicker {
+  market: string;
+  last_price?: string;
+  best_bid?: string;
+  best_ask?: string;
+  spread?: string;
+  spread_bps?: string;
+  bid_depth: DepthLevel[];
+  ask_depth: DepthLevel[];
+  /**
+   * 0–100 tradability heuristic: total top-of-book notional dampened by the
+   * relative spread, so deep tight books rank above deep wide ones.
+   */
+  liquidity_score: string;
+}
+
+export type EngineEvent =
+  | { type: 'trade'; market: string; price: number; quantity: number; taker_side: OrderSide; at: string }
+  | { type: 'book'; market: string };
+
+@Injectable()
+export class EngineService {
+  private readonly logger = new Logger(EngineService.name);
+  private readonly orders = new Map<string, Order>();
+  private readonly books = new Map<string, OrderBook>();
+  private readonly lastPrices = new Map<string, number>();
+  private readonly activationTimers = new Map<string, ReturnType<typeof setTimeout>>();
+  /** Trade and book-change stream consumed by the WebSocket gateway. */
+  readonly events$ = new Subject<EngineEvent>();
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly balances: BalancesService,
+    private readonly pools: PoolsService,
+    private readonly trades: TradesService,
+    private readonly netting: NettingService,
+    private readonly markets: MarketsService,
+    private readonly auditLog: AuditLogService,
+    private readonly execReports: ExecutionReportsService,
+  ) {}
+
+  placeLimitOrder(user: string, market: string, side: OrderSide, price: number, quantity: number, activateAt?: string): Order {
+    const [base, quote] = this.splitMarket(market);
+    try {
+      this.enforcePriceBand(market, price);
+      this.enforceUserDepthLimit(user, market);
+    } catch (error) {
+      this.execReports.orderRejected(user, market, side, quantity, error instanceof Error ? error.message : 'rejected');
+      throw error;
+    }
+
+    if (activateAt !== undefined) {
+      const activationMs = Date.parse(activateAt);
+      if (!Number.isFinite(activationMs)) {
+        throw new BadRequestException({ code: 'INVALID_ACTIVATE_AT', message: `activate_at is not a valid timestamp: ${activateAt}` });
+      }
+      if (activationMs > Date.now()) {
+        return this.scheduleOrder(user, market, side, price, quantity, activateAt, activationMs);
+      }
+    }
+
+    if (side === 'buy') {
+      this.balances.reserve(user, quote, price * quantity);
+    } else {
+      this.balances.reserve(user, base, quantity);
+    }
+
+    const order: Order = {
+      id: randomUUID(),
+      user,
+      market,
+      side,
+      price,
+      quantity,
+      remaining: quantity,
+      status: 'open',
+      createdAt: new Date().toISOString(),
+    };
+    this.orders.set(order.id, order);
+    this.auditLog.record({
+      category: 'engine',
+      action: 'limit_order_placed',
+      actor: user,
+      subject: market,
+      after: { order_id: order.id, side, price: price.toString(), quantity: quantity.toString() },
+    });
+    this.execReports.orderNew(order);
+
+    // Pre-open markets accept limit orders to build liquidity but defer
+    // matching; the book uncrosses when the market opens.
+    if (this.markets.isPreOpen(market)) {
+      this.rest(order);
+      this.maybeOpenMarket(market);
+      return order;
+    }
+
+    this.matchAgainstBook(order);
+    if (order.remaining > 0) {
+      this.rest(order);
+    }
+    return order;
+  }
+
+  /**
+   * Market order with hybrid execution: fill as much as possible against the
+   * book, then route any residual quantity to the deepest pool for the pair.
+   * The fill report carries the blended average price across both sources.
+   *
+   * Funds are reserved up front — the full base quantity for sells, the quote
+   * spend cap for capped buys — consumed fill by fill, and any unspent
+   * reservation is released when the sweep stops, so a partial fill never
+   * leaves funds locked. `maxSlippage` bounds execution against the reference
+   * price and `maxQuoteSpend` bounds total quote outlay for buys.
+   */
+  placeMarketOrder(user: string, market: string, side: OrderSide, quantity: number, options: MarketOrderOptions = {}): FillReport {
+    const [base, quote] = this.splitMarket(market);
+    if (this.markets.isPreOpen(market)) {
+      this.execReports.orderRejected(user, market, side, quantity, 'MARKET_PRE_OPEN');
+      throw new BadRequestException({
+        code: 'MARKET_PRE_OPEN',
+        message: `Market ${market} has not met its minimum liquidity requirement; only resting limit orders are accepted`,
+      });
+    }
+    const order: Order = {
+      id: randomUUID(),
+      user,
+      market,
+      side,
+      price: 0,
+      quantity,
+      remaining: quantity,
+      status: 'open',
+      createdAt: new Date().toISOString(),
+    };
+    this.orders.set(order.id, order);
+    this.execReports.orderNew(order);
+
+    if (options.maxSlippage !== undefined) {
+      const reference = this.referencePrice(market);
+      if (reference !== undefined) {
+        order.priceCap = side === 'buy' ? reference * (1 + options.maxSlippage) : reference * (1 - options.maxSlippage);
+      }
+    }
+
+    if (side === 'sell') {
+      this.balances.reserve(user, base, quantity);
+      order.reservedRemaining = quantity;
+    } else if (options.maxQuoteSpend !== undefined) {
+      this.balances.reserve(user, quote, options.maxQuoteSpend);
+      order.reservedRemaining = options.maxQuoteSpend;
+      order.quoteBudget = options.maxQuoteSpend;
+    }
+
+    const fills = this.matchAgainstBook(order);
+
+    if (order.remaining > 0) {
+      const poolFill = this.fillAgainstPool(order);
+      if (poolFill) {
+        fills.push(poolFill);
+        this.execReports.orderFill(order, poolFill.price, poolFill.quantity);
+      }
+    }
+
+    // Release whatever part of the reservation the sweep did not consume.
+    if (order.reservedRemaining !== undefined && order.reservedRemaining > 0) {
+      this.balances.release(user, side === 'sell' ? base : quote, order.reservedRemaining);
+      order.reservedRemaining = 0;
+    }
+
+    const filled = order.quantity - order.remaining;
+    order.status = order.remaining === 0 ? 'filled' : filled > 0 ? 'partially_filled' : 'cancelled';
+
+    const notional = fills.reduce((sum, fill) => sum + fill.price * fill.quantity, 0);
+    this.auditLog.record({
+      category: 'engine',
+      action: 'market_order_executed',
+      actor: user,
+      subject: market,
+      after: { order_id: order.id, side, filled_quantity: filled.toString(), residual: order.remaining.toString(), status: order.status },
+    });
+    return {
+      order_id: order.id,
+      market,
+      side,
+      filled_quantity: filled.toString(),
+      average_price: (filled > 0 ? notional / filled : 0).toString(),
+      residual: order.remaining.toString(),
+      fills: fills.map((fill) => ({
+        price: fill.price.toString(),
+        quantity: fill.quantity.toString(),
+        source: fill.source,
+      })),
+    };
+  }
+
+  /**
+   * Panic button: cancel every resting and scheduled order for a user in one
+   * pass, releasing all associated reserves. Optional market/side filters
+   * flatten just one book or one direction.
+   */
+  cancelAllOrders(user: string, market?: string, side?: OrderSide): { cancelled: number; order_ids: string[] } {
+    const cancelled: string[] = [];
+    for (const order of this.orders.values()) {
+      if (order.user !== user) continue;
+      if (order.status !== 'open' && order.status !== 'partially_filled' && order.status !== 'scheduled') continue;
+      if (market !== undefined && order.market !== market) continue;
+      if (side !== undefined && order.side !== side) continue;
+      this.cancelOrder(user, order.id);
+      cancelled.push(order.id);
+    }
+    return { cancelled: cancelled.length, order_ids: cancelled };
+  }
+
+  cancelOrder(user: string, orderId: string): Order {
+    const order = this.orders.get(orderId);
+    if (!order || order.user !== user) {
+      throw new NotFoundException(`Order ${orderId} not found`);
+    }
+    if (order.status === 'filled' || order.status === 'cancelled') {
+      return order;
+    }
+
+    // Scheduled orders have no reservation yet and are not in the book.
+    if (order.status === 'scheduled') {
+      const timer = this.activationTimers.get(orderId);
+      if (timer) {
+        clearTimeout(timer);
+        this.activationTimers.delete(orderId);
+      }
+      order.status = 'cancelled';
+      this.auditOrderCancel(order, 'scheduled');
+      this.execReports.orderCancelled(order);
+      return order;
+    }
+
+    const [base, quote] = this.splitMarket(order.market);
+    if (order.side === 'buy') {
+      this.balances.release(user, quote, order.price * order.remaining);
+    } else {
+      this.balances.release(user, base, order.remaining);
+    }
+    this.removeFromBook(order);
+    // Keep remaining as the unfilled quantity so filled_quantity stays
+    // derivable after cancellation; status alone gates matching.
+    const previousStatus = order.status;
+    order.status = 'cancelled';
+    this.auditOrderCancel(order, previousStatus);
+    this.execReports.orderCancelled(order);
+    return order;
+  }
+
+  private auditOrderCancel(order: Order, previousStatus: OrderStatus): void {
+    this.auditLog.record({
+      category: 'engine',
+      action: 'order_cancelled',
+      actor: order.user,
+      subject: order.market,
+      before: { order_id: order.id, status: previousStatus },
+      after: { order_id: order.id, status: order.status, remaining: order.remaining.toString() },
+    });
+  }
+
+  getOrder(orderId: string): Order {
+    const order = this.orders.get(orderId);
+    if (!order) {
+      throw new NotFoundException(`Order ${orderId} not found`);
+    }
+    return order;
+  }
+
+  /** Orders filtered by owner and optionally market/status, newest first. */
+  listOrders(user: string, market?: string, status?: OrderStatus): Order[] {
+    const matched: Order[] = [];
+    for (const order of this.orders.values()) {
+      if (order.user !== user) continue;
+      if (market !== undefined && order.market !== market) continue;
+      if (status !== undefined && order.status !== status) continue;
+      matched.push(order);
+    }
+    return matched.sort((a, b) => b.createdAt.localeCompare(a.createdAt));
+  }
+
+  getBook(market: string): OrderBook {
+    let book = this.books.get(market);
+    if (!book) {
+      book = { bids: [], asks: [] };
+      this.books.set(market, book);
+    }
+    return book;
+  }
+
+  /** Price of the most recent trade in the market, if any. */
+  getLastPrice(market: string): number | undefined {
+    return this.lastPrices.get(market);
+  }
+
+  /** Live-book spread and depth metrics so the markets overview can rank pairs. */
+  ticker(market: string): MarketTicker {
+    const book = this.getBook(market);
+    const aggregate = (orders: Order[]): DepthLevel[] => {
+      const levels = new Map<number, number>();
+      for (const order of orders) {
+        if (order.status !== 'open' && order.status !== 'partially_filled') continue;
+        levels.set(order.price, (levels.get(order.price) ?? 0) + order.remaining);
+        if (levels.size > TICKER_DEPTH_LEVELS) break;
+      }
+      return Array.from(levels.entries())
+        .slice(0, TICKER_DEPTH_LEVELS)
+        .map(([price, quantity]) => ({ price: price.toString(), quantity: quantity.toString() }));
+    };
+
+    const bidDepth = aggregate(book.bids);
+    const askDepth = aggregate(book.asks);
+    const bestBid = bidDepth.length > 0 ? Number(bidDepth[0].price) : undefined;
+    const bestAsk = askDepth.length > 0 ? Number(askDepth[0].price) : undefined;
+
+    let spread: number | undefined;
+    let spreadBps: number | undefined;
+    if (bestBid !== undefined && bestAsk !== undefined) {
+      spread = bestAsk - bestBid;
+      const mid = (bestAsk + bestBid) / 2;
+      spreadBps = mid > 0 ? (spread / mid) * 10_000 : undefined;
+    }
+
+    const notional = (levels: DepthLevel[]) =>
+      levels.reduce((sum, level) => sum + Number(level.price) * Number(level.quantity), 0);
+    const depthNotional = notional(bidDepth) + notional(askDepth);
+    // log10 keeps whales from saturating the scale; a missing side or a wide
+    // spread drags the score toward zero.
+    const spreadPenalty = spreadBps !== undefined ? 1 / (1 + spreadBps / 100) : 0;
+    const liquidityScore = Math.min(100, Math.log10(1 + depthNotional) * 20 * spreadPenalty);
+
+    return {
+      market,
+      last_price: this.lastPrices.get(market)?.toString(),
+      best_bid: bestBid?.toString(),
+      best_ask: bestAsk?.toString(),
+      spread: spread?.toString(),
+      spread_bps: spreadBps?.toFixed(2),
+      bid_depth: bidDepth,
+      ask_depth: askDepth,
+      liquidity_score: liquidityScore.toFixed(2),
+    };
+  }
+
+  /** Reserved funds implied by resting orders, summed per token. */
+  openOrderReserves(): Map<string, number> {
+    const reserves = new Map<string, number>();
+    for (const book of this.books.values()) {
+      for (const order of [...book.bids, ...book.asks]) {
+        if (order.status !== 'open' && order.status !== 'partially_filled') continue;
+        const [base, quote] = this.splitMarket(order.market);
+        const token = order.side === 'buy' ? quote : base;
+        const amount = order.side === 'buy' ? order.price * order.remaining : order.remaining;
+        reserves.set(token, (reserves.get(token) ?? 0) + amount);
+      }
+    }
+    return reserves;
+  }
+
+  /**
+   * Check a pre-open market against its liquidity threshold and, on
+   * transition, uncross the book: any bids that accumulated above asks while
+   * matching was deferred execute against them in price-time order.
+   */
+  private maybeOpenMarket(market: string): void {
+    const book = this.getBook(market);
+    const notional = (orders: Order[]) =>
+      orders.reduce((sum, order) => sum + order.price * order.remaining, 0);
+    if (!this.markets.maybeOpen(market, notional(book.bids), notional(book.asks))) {
+      return;
+    }
+
+    const crossedBids = [...book.bids];
+    for (const bid of crossedBids) {
+      if (bid.remaining === 0 || (bid.status !== 'open' && bid.status !== 'partially_filled')) continue;
+      const bestAsk = book.asks[0];
+      if (!bestAsk || bid.price < bestAsk.price) break;
+      this.removeFromBook(bid);
+      this.matchAgainstBook(bid);
+      if (bid.remaining > 0) {
+        this.rest(bid);
+      }
+    }
+  }
+
+  /**
+   * Funds are reserved at activation time, not scheduling time, so a
+   * scheduled order can still fail and be cancelled if the balance is gone.
+   */
+  private scheduleOrder(
+    user: string,
+    market: string,
+    side: OrderSide,
+    price: number,
+    quantity: number,
+    activateAt: string,
+    activationMs: number,
+  ): Order {
+    const order: Order = {
+      id: randomUUID(),
+      user,
+      market,
+      side,
+      price,
+      quantity,
+      remaining: quantity,
+      status: 'scheduled',
+      createdAt: new Date().toISOString(),
+      activateAt,
+    };
+    this.orders.set(order.id, order);
+    this.execReports.orderNew(order);
+
+    const timer = setTimeout(() => this.activateOrder(order), activationMs - Date.now());
+    this.activationTimers.set(order.id, timer);
+    return order;
+  }
+
+  private activateOrder(order: Order): void {
+    this.activationTimers.delete(order.id);
+    if (order.status !== 'scheduled') {
+      return;
+    }
+
+    const [base, quote] = this.splitMarket(order.market);
+    try {
+      if (order.side === 'buy') {
+        this.balances.reserve(order.user, quote, order.price * order.quantity);
+      } else {
+        this.balances.reserve(order.user, base, order.quantity);
+      }
+    } catch (error) {
+      order.status = 'cancelled';
+      this.logger.warn(`Scheduled order ${order.id} cancelled at activation: ${error instanceof Error ? error.message : 'reserve failed'}`);
+      this.execReports.orderCancelled(order, 'activation reserve failed');
+      return;
+    }
+
+    order.status = 'open';
+    this.matchAgainstBook(order);
+    if (order.remaining > 0 && order.status !== 'filled') {
+      this.rest(order);
+    }
+  }
+
+  /**
+   * Fat-finger protection: reject limit orders priced outside the configured
+   * band around the current mid. Falls back from book mid to last trade to
+   * pool spot; with no reference price at all the check is skipped.
+   */
+  private enforcePriceBand(market: string, price: number): void {
+    const reference = this.referencePrice(market);
+    if (reference === undefined) {
+      return;
+    }
+    const bandPct = this.priceBandPct(market);
+    const deviation = Math.abs(price - reference) / reference;
+    if (deviation > bandPct) {
+      throw new BadRequestException({
+        code: 'PRICE_OUT_OF_BAND',
+        message: `Price ${price} deviates ${(deviation * 100).toFixed(1)}% from reference ${reference}; allowed band is ${(bandPct * 100).toFixed(1)}%`,
+      });
+    }
+  }
+
+  private enforceUserDepthLimit(user: string, market: string): void {
+    const maxOrders = Number(this.config.get<string>('ENGINE_MAX_USER_ORDERS_PER_MARKET')) || 50;
+    const book = this.getBook(market);
+    const open = [...book.bids, ...book.asks].filter((order) => order.user === user).length;
+    if (open >= maxOrders) {
+      throw new BadRequestException({
+        code: 'USER_DEPTH_LIMIT',
+        message: `User has ${open} open orders in ${market}; limit is ${maxOrders}`,
+      });
+    }
+  }
+
+  private priceBandPct(market: string): number {
+    const raw = this.config.get<string>('ENGINE_PRICE_BANDS');
+    if (raw) {
+      try {
+        const bands = JSON.parse(raw) as Record<string, number>;
+        const band = bands[market];
+        if (typeof band === 'number' && band > 0) {
+          return band;
+        }
+      } catch {
+        this.logger.warn('Failed to parse ENGINE_PRICE_BANDS JSON; using default band');
+      }
+    }
+    return Number(this.config.get<string>('ENGINE_PRICE_BAND_PCT')) || 0.2;
+  }
+
+  private referencePrice(market: string): number | undefined {
+    const book = this.getBook(market);
+    const bestBid = book.bids[0]?.price;
+    const bestAsk = book.asks[0]?.price;
+    if (bestBid !== undefined && bestAsk !== undefined) {
+      return (bestBid + bestAsk) / 2;
+    }
+    const last = this.lastPrices.get(market);
+    if (last !== undefined) {
+      return last;
+    }
+    const [base, quote] = this.splitMarket(market);
+    const pool = this.pools.findPool(base, quote);
+    if (pool) {
+      const [reserveBase, reserveQuote] = pool.tokenA === base ? [pool.reserveA, pool.reserveB] : [pool.reserveB, pool.reserveA];
+      return reserveBase > 0 ? reserveQuote / reserveBase : undefined;
+    }
+    return bestBid ?? bestAsk;
+  }
+
+  private matchAgainstBook(taker: Order): Fill[] {
+    const book = this.getBook(taker.market);
+    const [base, quote] = this.splitMarket(taker.market);
+    const resting = taker.side === 'buy' ? book.asks : book.bids;
+    const fills: Fill[] = [];
+    const isMarket = taker.price === 0;
+
+    while (taker.remaining > 0 && resting.length > 0) {
+      const maker = resting[0];
+      const crosses = isMarket || (taker.side === 'buy' ? taker.price >= maker.price : taker.price <= maker.price);
+      if (!crosses) {
+        break;
+      }
+      // Stop sweeping once the book price breaches the slippage cap.
+      if (taker.priceCap !== undefined && (taker.side === 'buy' ? maker.price > taker.priceCap : maker.price < taker.priceCap)) {
+        break;
+      }
+
+      let quantity = Math.min(taker.remaining, maker.remaining);
+      const price = maker.price;
+      if (taker.quoteBudget !== undefined) {
+        quantity = Math.min(quantity, taker.quoteBudget / price);
+        if (!(quantity > 0)) {
+          break;
+        }
+      }
+      const buyer = taker.side === 'buy' ? taker : maker;
+      const seller = taker.side === 'buy' ? maker : taker;
+
+      const takerUnreserved = isMarket && taker.reservedRemaining === undefined;
+      this.settleBookTrade(buyer, seller, base, quote, price, quantity, takerUnreserved && taker.side === 'buy', takerUnreserved && taker.side === 'sell');
+      if (taker.reservedRemaining !== undefined && isMarket) {
+        taker.reservedRemaining -= taker.side === 'buy' ? price * quantity : quantity;
+      }
+      if (taker.quoteBudget !== undefined) {
+        taker.quoteBudget -= price * quantity;
+      }
+
+      taker.remaining -= quantity;
+      maker.remaining -= quantity;
+      fills.push({ price, quantity, source: 'book' });
+      this.execReports.orderFill(taker, price, quantity);
+      this.execReports.orderFill(maker, price, quantity);
+      this.trades.record({
+        market: taker.market,
+        price,
+        quantity,
+        maker_user: maker.user,
+        taker_user: taker.user,
+        taker_side: taker.side,
+        source: 'book',
+      });
+      this.lastPrices.set(taker.market, price);
+      this.events$.next({ type: 'trade', market: taker.market, price, quantity, taker_side: taker.side, at: new Date().toISOString() });
+
+      if (maker.remaining === 0) {
+        maker.status = 'filled';
+        resting.shift();
+      } else {
+        maker.status = 'partially_filled';
+      }
+    }
+
+    if (taker.remaining === 0) {
+      taker.status = 'filled';
+    } else if (taker.remaining < taker.quantity) {
+      taker.status = 'partially_filled';
+    }
+    if (fills.length > 0) {
+      this.events$.next({ type: 'book', market: taker.market });
+    }
+    return fills;
+  }
+
+  private settleBookTrade(
+    buyer: Order,
+    seller: Order,
+    base: string,
+    quote: string,
+    price: number,
+    quantity: number,
+    buyerUnreserved: boolean,
+    sellerUnreserved: boolean,
+  ): void {
+    const notional = price * quantity;
+
+    // All legs of the settlement post atomically so a failure on one side
+    // cannot leave the other side half-settled.
+    this.balances.transaction((tx) => {
+      if (buyerUnreserved) {
+        tx.debit(buyer.user, quote, notional);
+      } else if (buyer.price === 0) {
+        // Market buy with a reserved quote budget: consume exactly the notional.
+        tx.consumeReserved(buyer.user, quote, notional);
+      } else {
+        tx.consumeReserved(buyer.user, quote, buyer.price * quantity);
+        // Refund the difference between the limit price and the execution price.
+        const refund = (buyer.price - price) * quantity;
+        if (refund > 0) {
+          tx.credit(buyer.user, quote, refund);
+        }
+      }
+      tx.credit(buyer.user, base, quantity);
+
+      if (sellerUnreserved) {
+        tx.debit(seller.user, base, quantity);
+      } else {
+        tx.consumeReserved(seller.user, base, quantity);
+      }
+      tx.credit(seller.user, quote, notional);
+    });
+
+    this.netting.recordTradeObligations(buyer.user, seller.user, base, quote, price, quantity, `${buyer.id}:${seller.id}`);
+  }
+
+  private fillAgainstPool(order: Order): Fill | null {
+    const [base, quote] = this.splitMarket(order.market);
+    const pool = this.pools.findPool(base, quote);
+    if (!pool) {
+      return null;
+    }
+
+    try {
+      if (order.side === 'sell') {
+        const quoted = this.pools.quote(pool.id, base, order.remaining);
+        const expectedPrice = Number(quoted.amount_out) / order.remaining;
+        if (order.priceCap !== undefined && expectedPrice < order.priceCap) {
+          return null;
+        }
+        // Pool swaps debit the available balance, so hand back the reservation first.
+        if (order.reservedRemaining !== undefined) {
+          this.balances.release(order.user, base, order.remaining);
+          order.reservedRemaining -= order.remaining;
+        }
+        const result = this.pools.swap(order.user, pool, base, order.remaining);
+        const fill: Fill = { price: result.amountOut / order.remaining, quantity: order.remaining, source: 'pool' };
+        this.trades.record({
+          market: order.market,
+          price: fill.price,
+          quantity: fill.quantity,
+          maker_user: pool.id,
+          taker_user: order.user,
+          taker_side: order.side,
+          source: 'pool',
+        });
+        order.remaining = 0;
+        return fill;
+      }
+
+      let quoteIn = this.pools.quoteExactOut(pool, quote, order.remaining);
+      if (order.quoteBudget !== undefined) {
+        quoteIn = Math.min(quoteIn, order.quoteBudget);
+        if (!(quoteIn > 0)) {
+          return null;
+        }
+      }
+      if (order.priceCap !== undefined) {
+        const quoted = this.pools.quote(pool.id, quote, quoteIn);
+        const expectedPrice = quoteIn / Number(quoted.amount_out);
+        if (expectedPrice > order.priceCap) {
+          return null;
+        }
+      }
+      if (order.reservedRemaining !== undefined) {
+        this.balances.release(order.user, quote, quoteIn);
+        order.reservedRemaining -= quoteIn;
+        order.quoteBudget = (order.quoteBudget ?? quoteIn) - quoteIn;
+      }
+      const result = this.pools.swap(order.user, pool, quote, quoteIn);
+      const received = result.amountOut;
+      const fill: Fill = { price: quoteIn / received, quantity: received, source: 'pool' };
+      this.trades.record({
+        market: order.market,
+        price: fill.price,
+        quantity: fill.quantity,
+        maker_user: pool.id,
+        taker_user: order.user,
+        taker_side: order.side,
+        source: 'pool',
+      });
+      order.remaining = Math.max(0, order.remaining - received);
+      return fill;
+    } catch (error) {
+      this.logger.warn(`Pool fallback failed for order ${order.id}`, error);
+      return null;
+    }
+  }
+
+  private rest(order: Order): void {
+    const book = this.getBook(order.market);
+    if (order.side === 'buy') {
+      book.bids.push(order);
+      book.bids.sort((a, b) => b.price - a.price || a.createdAt.localeCompare(b.createdAt));
+    } else {
+      book.asks.push(order);
+      book.asks.sort((a, b) => a.price - b.price || a.createdAt.localeCompare(b.createdAt));
+    }
+    this.events$.next({ type: 'book', market: order.market });
+  }
+
+  private removeFromBook(order: Order): void {
+    const book = this.getBook(order.market);
+    const side = order.side === 'buy' ? book.bids : book.asks;
+    const index = side.findIndex((resting) => resting.id === order.id);
+    if (index >= 0) {
+      side.splice(index, 1);
+      this.events$.next({ type: 'book', market: order.market });
+    }
+  }
+
+  private splitMarket(market: string): [string, string] {
+    const [base, quote] = market.split('/');
+    if (!base || !quote) {
+      throw new Error(`Invalid market pair: ${market}`);
+    }
+    return [base, quote];
+  }
+}
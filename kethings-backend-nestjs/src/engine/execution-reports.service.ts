@@ -0,0 +1,128 @@
+import { Injectable } from '@nestjs/common';
+import { randomUUID } from 'crypto';
+import { Subject } from 'rxjs';
+
+export type ExecType = 'new' | 'partial_fill' | 'fill' | 'cancelled' | 'rejected';
+
+/**
+ * FIX-style execution report: one record per order event, carrying the
+ * cumulative fill state institutional clients expect (CumQty, LeavesQty,
+ * AvgPx) without the wire protocol itself.
+ */
+export interface ExecutionReport {
+  report_id: string;
+  order_id: string;
+  user_address: string;
+  market: string;
+  side: 'buy' | 'sell';
+  exec_type: ExecType;
+  order_status: string;
+  last_price?: string;
+  last_quantity?: string;
+  cumulative_quantity: string;
+  leaves_quantity: string;
+  average_price: string;
+  reason?: string;
+  at: string;
+}
+
+/** The slice of an engine order the reports need; avoids an import cycle. */
+interface OrderView {
+  id: string;
+  user: string;
+  market: string;
+  side: 'buy' | 'sell';
+  quantity: number;
+  remaining: number;
+  status: string;
+}
+
+const MAX_TRACKED_ORDERS = 10_000;
+
+/**
+ * Emits an execution report for every order lifecycle event (new, partial
+ * fill, fill, cancel, reject) and keeps the per-order history queryable.
+ * Reports fan out on the `executions:{user_address}` WS channel so clients
+ * track order state without polling.
+ */
+@Injectable()
+export class ExecutionReportsService {
+  private readonly reports = new Map<string, ExecutionReport[]>();
+  /** Running CumQty / notional per order, for AvgPx across fills. */
+  private readonly fillState = new Map<string, { cumulative: number; notional: number }>();
+  readonly events$ = new Subject<ExecutionReport>();
+
+  orderNew(order: OrderView): void {
+    this.record(order, 'new');
+  }
+
+  orderFill(order: OrderView, price: number, quantity: number): void {
+    const state = this.fillState.get(order.id) ?? { cumulative: 0, notional: 0 };
+    state.cumulative += quantity;
+    state.notional += price * quantity;
+    this.fillState.set(order.id, state);
+    // Derive the status from the remaining quantity: callers emit fills
+    // mid-sweep, before the engine writes the final status back.
+    const status = order.remaining === 0 ? 'filled' : 'partially_filled';
+    this.record({ ...order, status }, order.remaining === 0 ? 'fill' : 'partial_fill', {
+      lastPrice: price,
+      lastQuantity: quantity,
+    });
+  }
+
+  orderCancelled(order: OrderView, reason?: string): void {
+    this.record(order, 'cancelled', { reason });
+  }
+
+  /** Rejections happen before an order exists; synthesize the order fields. */
+  orderRejected(user: string, market: string, side: 'buy' | 'sell', quantity: number, reason: string): void {
+    this.record(
+      { id: randomUUID(), user, market, side, quantity, remaining: quantity, status: 'rejected' },
+      'rejected',
+      { reason },
+    );
+  }
+
+  reportsFor(orderId: string): ExecutionReport[] {
+    return this.reports.get(orderId) ?? [];
+  }
+
+  private record(
+    order: OrderView,
+    execType: ExecType,
+    extra: { lastPrice?: number; lastQuantity?: number; reason?: string } = {},
+  ): void {
+    const state = this.fillState.get(order.id) ?? { cumulative: 0, notional: 0 };
+    const report: ExecutionReport = {
+      report_id: randomUUID(),
+      order_id: order.id,
+      user_address: order.user,
+      market: order.market,
+      side: order.side,
+      exec_type: execType,
+      order_status: execType === 'rejected' ? 'rejected' : order.status,
+      ...(extra.lastPrice !== undefined ? { last_price: extra.lastPrice.toString() } : {}),
+      ...(extra.lastQuantity !== undefined ? { last_quantity: extra.lastQuantity.toString() } : {}),
+      cumulative_quantity: state.cumulative.toString(),
+      leaves_quantity: order.remaining.toString(),
+      average_price: (state.cumulative > 0 ? state.notional / state.cumulative : 0).toString(),
+      ...(extra.reason !== undefined ? { reason: extra.reason } : {}),
+      at: new Date().toISOString(),
+    };
+
+    const history = this.reports.get(order.id) ?? [];
+    history.push(report);
+    this.reports.set(order.id, history);
+    this.prune();
+    this.events$.next(report);
+  }
+
+  private prune(): void {
+    // Maps iterate in insertion order, so the first key is the oldest order.
+    while (this.reports.size > MAX_TRACKED_ORDERS) {
+      const oldest = this.reports.keys().next().value as string;
+      this.reports.delete(oldest);
+      this.fillState.delete(oldest);
+    }
+  }
+}
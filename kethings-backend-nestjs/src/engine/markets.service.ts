@@ -0,0 +1,125 @@
+import { ForbiddenException, Injectable, Logger, NotFoundException } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+import { BalancesService } from '../balances/balances.service';
+
+export type MarketState = 'pre_open' | 'trading';
+
+export type MarketVisibility = 'public' | 'token_gated';
+
+export interface MarketRecord {
+  market: string;
+  state: MarketState;
+  /** Resting notional required on each side before trading opens. */
+  min_liquidity_notional: number;
+  /** Token-gated markets hide their data from users not holding the token. */
+  visibility: MarketVisibility;
+  required_token?: string;
+  created_at: string;
+  opened_at?: string;
+}
+
+export interface RegisterMarketOptions {
+  minLiquidityNotional?: number;
+  visibility?: MarketVisibility;
+  requiredToken?: string;
+}
+
+const DEFAULT_MIN_LIQUIDITY_NOTIONAL = 1000;
+
+/**
+ * Market registry with a liquidity guardrail. Registered markets start in
+ * `pre_open`: limit orders rest without matching until both sides carry the
+ * minimum notional, at which point the market transitions to `trading` and
+ * the book uncrosses. Markets never registered here are implicitly trading,
+ * preserving the old lazily-created-book behaviour.
+ */
+@Injectable()
+export class MarketsService {
+  private readonly logger = new Logger(MarketsService.name);
+  private readonly markets = new Map<string, MarketRecord>();
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly balances: BalancesService,
+  ) {}
+
+  register(market: string, options: RegisterMarketOptions = {}): MarketRecord {
+    const existing = this.markets.get(market);
+    if (existing) {
+      return existing;
+    }
+    if (options.visibility === 'token_gated' && !options.requiredToken) {
+      throw new Error('required_token must be set for a token-gated market');
+    }
+    const fallback = Number(this.config.get<string>('MARKET_MIN_LIQUIDITY_NOTIONAL')) || DEFAULT_MIN_LIQUIDITY_NOTIONAL;
+    const record: MarketRecord = {
+      market,
+      state: 'pre_open',
+      min_liquidity_notional: options.minLiquidityNotional ?? fallback,
+      visibility: options.visibility ?? 'public',
+      required_token: options.requiredToken,
+      created_at: new Date().toISOString(),
+    };
+    this.markets.set(market, record);
+    this.logger.log(`Registered market ${market} in pre_open (min liquidity ${record.min_liquidity_notional})`);
+    return record;
+  }
+
+  list(): MarketRecord[] {
+    return Array.from(this.markets.values());
+  }
+
+  get(market: string): MarketRecord {
+    const record = this.markets.get(market);
+    if (!record) {
+      throw new NotFoundException(`Market ${market} is not registered`);
+    }
+    return record;
+  }
+
+  isPreOpen(market: string): boolean {
+    return this.markets.get(market)?.state === 'pre_open';
+  }
+
+  /**
+   * Whether a viewer may see the market's data. Public and unregistered
+   * markets are visible to everyone; token-gated markets require the viewer
+   * to hold any amount of the gating token in the internal ledger.
+   */
+  canView(market: string, viewerAddress?: string): boolean {
+    const record = this.markets.get(market);
+    if (!record || record.visibility === 'public' || !record.required_token) {
+      return true;
+    }
+    if (!viewerAddress) {
+      return false;
+    }
+    const balance = this.balances.getBalance(viewerAddress, record.required_token);
+    return balance.available + balance.reserved > 0;
+  }
+
+  assertVisible(market: string, viewerAddress?: string): void {
+    if (!this.canView(market, viewerAddress)) {
+      throw new ForbiddenException({
+        code: 'MARKET_RESTRICTED',
+        message: `Market ${market} is restricted; holding the gating token is required to view it`,
+      });
+    }
+  }
+
+  /** Transition to trading once both sides meet the minimum; false otherwise. */
+  maybeOpen(market: string, bidNotional: number, askNotional: number): boolean {
+    const record = this.markets.get(market);
+    if (!record || record.state !== 'pre_open') {
+      return false;
+    }
+    if (bidNotional < record.min_liquidity_notional || askNotional < record.min_liquidity_notional) {
+      return false;
+    }
+    record.state = 'trading';
+    record.opened_at = new Date().toISOString();
+    this.logger.log(`Market ${market} opened for trading (bids ${bidNotional}, asks ${askNotional})`);
+    return true;
+  }
+}
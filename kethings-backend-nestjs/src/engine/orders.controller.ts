@@ -0,0 +1,102 @@
+import { BadRequestException, Body, Controller, Delete, Get, Param, Post, Query } from '@nestjs/common';
+
+import { EngineService, Order, OrderStatus } from './engine.service';
+import { EngineMetricsService } from './engine-metrics.service';
+import { ExecutionReportsService } from './execution-reports.service';
+import { PreferencesService } from '../users/preferences.service';
+import { PlaceOrderDto } from './dto/place-order.dto';
+
+const ORDER_STATUSES: OrderStatus[] = ['scheduled', 'open', 'partially_filled', 'filled', 'cancelled'];
+
+/**
+ * Order lifecycle surface: place, list, inspect and cancel. Backed by the
+ * same in-memory order map the matching engine uses, so status and
+ * filled_quantity reflect the book in real time.
+ */
+@Controller('orders')
+export class OrdersController {
+  constructor(
+    private readonly engine: EngineService,
+    private readonly metrics: EngineMetricsService,
+    private readonly preferences: PreferencesService,
+    private readonly execReports: ExecutionReportsService,
+  ) {}
+
+  @Post()
+  placeOrder(@Body() body: PlaceOrderDto) {
+    this.metrics.admit('normal');
+    const started = Date.now();
+    try {
+      if (body.order_type === 'market') {
+        return this.engine.placeMarketOrder(body.user_address, body.market, body.side, body.quantity, {
+          maxSlippage: body.max_slippage ?? this.preferences.defaultSlippage(body.user_address),
+          maxQuoteSpend: body.max_quote_spend,
+        });
+      }
+      if (body.price === undefined) {
+        throw new BadRequestException('price is required for limit orders');
+      }
+      return this.engine.placeLimitOrder(body.user_address, body.market, body.side, body.price, body.quantity, body.activate_at);
+    } finally {
+      this.metrics.recordLatency(Date.now() - started);
+    }
+  }
+
+  @Get()
+  listOrders(
+    @Query('user_address') userAddress: string,
+    @Query('market') market?: string,
+    @Query('status') status?: string,
+  ) {
+    this.metrics.admit('low');
+    if (!userAddress) {
+      throw new BadRequestException('user_address query parameter is required');
+    }
+    if (status !== undefined && !ORDER_STATUSES.includes(status as OrderStatus)) {
+      throw new BadRequestException(`status must be one of: ${ORDER_STATUSES.join(', ')}`);
+    }
+    const orders = this.engine.listOrders(userAddress, market, status as OrderStatus | undefined);
+    return { orders: orders.map((order) => this.serialize(order)) };
+  }
+
+  @Get(':orderId')
+  getOrder(@Param('orderId') orderId: string) {
+    this.metrics.admit('low');
+    return this.serialize(this.engine.getOrder(orderId));
+  }
+
+  /** FIX-style execution report history for one order, oldest first. */
+  @Get(':orderId/executions')
+  executions(@Param('orderId') orderId: string) {
+    this.metrics.admit('low');
+    this.engine.getOrder(orderId);
+    return { order_id: orderId, reports: this.execReports.reportsFor(orderId) };
+  }
+
+  @Delete(':orderId')
+  cancelOrder(@Param('orderId') orderId: string, @Query('user_address') userAddress: string) {
+    this.metrics.admit('high');
+    const started = Date.now();
+    try {
+      return this.serialize(this.engine.cancelOrder(userAddress, orderId));
+    } finally {
+      this.metrics.recordLatency(Date.now() - started);
+    }
+  }
+
+  private serialize(order: Order) {
+    return {
+      order_id: order.id,
+      user_address: order.user,
+      market: order.market,
+      side: order.side,
+      price: order.price.toString(),
+      quantity: order.quantity.toString(),
+      remaining: order.remaining.toString(),
+      filled_quantity: (order.quantity - order.remaining).toString(),
+      status: order.status,
+      created_at: order.createdAt,
+      activate_at: order.activateAt,
+    };
+  }
+}
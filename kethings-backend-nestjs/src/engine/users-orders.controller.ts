@@ -0,0 +1,14 @@
+import { Body, Controller, Param, Post } from '@nestjs/common';
+
+import { EngineService } from './engine.service';
+import { CancelAllOrdersDto } from './dto/cancel-all-orders.dto';
+
+@Controller('users')
+export class UsersOrdersController {
+  constructor(private readonly engine: EngineService) {}
+
+  @Post(':userAddress/orders/cancel-all')
+  cancelAll(@Param('userAddress') userAddress: string, @Body() body: CancelAllOrdersDto) {
+    return this.engine.cancelAllOrders(userAddress, body.market, body.side);
+  }
+}
@@ -0,0 +1,27 @@
+import { BadRequestException, Body, Controller, Post } from '@nestjs/common';
+
+import { TypedIntent, intentDigest, verifyIntentDigest } from './typed-intents';
+
+@Controller('intents')
+export class IntentsController {
+  @Post('digest')
+  digest(@Body() intent: TypedIntent) {
+    try {
+      return { digest: intentDigest(intent) };
+    } catch (error) {
+      throw new BadRequestException(error instanceof Error ? error.message : 'Invalid intent');
+    }
+  }
+
+  @Post('verify')
+  verify(@Body() body: { intent: TypedIntent; digest: string }) {
+    if (!body?.intent || typeof body?.digest !== 'string') {
+      throw new BadRequestException('intent and digest are required');
+    }
+    try {
+      return { valid: verifyIntentDigest(body.intent, body.digest) };
+    } catch (error) {
+      throw new BadRequestException(error instanceof Error ? error.message : 'Invalid intent');
+    }
+  }
+}
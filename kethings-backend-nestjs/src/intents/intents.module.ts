@@ -0,0 +1,7 @@
+import { Module } from '@nestjs/common';
+import { IntentsController } from './intents.controller';
+
+@Module({
+  controllers: [IntentsController],
+})
+export class IntentsModule {}
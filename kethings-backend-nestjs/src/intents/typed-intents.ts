@@ -0,0 +1,84 @@
+import { createHash } from 'crypto';
+
+/**
+ * Canonical typed signing payloads for Keeta intents, the counterpart of the
+ * frontend module in src/app/lib/typed-intents.ts. Wallets render the typed
+ * fields; both sides must produce byte-identical serialization so digests
+ * match. Field order is fixed per type — do not reorder.
+ */
+
+export const INTENT_DOMAIN = 'keythings-dex-v1';
+
+export interface OrderIntent {
+  type: 'order';
+  user_address: string;
+  market: string;
+  side: 'buy' | 'sell';
+  order_type: 'limit' | 'market';
+  price: string;
+  quantity: string;
+  nonce: string;
+}
+
+export interface WithdrawalIntent {
+  type: 'withdrawal';
+  user_address: string;
+  token: string;
+  amount: string;
+  destination: string;
+  nonce: string;
+}
+
+export interface PoolActionIntent {
+  type: 'pool_action';
+  user_address: string;
+  pool_id: string;
+  action: 'add_liquidity' | 'remove_liquidity' | 'swap';
+  amount_a: string;
+  amount_b: string;
+  nonce: string;
+}
+
+export interface AclSetupIntent {
+  type: 'acl_setup';
+  user_address: string;
+  storage_account: string;
+  /** Comma-joined permission names, sorted ascending by the client. */
+  permissions: string;
+  nonce: string;
+}
+
+export type TypedIntent = OrderIntent | WithdrawalIntent | PoolActionIntent | AclSetupIntent;
+
+const FIELD_ORDER: Record<TypedIntent['type'], string[]> = {
+  order: ['type', 'user_address', 'market', 'side', 'order_type', 'price', 'quantity', 'nonce'],
+  withdrawal: ['type', 'user_address', 'token', 'amount', 'destination', 'nonce'],
+  pool_action: ['type', 'user_address', 'pool_id', 'action', 'amount_a', 'amount_b', 'nonce'],
+  acl_setup: ['type', 'user_address', 'storage_account', 'permissions', 'nonce'],
+};
+
+/** Deterministic serialization: domain prefix plus fixed-order field list. */
+export function serializeIntent(intent: TypedIntent): string {
+  const order = FIELD_ORDER[intent.type];
+  if (!order) {
+    throw new Error(`Unknown intent type: ${(intent as { type: string }).type}`);
+  }
+  const record = intent as unknown as Record<string, unknown>;
+  const parts = order.map((field) => {
+    const value = record[field];
+    if (typeof value !== 'string') {
+      throw new Error(`Intent field ${field} must be a string`);
+    }
+    return `${field}=${value}`;
+  });
+  return `${INTENT_DOMAIN}|${parts.join('|')}`;
+}
+
+/** Hex SHA-256 digest of the canonical serialization. */
+export function intentDigest(intent: TypedIntent): string {
+  return createHash('sha256').update(serializeIntent(intent), 'utf8').digest('hex');
+}
+
+export function verifyIntentDigest(intent: TypedIntent, digest: string): boolean {
+  return intentDigest(intent) === digest.toLowerCase();
+}
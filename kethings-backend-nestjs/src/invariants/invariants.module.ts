@@ -0,0 +1,13 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+import { InvariantsService } from './invariants.service';
+import { BalancesModule } from '../balances/balances.module';
+import { EngineModule } from '../engine/engine.module';
+import { PoolsModule } from '../pools/pools.module';
+
+@Module({
+  imports: [ConfigModule, BalancesModule, EngineModule, PoolsModule],
+  providers: [InvariantsService],
+  exports: [InvariantsService],
+})
+export class InvariantsModule {}
@@ -0,0 +1,128 @@
+import { Injectable, Logger, OnModuleDestroy, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+import { BalancesService } from '../balances/balances.service';
+import { EngineService } from '../engine/engine.service';
+import { PoolsService } from '../pools/pools.service';
+
+export interface InvariantViolation {
+  invariant: string;
+  detail: string;
+  observedAt: string;
+}
+
+const DEFAULT_INTERVAL_MS = 30_000;
+// Floating point bookkeeping accumulates rounding error; treat differences
+// below this as equal until amounts move to fixed-point.
+const EPSILON = 1e-6;
+
+/**
+ * Periodic cross-module consistency checker. Disabled by default; enable via
+ * INVARIANT_CHECKS_ENABLED=true in debug/staging deployments.
+ */
+@Injectable()
+export class InvariantsService implements OnModuleInit, OnModuleDestroy {
+  private readonly logger = new Logger(InvariantsService.name);
+  private timer?: ReturnType<typeof setInterval>;
+  private lastViolations: InvariantViolation[] = [];
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly balances: BalancesService,
+    private readonly engine: EngineService,
+    private readonly pools: PoolsService,
+  ) {}
+
+  onModuleInit(): void {
+    const enabled = (this.config.get<string>('INVARIANT_CHECKS_ENABLED') || '').toLowerCase() === 'true';
+    if (!enabled) {
+      return;
+    }
+    const intervalMs = Number(this.config.get<string>('INVARIANT_CHECK_INTERVAL_MS')) || DEFAULT_INTERVAL_MS;
+    this.timer = setInterval(() => this.runChecks(), intervalMs);
+    this.logger.log(`Invariant checks enabled, running every ${intervalMs}ms`);
+  }
+
+  onModuleDestroy(): void {
+    if (this.timer) {
+      clearInterval(this.timer);
+    }
+  }
+
+  getLastViolations(): InvariantViolation[] {
+    return this.lastViolations;
+  }
+
+  runChecks(): InvariantViolation[] {
+    const violations: InvariantViolation[] = [
+      ...this.checkReservedMatchesOpenOrders(),
+      ...this.checkLpSupplyMatchesBalances(),
+      ...this.checkNoNegativeBalances(),
+    ];
+
+    for (const violation of violations) {
+      this.logger.error(`INVARIANT VIOLATION [${violation.invariant}]: ${violation.detail}`);
+    }
+    this.lastViolations = violations;
+    return violations;
+  }
+
+  private checkReservedMatchesOpenOrders(): InvariantViolation[] {
+    const violations: InvariantViolation[] = [];
+    const expected = this.engine.openOrderReserves();
+    const actual = new Map<string, number>();
+    for (const entry of this.balances.snapshot()) {
+      actual.set(entry.token, (actual.get(entry.token) ?? 0) + entry.reserved);
+    }
+
+    const tokens = new Set([...expected.keys(), ...actual.keys()]);
+    for (const token of tokens) {
+      const expectedAmount = expected.get(token) ?? 0;
+      const actualAmount = actual.get(token) ?? 0;
+      if (Math.abs(expectedAmount - actualAmount) > EPSILON) {
+        violations.push({
+          invariant: 'reserved_equals_open_orders',
+          detail: `token ${token}: ledger reserved ${actualAmount} != engine open-order reserves ${expectedAmount}`,
+          observedAt: new Date().toISOString(),
+        });
+      }
+    }
+    return violations;
+  }
+
+  private checkLpSupplyMatchesBalances(): InvariantViolation[] {
+    const violations: InvariantViolation[] = [];
+    const lpTotals = new Map<string, number>();
+    for (const entry of this.balances.snapshot()) {
+      if (entry.token.startsWith('LP-')) {
+        lpTotals.set(entry.token, (lpTotals.get(entry.token) ?? 0) + entry.available + entry.reserved + entry.vault);
+      }
+    }
+
+    for (const pool of this.pools.allPools()) {
+      const tracked = lpTotals.get(pool.lpToken) ?? 0;
+      if (Math.abs(tracked - pool.totalLpSupply) > EPSILON) {
+        violations.push({
+          invariant: 'lp_supply_equals_tracked_balances',
+          detail: `pool ${pool.id}: LP supply ${pool.totalLpSupply} != tracked LP balances ${tracked}`,
+          observedAt: new Date().toISOString(),
+        });
+      }
+    }
+    return violations;
+  }
+
+  private checkNoNegativeBalances(): InvariantViolation[] {
+    const violations: InvariantViolation[] = [];
+    for (const entry of this.balances.snapshot()) {
+      if (entry.available < -EPSILON || entry.reserved < -EPSILON) {
+        violations.push({
+          invariant: 'no_negative_balances',
+          detail: `user ${entry.user} token ${entry.token}: available ${entry.available}, reserved ${entry.reserved}`,
+          observedAt: new Date().toISOString(),
+        });
+      }
+    }
+    return violations;
+  }
+}
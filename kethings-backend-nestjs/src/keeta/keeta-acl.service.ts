@@ -0,0 +1,117 @@
+import { BadRequestException, Injectable, Logger } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+import { KeetaSdkService } from './keeta-sdk.service';
+import { KeetaRpcService } from './keeta-rpc.service';
+
+export type AclMode = 'permissive' | 'strict';
+
+export interface AclMetrics {
+  mode: AclMode;
+  checks: number;
+  passed: number;
+  missing_permissions: number;
+  errored: number;
+  permissive_allowed: number;
+  strict_rejected: number;
+}
+
+/** Permissions a user needs on the deposit storage account. */
+const REQUIRED_DEPOSIT_PERMISSIONS = ['ACCESS'];
+
+/**
+ * On-chain ACL verification with an explicit permissive/strict switch.
+ * Permissive mode (the default, matching the old warn-and-continue
+ * behaviour) lets failed or errored checks through but counts every outcome,
+ * so operators can watch the metrics and flip ACL_MODE=strict once the
+ * numbers look clean. Strict mode rejects with the missing permissions
+ * named, instead of silently masking real permission problems.
+ */
+@Injectable()
+export class KeetaAclService {
+  private readonly logger = new Logger(KeetaAclService.name);
+  private readonly counters = {
+    checks: 0,
+    passed: 0,
+    missing_permissions: 0,
+    errored: 0,
+    permissive_allowed: 0,
+    strict_rejected: 0,
+  };
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly keeta: KeetaSdkService,
+    private readonly rpc: KeetaRpcService,
+  ) {}
+
+  mode(): AclMode {
+    return this.config.get<string>('ACL_MODE') === 'strict' ? 'strict' : 'permissive';
+  }
+
+  metrics(): AclMetrics {
+    return { mode: this.mode(), ...this.counters };
+  }
+
+  /**
+   * Verify the user holds the permissions needed to deposit into the
+   * storage account. Outcome handling depends on the mode: strict rejects
+   * on missing permissions or probe errors, permissive allows and counts.
+   */
+  async verifyUserCanDeposit(userAddress: string, storageAccount: string): Promise<void> {
+    this.counters.checks += 1;
+    let missing: string[];
+    try {
+      missing = await this.missingPermissions(userAddress, storageAccount);
+    } catch (error) {
+      this.counters.errored += 1;
+      const reason = error instanceof Error ? error.message : 'ACL probe failed';
+      if (this.mode() === 'strict') {
+        this.counters.strict_rejected += 1;
+        throw new BadRequestException({
+          code: 'ACL_VERIFICATION_FAILED',
+          message: `Could not verify deposit permissions for ${userAddress}: ${reason}`,
+          required_permissions: REQUIRED_DEPOSIT_PERMISSIONS,
+        });
+      }
+      this.counters.permissive_allowed += 1;
+      this.logger.warn(`ACL check errored for ${userAddress} (permissive mode, allowing): ${reason}`);
+      return;
+    }
+
+    if (missing.length === 0) {
+      this.counters.passed += 1;
+      return;
+    }
+
+    this.counters.missing_permissions += 1;
+    if (this.mode() === 'strict') {
+      this.counters.strict_rejected += 1;
+      throw new BadRequestException({
+        code: 'ACL_PERMISSION_MISSING',
+        message: `Account ${userAddress} is missing permissions on ${storageAccount}: ${missing.join(', ')}`,
+        missing_permissions: missing,
+        required_permissions: REQUIRED_DEPOSIT_PERMISSIONS,
+      });
+    }
+    this.counters.permissive_allowed += 1;
+    this.logger.warn(`ACL check missing [${missing.join(', ')}] for ${userAddress} (permissive mode, allowing)`);
+  }
+
+  private async missingPermissions(userAddress: string, storageAccount: string): Promise<string[]> {
+    const account = await this.keeta.accountFromPublicKey(userAddress);
+    const storage = await this.keeta.accountFromPublicKey(storageAccount);
+    const client = await this.keeta.getReadClient(storageAccount);
+    const acls = await this.rpc.call('listACLsByEntity', () => client.listACLsByEntity({ account: storage }));
+    const entries = Array.isArray(acls) ? acls : [];
+    const granted = new Set<string>();
+    for (const entry of entries as Array<{ principal?: { publicKeyString?: { toString(): string } }; permissions?: { base?: { flags?: string[] } } }>) {
+      const principal = entry.principal?.publicKeyString?.toString();
+      if (principal !== account.publicKeyString?.toString()) continue;
+      for (const flag of entry.permissions?.base?.flags ?? []) {
+        granted.add(flag);
+      }
+    }
+    return REQUIRED_DEPOSIT_PERMISSIONS.filter((permission) => !granted.has(permission));
+  }
+}
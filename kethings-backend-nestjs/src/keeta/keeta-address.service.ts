@@ -0,0 +1,95 @@
+import { BadRequestException, Injectable, Logger } from '@nestjs/common';
+
+import { KeetaSdkService } from './keeta-sdk.service';
+import { KeetaRpcService } from './keeta-rpc.service';
+
+const ADDRESS_PREFIX = 'keeta_';
+const ADDRESS_LENGTHS = new Set([67, 69]);
+const ADDRESS_BODY_REGEX = /^[a-z0-9]+$/;
+
+export interface AddressValidationReport {
+  address: string;
+  format_valid: boolean;
+  checksum_valid: boolean;
+  exists_onchain?: boolean;
+}
+
+/**
+ * Destination-address validation for withdrawals. Format checks are cheap
+ * and local; checksum verification goes through the SDK's address decoder so
+ * we stay in sync with whatever encoding the network actually uses; the
+ * optional existence probe asks a node whether the account has any state.
+ * Each failure mode gets its own error code so callers can tell a typo from
+ * an account that simply has not been used yet.
+ */
+@Injectable()
+export class KeetaAddressService {
+  private readonly logger = new Logger(KeetaAddressService.name);
+
+  constructor(
+    private readonly keeta: KeetaSdkService,
+    private readonly rpc: KeetaRpcService,
+  ) {}
+
+  /** Throws with a specific error code when the address is unusable. */
+  async assertValidDestination(address: string, options: { checkExistence?: boolean } = {}): Promise<AddressValidationReport> {
+    this.assertFormat(address);
+
+    try {
+      await this.keeta.accountFromPublicKey(address);
+    } catch (error) {
+      throw new BadRequestException({
+        code: 'INVALID_ADDRESS_CHECKSUM',
+        message: `Address ${address} has a valid shape but failed checksum verification: ${error instanceof Error ? error.message : 'decode failed'}`,
+      });
+    }
+
+    const report: AddressValidationReport = { address, format_valid: true, checksum_valid: true };
+
+    if (options.checkExistence) {
+      report.exists_onchain = await this.existsOnChain(address);
+      if (!report.exists_onchain) {
+        throw new BadRequestException({
+          code: 'ADDRESS_NOT_FOUND',
+          message: `Address ${address} is well-formed but has no on-chain state; double-check the destination before retrying`,
+        });
+      }
+    }
+
+    return report;
+  }
+
+  private assertFormat(address: string): void {
+    if (typeof address !== 'string' || !address.startsWith(ADDRESS_PREFIX)) {
+      throw new BadRequestException({
+        code: 'INVALID_ADDRESS_FORMAT',
+        message: `Destination must be a Keeta address starting with "${ADDRESS_PREFIX}"`,
+      });
+    }
+    if (!ADDRESS_LENGTHS.has(address.length)) {
+      throw new BadRequestException({
+        code: 'INVALID_ADDRESS_FORMAT',
+        message: `Destination has length ${address.length}; Keeta addresses are ${Array.from(ADDRESS_LENGTHS).join(' or ')} characters`,
+      });
+    }
+    const body = address.slice(ADDRESS_PREFIX.length);
+    if (!ADDRESS_BODY_REGEX.test(body)) {
+      throw new BadRequestException({
+        code: 'INVALID_ADDRESS_FORMAT',
+        message: 'Destination contains characters outside the Keeta address alphabet (lowercase letters and digits)',
+      });
+    }
+  }
+
+  private async existsOnChain(address: string): Promise<boolean> {
+    try {
+      const account = await this.keeta.accountFromPublicKey(address);
+      const client = await this.keeta.getReadClient(address);
+      const state = await this.rpc.call('state', () => client.state({ account }));
+      return state !== null && state !== undefined;
+    } catch (error) {
+      this.logger.warn(`Existence check for ${address.substring(0, 20)}... failed: ${error instanceof Error ? error.message : 'unknown error'}`);
+      return false;
+    }
+  }
+}
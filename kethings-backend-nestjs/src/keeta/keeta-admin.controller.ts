@@ -0,0 +1,24 @@
+import { Controller, Get, UseGuards } from '@nestjs/common';
+
+import { KeetaRpcService } from './keeta-rpc.service';
+import { KeetaAclService } from './keeta-acl.service';
+import { AdminGuard } from '../common/admin.guard';
+
+@Controller('admin/keeta')
+@UseGuards(AdminGuard)
+export class KeetaAdminController {
+  constructor(
+    private readonly rpc: KeetaRpcService,
+    private readonly acl: KeetaAclService,
+  ) {}
+
+  @Get('breakers')
+  breakers() {
+    return { breakers: this.rpc.report() };
+  }
+
+  @Get('acl-metrics')
+  aclMetrics() {
+    return this.acl.metrics();
+  }
+}
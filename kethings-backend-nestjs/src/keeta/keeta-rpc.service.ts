@@ -0,0 +1,106 @@
+import { Injectable, Logger, ServiceUnavailableException } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+export type BreakerState = 'closed' | 'open' | 'half_open';
+
+interface Breaker {
+  state: BreakerState;
+  consecutiveFailures: number;
+  openedAt?: number;
+  lastError?: string;
+}
+
+export interface BreakerReport {
+  endpoint: string;
+  state: BreakerState;
+  consecutive_failures: number;
+  last_error?: string;
+}
+
+const DEFAULT_FAILURE_THRESHOLD = 5;
+const DEFAULT_COOLDOWN_MS = 30_000;
+const DEFAULT_TIMEOUT_MS = 10_000;
+
+/**
+ * Per-endpoint circuit breakers and timeout budgets for Keeta RPC calls.
+ * Every RPC goes through call(): a run of failures opens the breaker so a
+ * sick node stops eating the timeout budget on every request; after the
+ * cooldown a single half-open probe decides whether to close it again.
+ */
+@Injectable()
+export class KeetaRpcService {
+  private readonly logger = new Logger(KeetaRpcService.name);
+  private readonly breakers = new Map<string, Breaker>();
+
+  constructor(private readonly config: ConfigService) {}
+
+  async call<T>(endpoint: string, fn: () => Promise<T>): Promise<T> {
+    const breaker = this.breaker(endpoint);
+    const cooldownMs = Number(this.config.get<string>('KEETA_RPC_COOLDOWN_MS')) || DEFAULT_COOLDOWN_MS;
+
+    if (breaker.state === 'open') {
+      if (Date.now() - (breaker.openedAt ?? 0) < cooldownMs) {
+        throw new ServiceUnavailableException({
+          code: 'KEETA_RPC_CIRCUIT_OPEN',
+          message: `Keeta RPC endpoint ${endpoint} is unavailable (circuit open)`,
+        });
+      }
+      breaker.state = 'half_open';
+      this.logger.log(`Circuit for ${endpoint} half-open; sending probe request`);
+    }
+
+    try {
+      const result = await this.withTimeout(endpoint, fn());
+      breaker.state = 'closed';
+      breaker.consecutiveFailures = 0;
+      return result;
+    } catch (error) {
+      this.recordFailure(endpoint, breaker, error);
+      throw error;
+    }
+  }
+
+  report(): BreakerReport[] {
+    return Array.from(this.breakers.entries()).map(([endpoint, breaker]) => ({
+      endpoint,
+      state: breaker.state,
+      consecutive_failures: breaker.consecutiveFailures,
+      last_error: breaker.lastError,
+    }));
+  }
+
+  private breaker(endpoint: string): Breaker {
+    let breaker = this.breakers.get(endpoint);
+    if (!breaker) {
+      breaker = { state: 'closed', consecutiveFailures: 0 };
+      this.breakers.set(endpoint, breaker);
+    }
+    return breaker;
+  }
+
+  private recordFailure(endpoint: string, breaker: Breaker, error: unknown): void {
+    breaker.consecutiveFailures += 1;
+    breaker.lastError = error instanceof Error ? error.message : 'unknown error';
+    const threshold = Number(this.config.get<string>('KEETA_RPC_FAILURE_THRESHOLD')) || DEFAULT_FAILURE_THRESHOLD;
+    if (breaker.state === 'half_open' || breaker.consecutiveFailures >= threshold) {
+      breaker.state = 'open';
+      breaker.openedAt = Date.now();
+      this.logger.warn(`Circuit for ${endpoint} opened after ${breaker.consecutiveFailures} consecutive failures: ${breaker.lastError}`);
+    }
+  }
+
+  private async withTimeout<T>(endpoint: string, promise: Promise<T>): Promise<T> {
+    const timeoutMs = Number(this.config.get<string>('KEETA_RPC_TIMEOUT_MS')) || DEFAULT_TIMEOUT_MS;
+    let timer: ReturnType<typeof setTimeout> | undefined;
+    try {
+      return await Promise.race([
+        promise,
+        new Promise<never>((_, reject) => {
+          timer = setTimeout(() => reject(new Error(`Keeta RPC ${endpoint} timed out after ${timeoutMs}ms`)), timeoutMs);
+        }),
+      ]);
+    } finally {
+      if (timer) clearTimeout(timer);
+    }
+  }
+}
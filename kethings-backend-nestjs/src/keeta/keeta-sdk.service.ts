@@ -0,0 +1,149 @@
+import { Injectable, Logger } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+export type KeetaNetworkName = 'test' | 'main' | 'staging' | 'dev';
+
+/**
+ * Single integration point for the Keeta network SDK. Account derivation,
+ * client construction, block building/signing and submission all live here so
+ * the ledger, settlement and RFQ modules stop re-importing the client
+ * package ad hoc. The SDK is loaded lazily and every entry point degrades
+ * with a descriptive error when the installed SDK does not expose the
+ * expected surface, since the client API is still evolving.
+ */
+const READ_CLIENT_CACHE_LIMIT = 100;
+
+@Injectable()
+export class KeetaSdkService {
+  private readonly logger = new Logger(KeetaSdkService.name);
+  /** Cached read-only clients keyed by public key, for connection reuse. */
+  private readonly readClients = new Map<string, Promise<any>>();
+
+  constructor(private readonly config: ConfigService) {}
+
+  resolveNetwork(): KeetaNetworkName {
+    const raw = (this.config.get<string>('KEETA_NETWORK') || 'test').toLowerCase();
+    if (raw === 'main' || raw === 'mainnet') return 'main';
+    if (raw === 'staging') return 'staging';
+    if (raw === 'dev' || raw === 'development') return 'dev';
+    return 'test';
+  }
+
+  /** Resolve a read-only account handle from a public key string. */
+  async accountFromPublicKey(publicKey: string): Promise<unknown> {
+    if (!publicKey || typeof publicKey !== 'string') {
+      throw new Error('Invalid public key provided');
+    }
+    const KeetaNet = await this.sdk();
+    const maybe = (KeetaNet as any)?.lib?.Account?.fromPublicKeyString?.(publicKey);
+    if (maybe === undefined) {
+      throw new Error('Keeta SDK does not expose Account.fromPublicKeyString');
+    }
+    return typeof (maybe as any)?.then === 'function' ? await maybe : maybe;
+  }
+
+  /**
+   * True when the supplied raw public key (base64) is the key encoded in the
+   * account address. Binds caller-supplied keys to the account they claim to
+   * act for: a signature that verifies against an unbound key proves nothing.
+   */
+  async addressMatchesPublicKey(address: string, publicKeyBase64: string): Promise<boolean> {
+    const account = await this.accountFromPublicKey(address);
+    const raw = (account as any)?.publicKey?.get?.() ?? (account as any)?.publicKey;
+    const encoded =
+      raw instanceof Uint8Array
+        ? Buffer.from(raw)
+        : typeof (raw as any)?.toBytes === 'function'
+          ? Buffer.from((raw as any).toBytes())
+          : undefined;
+    if (!encoded || encoded.length === 0) {
+      throw new Error('Keeta SDK account does not expose raw public key bytes');
+    }
+    const supplied = Buffer.from(publicKeyBase64, 'base64');
+    if (supplied.length === 0 || supplied.length > encoded.length) {
+      return false;
+    }
+    // Some SDK versions prefix the key bytes with an algorithm tag, so the
+    // supplied key must match the tail of the encoded key.
+    return encoded.subarray(encoded.length - supplied.length).equals(supplied);
+  }
+
+  /** Derive a signing account from seed material, for operator-held keys. */
+  async accountFromSeed(seed: string, index = 0): Promise<unknown> {
+    const KeetaNet = await this.sdk();
+    const factory = (KeetaNet as any)?.lib?.Account?.fromSeed;
+    if (typeof factory !== 'function') {
+      throw new Error('Keeta SDK does not expose Account.fromSeed');
+    }
+    const maybe = factory(seed, index);
+    return typeof (maybe as any)?.then === 'function' ? await maybe : maybe;
+  }
+
+  /**
+   * Network client. Read-only when constructed with just an account; pass a
+   * signer account to enable block submission.
+   */
+  async getClient(options: { account?: unknown; signer?: unknown } = {}): Promise<any> {
+    const KeetaNet = await this.sdk();
+    const network = this.resolveNetwork();
+    const factory = (KeetaNet as any)?.UserClient?.fromNetwork;
+    if (typeof factory !== 'function') {
+      throw new Error('KeetaNet.UserClient.fromNetwork is unavailable');
+    }
+    if (options.signer) {
+      return factory(network, options.signer);
+    }
+    return options.account ? factory(network, null, { account: options.account }) : factory(network, null);
+  }
+
+  /**
+   * Cached read-only client for a public key. Re-creating a client per
+   * request throws away connection state; reuse keeps latency flat and lets
+   * the underlying transport pool connections.
+   */
+  getReadClient(publicKey: string): Promise<any> {
+    let cached = this.readClients.get(publicKey);
+    if (!cached) {
+      cached = this.accountFromPublicKey(publicKey).then((account) => this.getClient({ account }));
+      cached.catch(() => this.readClients.delete(publicKey));
+      if (this.readClients.size >= READ_CLIENT_CACHE_LIMIT) {
+        const oldest = this.readClients.keys().next().value;
+        if (oldest !== undefined) {
+          this.readClients.delete(oldest);
+        }
+      }
+      this.readClients.set(publicKey, cached);
+    }
+    return cached;
+  }
+
+  /**
+   * Build, sign and submit a block of operations with the given signer. The
+   * builder surface differs across SDK versions, so each step is probed and
+   * a missing capability fails loudly rather than submitting a partial block.
+   */
+  async buildAndSubmit(signer: unknown, build: (builder: any) => void | Promise<void>): Promise<unknown> {
+    const client = await this.getClient({ signer });
+    const initBuilder = client?.initBuilder ?? client?.builder;
+    if (typeof initBuilder !== 'function') {
+      throw new Error('Keeta client does not expose a block builder');
+    }
+    const builder = await initBuilder.call(client);
+    await build(builder);
+    const publish = client?.publishBuilder ?? client?.publish ?? builder?.publish;
+    if (typeof publish !== 'function') {
+      throw new Error('Keeta client does not expose a publish method');
+    }
+    this.logger.log(`Submitting block to ${this.resolveNetwork()} network`);
+    return publish === builder?.publish ? await publish.call(builder) : await publish.call(client, builder);
+  }
+
+  private async sdk(): Promise<unknown> {
+    try {
+      return await import('@keetanetwork/keetanet-client');
+    } catch (error) {
+      this.logger.error('Failed to load @keetanetwork/keetanet-client', error);
+      throw new Error('Keeta SDK is not available');
+    }
+  }
+}
@@ -0,0 +1,16 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+import { KeetaSdkService } from './keeta-sdk.service';
+import { KeetaRpcService } from './keeta-rpc.service';
+import { KeetaAddressService } from './keeta-address.service';
+import { KeetaAclService } from './keeta-acl.service';
+import { KeetaAdminController } from './keeta-admin.controller';
+import { AdminGuard } from '../common/admin.guard';
+
+@Module({
+  imports: [ConfigModule],
+  providers: [KeetaSdkService, KeetaRpcService, KeetaAddressService, KeetaAclService, AdminGuard],
+  controllers: [KeetaAdminController],
+  exports: [KeetaSdkService, KeetaRpcService, KeetaAddressService, KeetaAclService],
+})
+export class KeetaModule {}
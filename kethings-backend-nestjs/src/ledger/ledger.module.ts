@@ -2,10 +2,13 @@ import { Module } from '@nestjs/common';
 import { ConfigModule } from '@nestjs/config';
 import { LedgerService } from './ledger.service';
 import { LedgerController } from './ledger.controller';
+import { TokensModule } from '../tokens/tokens.module';
+import { KeetaModule } from '../keeta/keeta.module';
 
 @Module({
-  imports: [ConfigModule],
+  imports: [ConfigModule, TokensModule, KeetaModule],
   providers: [LedgerService],
   controllers: [LedgerController],
+  exports: [LedgerService],
 })
 export class LedgerModule {}
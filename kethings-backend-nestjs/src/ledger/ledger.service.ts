@@ -1,7 +1,8 @@
 import { Injectable, Logger } from '@nestjs/common';
-import { ConfigService } from '@nestjs/config';
 
-type KeetaNetworkName = 'test' | 'main' | 'staging' | 'dev';
+import { TokensService } from '../tokens/tokens.service';
+import { KeetaSdkService, KeetaNetworkName } from '../keeta/keeta-sdk.service';
+import { KeetaRpcService } from '../keeta/keeta-rpc.service';
 
 type VoteStaple = Record<string, unknown>;
 
@@ -54,72 +55,25 @@ export interface LedgerOperationsResponse {
 export class LedgerService {
   private readonly logger = new Logger(LedgerService.name);
 
-  constructor(private readonly config: ConfigService) {}
+  constructor(
+    private readonly tokens: TokensService,
+    private readonly keeta: KeetaSdkService,
+    private readonly rpc: KeetaRpcService,
+  ) {}
 
   private resolveNetwork(): KeetaNetworkName {
-    const raw = (this.config.get<string>('KEETA_NETWORK') || 'test').toLowerCase();
-    if (raw === 'main' || raw === 'mainnet') return 'main';
-    if (raw === 'staging') return 'staging';
-    if (raw === 'dev' || raw === 'development') return 'dev';
-    return 'test';
+    return this.keeta.resolveNetwork();
   }
 
   private async toAccountFromPublicKey(publicKey: string): Promise<unknown> {
     try {
-      this.logger.log(`Creating account from public key: ${publicKey.substring(0, 20)}...`);
-      
-      if (!publicKey || typeof publicKey !== 'string') {
-        throw new Error('Invalid public key provided');
-      }
-
-      // Dynamic import to avoid initialization issues
-      const KeetaNet = await import('@keetanetwork/keetanet-client');
-      const maybe = (KeetaNet as any)?.lib?.Account?.fromPublicKeyString?.(publicKey);
-      if (maybe && typeof (maybe as any).then === 'function') {
-        const account = await maybe;
-        this.logger.log(`Successfully created account from public key`);
-        return account;
-      }
-      
-      this.logger.log(`Account created synchronously`);
-      return maybe;
+      return await this.keeta.accountFromPublicKey(publicKey);
     } catch (error) {
       this.logger.error(`Error creating account from public key ${publicKey.substring(0, 20)}...:`, error);
       throw new Error(`Failed to create account from public key: ${error instanceof Error ? error.message : 'Unknown error'}`);
     }
   }
 
-  private async getClient(account?: unknown) {
-    try {
-      this.logger.log(`Creating Keeta client for network: ${this.resolveNetwork()}`);
-      
-      // Dynamic import to avoid initialization issues
-      const KeetaNet = await import('@keetanetwork/keetanet-client');
-      const network = this.resolveNetwork();
-      const factory = (KeetaNet as any)?.UserClient?.fromNetwork;
-      
-      if (typeof factory !== 'function') {
-        throw new Error('KeetaNet.UserClient.fromNetwork is unavailable');
-      }
-      
-      let client;
-      if (account) {
-        this.logger.log(`Creating read-only client with account for querying`);
-        // Use null as signer and pass account in options for read-only operations
-        client = factory(network, null, { account });
-      } else {
-        this.logger.log(`Creating client without account`);
-        client = factory(network, null);
-      }
-      
-      this.logger.log(`Successfully created Keeta client`);
-      return client;
-    } catch (error) {
-      this.logger.error(`Error creating Keeta client:`, error);
-      throw new Error(`Failed to create Keeta client: ${error instanceof Error ? error.message : 'Unknown error'}`);
-    }
-  }
-
   async getHistory(publicKey: string, limit?: number, includeOps?: boolean): Promise<LedgerHistoryResponse> {
     this.logger.log(`Getting history for account: ${publicKey}`);
     
@@ -138,16 +92,10 @@ export class LedgerService {
 
   private async fetchHistoryData(publicKey: string, limit?: number, includeOps?: boolean): Promise<LedgerHistoryResponse> {
     this.logger.log(`[FETCH_HISTORY] Starting fetch for account: ${publicKey.substring(0, 20)}...`);
-    
-    // Use direct Keeta SDK calls instead of the complex service methods
-    this.logger.log(`[FETCH_HISTORY] Using direct Keeta SDK calls...`);
-    
-    const KeetaNet = await import('@keetanetwork/keetanet-client');
-    const account = await KeetaNet.lib.Account.fromPublicKeyString(publicKey);
-    this.logger.log(`[FETCH_HISTORY] Account created successfully`);
-    
-    const client = KeetaNet.UserClient.fromNetwork('test', null, { account });
-    this.logger.log(`[FETCH_HISTORY] Client created successfully`);
+
+    const account = await this.toAccountFromPublicKey(publicKey);
+    const client = await this.keeta.getReadClient(publicKey);
+    this.logger.log(`[FETCH_HISTORY] Client ready`);
 
     this.logger.log(`[FETCH_HISTORY] Network: ${this.resolveNetwork()}`);
     this.logger.log(`[FETCH_HISTORY] Client methods available: ${Object.keys(client).join(', ')}`);
@@ -164,7 +112,7 @@ export class LedgerService {
     }
 
     this.logger.log('Calling client.history()...');
-    const rawHistory = await client.history();
+    const rawHistory = await this.rpc.call('history', () => client.history());
     this.logger.log(`Raw history result type: ${typeof rawHistory}, isArray: ${Array.isArray(rawHistory)}`);
     
     const staples: VoteStaple[] = Array.isArray(rawHistory) ? rawHistory : [];
@@ -209,10 +157,9 @@ export class LedgerService {
   async getChain(publicKey: string, limit?: number): Promise<LedgerChainResponse> {
     this.logger.log(`Getting chain for account: ${publicKey}`);
     
-    const account = await this.toAccountFromPublicKey(publicKey);
-    const client = await this.getClient(account);
+    const client = await this.keeta.getReadClient(publicKey);
 
-    const rawChain = await (client.chain?.() ?? Promise.resolve([]));
+    const rawChain = await this.rpc.call('chain', () => client.chain?.() ?? Promise.resolve([]));
     const blocks: ChainBlock[] = Array.isArray(rawChain) ? rawChain : [];
 
     const sliced = typeof limit === 'number' ? blocks.slice(0, Math.max(1, Math.min(100, limit))) : blocks;
@@ -230,10 +177,9 @@ export class LedgerService {
   async getOperations(publicKey: string, limit?: number): Promise<LedgerOperationsResponse> {
     this.logger.log(`Getting operations for account: ${publicKey}`);
     
-    const account = await this.toAccountFromPublicKey(publicKey);
-    const client = await this.getClient(account);
+    const client = await this.keeta.getReadClient(publicKey);
 
-    const rawHistory = await (client.history?.() ?? Promise.resolve([]));
+    const rawHistory = await this.rpc.call('history', () => client.history?.() ?? Promise.resolve([]));
     const staples: VoteStaple[] = Array.isArray(rawHistory) ? rawHistory : [];
 
     // Try to get operations from blocks directly instead of using filterStapleOperations
@@ -262,21 +208,25 @@ export class LedgerService {
     this.logger.log(`Getting balance for account: ${publicKey}`);
     
     const account = await this.toAccountFromPublicKey(publicKey);
-    const client = await this.getClient(account);
+    const client = await this.keeta.getReadClient(publicKey);
 
     // Get all token balances using allBalances() method
-    const allBalances = await client.allBalances();
+    const allBalances = await this.rpc.call('allBalances', () => client.allBalances());
     this.logger.log(`All balances for ${publicKey}:`, allBalances);
 
     // Get base token balance
-    const state = await client.state({ account });
+    const state = await this.rpc.call('state', () => client.state({ account }));
     const baseBalance = state.balance?.toString() ?? '0';
 
     // Convert BigInt values to strings for JSON serialization
-    const serializedBalances = allBalances.map((balance: any) => ({
-      token: balance.token?.publicKeyString?.toString() || 'unknown',
-      balance: balance.balance?.toString() || '0'
-    }));
+    const serializedBalances = allBalances.map((balance: any) => {
+      const token = balance.token?.publicKeyString?.toString() || 'unknown';
+      return {
+        token,
+        balance: balance.balance?.toString() || '0',
+        display: this.tokens.getDisplayMetadata(token),
+      };
+    });
 
     this.logger.log(`Successfully retrieved balance for ${publicKey}: base=${baseBalance}, allBalances=${serializedBalances.length} tokens`);
     return {
@@ -1,16 +1,23 @@
 import 'reflect-metadata';
 import { NestFactory } from '@nestjs/core';
+import { WsAdapter } from '@nestjs/platform-ws';
 import { AppModule } from './app.module';
 import { ValidationPipe } from '@nestjs/common';
+import { loadAppConfig } from './config/app-config';
 
 async function bootstrap() {
-  const app = await NestFactory.create(AppModule, { cors: true });
+  const config = loadAppConfig();
+  const app = await NestFactory.create(AppModule, {
+    cors: { origin: config.corsOrigins === '*' ? true : config.corsOrigins },
+  });
   app.setGlobalPrefix('api');
+  app.useWebSocketAdapter(new WsAdapter(app));
   app.useGlobalPipes(
     new ValidationPipe({ transform: true, whitelist: true, forbidNonWhitelisted: true }),
   );
-  const port = Number(process.env.PORT || 8080);
-  await app.listen(port);
+  // Trap SIGTERM/SIGINT: stop accepting requests, then run the drain hooks.
+  app.enableShutdownHooks();
+  await app.listen(config.port, config.host);
 }
 
 bootstrap();
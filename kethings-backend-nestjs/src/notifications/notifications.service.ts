@@ -0,0 +1,52 @@
+import { Injectable, Logger } from '@nestjs/common';
+import { randomUUID } from 'crypto';
+
+export interface Notification {
+  id: string;
+  user_address: string;
+  kind: string;
+  message: string;
+  payload?: Record<string, unknown>;
+  created_at: string;
+  read: boolean;
+}
+
+const MAX_FEED_LENGTH = 200;
+
+/** Per-user notification feed, consumed by polling and (later) WS delivery. */
+@Injectable()
+export class NotificationsService {
+  private readonly logger = new Logger(NotificationsService.name);
+  private readonly feeds = new Map<string, Notification[]>();
+
+  push(userAddress: string, kind: string, message: string, payload?: Record<string, unknown>): Notification {
+    const notification: Notification = {
+      id: randomUUID(),
+      user_address: userAddress,
+      kind,
+      message,
+      payload,
+      created_at: new Date().toISOString(),
+      read: false,
+    };
+    const feed = this.feeds.get(userAddress) ?? [];
+    feed.unshift(notification);
+    if (feed.length > MAX_FEED_LENGTH) {
+      feed.length = MAX_FEED_LENGTH;
+    }
+    this.feeds.set(userAddress, feed);
+    return notification;
+  }
+
+  list(userAddress: string, limit = 50): Notification[] {
+    return (this.feeds.get(userAddress) ?? []).slice(0, Math.max(1, Math.min(limit, MAX_FEED_LENGTH)));
+  }
+
+  markRead(userAddress: string, notificationId: string): void {
+    const feed = this.feeds.get(userAddress) ?? [];
+    const notification = feed.find((entry) => entry.id === notificationId);
+    if (notification) {
+      notification.read = true;
+    }
+  }
+}
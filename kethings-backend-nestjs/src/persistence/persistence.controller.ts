@@ -0,0 +1,15 @@
+import { Controller, Get, UseGuards } from '@nestjs/common';
+
+import { SchemaVersionService } from './schema-version.service';
+import { AdminGuard } from '../common/admin.guard';
+
+@Controller('admin/schema')
+@UseGuards(AdminGuard)
+export class PersistenceController {
+  constructor(private readonly schema: SchemaVersionService) {}
+
+  @Get()
+  status() {
+    return this.schema.status();
+  }
+}
@@ -0,0 +1,13 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+import { SchemaVersionService } from './schema-version.service';
+import { PersistenceController } from './persistence.controller';
+import { AdminGuard } from '../common/admin.guard';
+
+@Module({
+  imports: [ConfigModule],
+  providers: [SchemaVersionService, AdminGuard],
+  controllers: [PersistenceController],
+  exports: [SchemaVersionService],
+})
+export class PersistenceModule {}
@@ -0,0 +1,23 @@
+import { Injectable, NestMiddleware, ServiceUnavailableException } from '@nestjs/common';
+
+import { SchemaVersionService } from './schema-version.service';
+
+/**
+ * Rejects mutating requests while the instance is in schema read-only mode
+ * (a newer deployment owns the data directory). Reads keep working so the
+ * blue side can serve traffic until the cutover completes.
+ */
+@Injectable()
+export class ReadOnlyMiddleware implements NestMiddleware {
+  constructor(private readonly schema: SchemaVersionService) {}
+
+  use(req: any, _res: any, next: () => void): void {
+    if (this.schema.isReadOnly() && req.method !== 'GET' && req.method !== 'HEAD') {
+      throw new ServiceUnavailableException({
+        code: 'SCHEMA_READ_ONLY',
+        message: 'This instance is read-only: a newer persistence schema was detected; retry against the upgraded deployment',
+      });
+    }
+    next();
+  }
+}
@@ -0,0 +1,122 @@
+import { Injectable, Logger, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { existsSync, mkdirSync, readFileSync, readdirSync, writeFileSync } from 'fs';
+import { join } from 'path';
+
+export interface SchemaMigration {
+  from: number;
+  to: number;
+  description: string;
+  /** Transform the data directory in place; must be idempotent. */
+  migrate: (dataDir: string) => void;
+}
+
+export interface AppliedMigration {
+  from: number;
+  to: number;
+  description: string;
+  applied_at: string;
+}
+
+export interface SchemaStatus {
+  current_version: number;
+  recorded_version: number;
+  read_only: boolean;
+  applied: AppliedMigration[];
+}
+
+interface VersionFile {
+  version: number;
+  updated_at: string;
+  applied: AppliedMigration[];
+}
+
+/** Version of the on-disk persistence layout this build reads and writes. */
+export const CURRENT_SCHEMA_VERSION = 1;
+
+const VERSION_FILE = 'schema-version.json';
+
+/**
+ * Blue/green-safe schema versioning for the append-only persistence files
+ * (audit log, settlement journal, drift archive, cold storage). On startup
+ * the recorded data-directory version is checked against the build:
+ *
+ * - older with a registered migration path: migrations run forward, each
+ *   application recorded in the version file;
+ * - older with no path: startup is refused — continuing would corrupt data;
+ * - newer: another deployment has already migrated forward, so this (blue)
+ *   instance drops into read-only mode and rejects mutating requests rather
+ *   than writing records the new schema no longer understands.
+ */
+@Injectable()
+export class SchemaVersionService implements OnModuleInit {
+  private readonly logger = new Logger(SchemaVersionService.name);
+  private readonly migrations: SchemaMigration[] = [
+    {
+      from: 0,
+      to: 1,
+      description: 'Stamp pre-versioned data directories; file formats are unchanged',
+      migrate: () => {},
+    },
+  ];
+  private state: VersionFile = { version: CURRENT_SCHEMA_VERSION, updated_at: new Date(0).toISOString(), applied: [] };
+  private readOnly = false;
+
+  constructor(private readonly config: ConfigService) {}
+
+  onModuleInit(): void {
+    const dataDir = this.config.get<string>('PERSISTENCE_DATA_DIR') || 'data';
+    const versionPath = join(dataDir, VERSION_FILE);
+
+    if (!existsSync(versionPath)) {
+      // A data directory with existing files but no stamp predates
+      // versioning (version 0); an empty one is a fresh install.
+      const hasData = existsSync(dataDir) && readdirSync(dataDir).length > 0;
+      this.state = { version: hasData ? 0 : CURRENT_SCHEMA_VERSION, updated_at: new Date().toISOString(), applied: [] };
+    } else {
+      this.state = JSON.parse(readFileSync(versionPath, 'utf8')) as VersionFile;
+    }
+
+    if (this.state.version > CURRENT_SCHEMA_VERSION) {
+      // Deliberately leaves the version file untouched: the green side owns
+      // the data now and this instance must not write anything.
+      this.readOnly = true;
+      this.logger.error(
+        `Data directory schema is v${this.state.version} but this build supports v${CURRENT_SCHEMA_VERSION}; entering read-only mode`,
+      );
+      return;
+    }
+
+    while (this.state.version < CURRENT_SCHEMA_VERSION) {
+      const step = this.migrations.find((migration) => migration.from === this.state.version);
+      if (!step) {
+        throw new Error(
+          `No migration path from schema v${this.state.version} to v${CURRENT_SCHEMA_VERSION}; refusing to start against an incompatible data directory`,
+        );
+      }
+      this.logger.log(`Migrating persistence schema v${step.from} -> v${step.to}: ${step.description}`);
+      step.migrate(dataDir);
+      this.state.version = step.to;
+      this.state.applied.push({ from: step.from, to: step.to, description: step.description, applied_at: new Date().toISOString() });
+    }
+
+    this.state.updated_at = new Date().toISOString();
+    if (!existsSync(dataDir)) {
+      mkdirSync(dataDir, { recursive: true });
+    }
+    writeFileSync(versionPath, `${JSON.stringify(this.state, null, 2)}\n`);
+  }
+
+  isReadOnly(): boolean {
+    return this.readOnly;
+  }
+
+  status(): SchemaStatus {
+    return {
+      current_version: CURRENT_SCHEMA_VERSION,
+      recorded_version: this.state.version,
+      read_only: this.readOnly,
+      applied: this.state.applied,
+    };
+  }
+}
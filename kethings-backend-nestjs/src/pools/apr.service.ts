@@ -0,0 +1,142 @@
+import { Injectable, OnModuleDestroy, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+import { Pool, PoolsService } from './pools.service';
+
+interface PoolSample {
+  at: number;
+  cumFeesA: number;
+  cumFeesB: number;
+  reserveA: number;
+  reserveB: number;
+}
+
+export interface AprWindow {
+  window: string;
+  realized_fees: string;
+  average_tvl: string;
+  apr_pct: string;
+  /** Samples the window actually covers; short histories under-cover. */
+  coverage: number;
+}
+
+export interface PoolAprReport {
+  pool_id: string;
+  windows: AprWindow[];
+}
+
+const DEFAULT_SAMPLE_INTERVAL_MS = 300_000;
+const WINDOWS: Array<{ label: string; ms: number }> = [
+  { label: '24h', ms: 86_400_000 },
+  { label: '7d', ms: 604_800_000 },
+  { label: '30d', ms: 2_592_000_000 },
+];
+const YEAR_MS = 31_536_000_000;
+
+/**
+ * Realized APR from actual fee accrual. A background sampler snapshots each
+ * pool's cumulative fees and reserves; APR for a window is the fee value
+ * earned across it divided by the average TVL over the same span, annualized
+ * — so a single outlier hour cannot be projected into a headline number the
+ * way instantaneous rates can. Values are expressed in token-B terms using
+ * the pool's own spot price, which keeps pools comparable without an
+ * external price feed.
+ */
+@Injectable()
+export class AprService implements OnModuleInit, OnModuleDestroy {
+  private readonly samples = new Map<string, PoolSample[]>();
+  private sampleTimer?: ReturnType<typeof setInterval>;
+  private sampleIntervalMs = DEFAULT_SAMPLE_INTERVAL_MS;
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly pools: PoolsService,
+  ) {}
+
+  onModuleInit(): void {
+    this.sampleIntervalMs = Number(this.config.get<string>('POOL_APR_SAMPLE_INTERVAL_MS')) || DEFAULT_SAMPLE_INTERVAL_MS;
+    this.sampleTimer = setInterval(() => this.sampleAll(), this.sampleIntervalMs);
+    this.sampleAll();
+  }
+
+  onModuleDestroy(): void {
+    if (this.sampleTimer) {
+      clearInterval(this.sampleTimer);
+    }
+  }
+
+  report(poolId: string): PoolAprReport {
+    this.pools.getPool(poolId);
+    const history = this.samples.get(poolId) ?? [];
+    return {
+      pool_id: poolId,
+      windows: WINDOWS.map((window) => this.windowApr(history, window.label, window.ms)),
+    };
+  }
+
+  /** Realized APR over the named window, for sorting; 0 without history. */
+  aprPct(poolId: string, window = '24h'): number {
+    const history = this.samples.get(poolId) ?? [];
+    const spec = WINDOWS.find((candidate) => candidate.label === window);
+    if (!spec) {
+      return 0;
+    }
+    return Number(this.windowApr(history, spec.label, spec.ms).apr_pct);
+  }
+
+  private sampleAll(): void {
+    const now = Date.now();
+    const maxAge = WINDOWS[WINDOWS.length - 1].ms;
+    for (const pool of this.pools.allPools()) {
+      const history = this.samples.get(pool.id) ?? [];
+      history.push(this.snapshot(pool, now));
+      // Retain one window past the longest horizon so its oldest edge always
+      // has a baseline sample to diff against.
+      while (history.length > 0 && history[0].at < now - maxAge - this.sampleIntervalMs) {
+        history.shift();
+      }
+      this.samples.set(pool.id, history);
+    }
+  }
+
+  private snapshot(pool: Pool, at: number): PoolSample {
+    return {
+      at,
+      cumFeesA: pool.cumFeesA,
+      cumFeesB: pool.cumFeesB,
+      reserveA: pool.reserveA,
+      reserveB: pool.reserveB,
+    };
+  }
+
+  private windowApr(history: PoolSample[], label: string, windowMs: number): AprWindow {
+    const now = Date.now();
+    const inWindow = history.filter((sample) => sample.at >= now - windowMs);
+    if (inWindow.length < 2) {
+      return { window: label, realized_fees: '0', average_tvl: '0', apr_pct: '0', coverage: 0 };
+    }
+    const first = inWindow[0];
+    const last = inWindow[inWindow.length - 1];
+    const spanMs = last.at - first.at;
+    if (spanMs <= 0) {
+      return { window: label, realized_fees: '0', average_tvl: '0', apr_pct: '0', coverage: 0 };
+    }
+
+    // Value both fee legs in token-B terms at each sample's own spot price.
+    const priceAtoB = (sample: PoolSample) => (sample.reserveA > 0 ? sample.reserveB / sample.reserveA : 0);
+    const feesA = Math.max(0, last.cumFeesA - first.cumFeesA);
+    const feesB = Math.max(0, last.cumFeesB - first.cumFeesB);
+    const realizedFees = feesA * priceAtoB(last) + feesB;
+    const averageTvl =
+      inWindow.reduce((sum, sample) => sum + sample.reserveA * priceAtoB(sample) + sample.reserveB, 0) / inWindow.length;
+
+    const aprPct = averageTvl > 0 ? (realizedFees / averageTvl) * (YEAR_MS / spanMs) * 100 : 0;
+    return {
+      window: label,
+      realized_fees: realizedFees.toString(),
+      average_tvl: averageTvl.toString(),
+      apr_pct: aprPct.toFixed(4),
+      coverage: Math.min(1, spanMs / windowMs),
+    };
+  }
+}
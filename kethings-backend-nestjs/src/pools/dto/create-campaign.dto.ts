@@ -0,0 +1,24 @@
+import { Type } from 'class-transformer';
+import { IsNumber, IsOptional, IsPositive, IsString, Max } from 'class-validator';
+
+export class CreateCampaignDto {
+  @IsOptional()
+  @IsString()
+  pool_id?: string;
+
+  @IsOptional()
+  @IsString()
+  market?: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  @Max(1)
+  discount!: number;
+
+  @IsString()
+  starts_at!: string;
+
+  @IsString()
+  ends_at!: string;
+}
@@ -0,0 +1,41 @@
+import { Type } from 'class-transformer';
+import { IsNumber, IsOptional, IsPositive, IsString } from 'class-validator';
+
+export class CreatePoolDto {
+  @IsString()
+  token_a!: string;
+
+  @IsString()
+  token_b!: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  reserve_a!: number;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  reserve_b!: number;
+
+  @IsString()
+  storage_account!: string;
+
+  /** From a prior POST pools/reserve; lets the holder create past the hold. */
+  @IsOptional()
+  @IsString()
+  reservation_id?: string;
+
+  /** Set both weights to create a weighted pool; omit for constant product. */
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  weight_a?: number;
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  weight_b?: number;
+}
@@ -0,0 +1,15 @@
+import { Type } from 'class-transformer';
+import { IsBoolean, IsOptional, IsString } from 'class-validator';
+
+export class DustSweepDto {
+  @IsString()
+  user_address!: string;
+
+  @IsString()
+  target_token!: string;
+
+  @IsOptional()
+  @Type(() => Boolean)
+  @IsBoolean()
+  preview?: boolean;
+}
@@ -0,0 +1,48 @@
+import { Type } from 'class-transformer';
+import { IsBoolean, IsNumber, IsOptional, IsPositive, IsString } from 'class-validator';
+
+export class OpenPositionDto {
+  @IsString()
+  pool_id!: string;
+
+  @IsString()
+  owner!: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  amount_a!: number;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  amount_b!: number;
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  lower_price?: number;
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  upper_price?: number;
+
+  @IsOptional()
+  @IsString()
+  lock_until?: string;
+
+  @IsOptional()
+  @IsBoolean()
+  transferable?: boolean;
+}
+
+export class TransferPositionDto {
+  @IsString()
+  from!: string;
+
+  @IsString()
+  to!: string;
+}
@@ -0,0 +1,15 @@
+import { Type } from 'class-transformer';
+import { IsNumber, IsPositive, IsString } from 'class-validator';
+
+export class QuoteRequestDto {
+  @IsString()
+  pool_id!: string;
+
+  @IsString()
+  token_in!: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  amount_in!: number;
+}
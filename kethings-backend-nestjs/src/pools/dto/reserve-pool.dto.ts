@@ -0,0 +1,12 @@
+import { IsString } from 'class-validator';
+
+export class ReservePoolDto {
+  @IsString()
+  token_a!: string;
+
+  @IsString()
+  token_b!: string;
+
+  @IsString()
+  storage_account!: string;
+}
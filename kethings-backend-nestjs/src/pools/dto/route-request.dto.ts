@@ -0,0 +1,19 @@
+import { Type } from 'class-transformer';
+import { IsNumber, IsOptional, IsPositive, IsString } from 'class-validator';
+
+export class RouteRequestDto {
+  @IsOptional()
+  @IsString()
+  user_address?: string;
+
+  @IsString()
+  token_in!: string;
+
+  @IsString()
+  token_out!: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  amount_in!: number;
+}
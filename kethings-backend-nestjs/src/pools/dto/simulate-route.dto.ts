@@ -0,0 +1,17 @@
+import { Type } from 'class-transformer';
+import { IsNumber, IsPositive, IsString, Max } from 'class-validator';
+
+export class SimulateRouteDto {
+  @IsString()
+  token_in!: string;
+
+  @IsString()
+  token_out!: string;
+
+  /** Impact budget as a fraction (0.02 = 2%). */
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  @Max(0.99)
+  max_price_impact!: number;
+}
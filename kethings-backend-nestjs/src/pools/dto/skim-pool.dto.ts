@@ -0,0 +1,7 @@
+import { IsIn, IsOptional } from 'class-validator';
+
+export class SkimPoolDto {
+  @IsOptional()
+  @IsIn(['fold_into_reserves', 'protocol_fees'])
+  mode?: 'fold_into_reserves' | 'protocol_fees';
+}
@@ -0,0 +1,23 @@
+import { Type } from 'class-transformer';
+import { IsNumber, IsPositive, IsString } from 'class-validator';
+
+export class SwapTelemetryDto {
+  @IsString()
+  user_address!: string;
+
+  @IsString()
+  tx_hash!: string;
+
+  @IsString()
+  token_in!: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  amount_in!: number;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  amount_out!: number;
+}
@@ -0,0 +1,90 @@
+import { Injectable, Logger } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+import { BalancesService } from '../balances/balances.service';
+import { PoolsService } from './pools.service';
+
+export interface DustSweepLine {
+  token: string;
+  amount_in: string;
+  amount_out: string;
+  route: string;
+}
+
+export interface DustSweepReport {
+  target_token: string;
+  preview: boolean;
+  swept: DustSweepLine[];
+  skipped: Array<{ token: string; reason: string }>;
+  total_proceeds: string;
+}
+
+const DEFAULT_DUST_THRESHOLD = 1;
+
+@Injectable()
+export class DustSweepService {
+  private readonly logger = new Logger(DustSweepService.name);
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly balances: BalancesService,
+    private readonly pools: PoolsService,
+  ) {}
+
+  /**
+   * Convert every dust balance the user holds into the target token via
+   * direct pool routes. A balance counts as dust when its expected proceeds
+   * in the target token fall below the configured threshold. Preview mode
+   * reports expected proceeds without touching balances or reserves.
+   */
+  sweep(user: string, targetToken: string, preview: boolean): DustSweepReport {
+    const threshold = Number(this.config.get<string>('DUST_SWEEP_THRESHOLD')) || DEFAULT_DUST_THRESHOLD;
+    const swept: DustSweepLine[] = [];
+    const skipped: Array<{ token: string; reason: string }> = [];
+    let totalProceeds = 0;
+
+    for (const balance of this.balances.getBalances(user)) {
+      if (balance.token === targetToken || balance.available <= 0) {
+        continue;
+      }
+
+      const pool = this.pools.findPool(balance.token, targetToken);
+      if (!pool) {
+        skipped.push({ token: balance.token, reason: 'no direct pool route to target token' });
+        continue;
+      }
+
+      const quote = this.pools.quote(pool.id, balance.token, balance.available);
+      const expectedOut = Number(quote.amount_out);
+      if (expectedOut >= threshold) {
+        skipped.push({ token: balance.token, reason: 'balance above dust threshold' });
+        continue;
+      }
+
+      let amountOut = expectedOut;
+      if (!preview) {
+        amountOut = this.pools.swap(user, pool, balance.token, balance.available).amountOut;
+      }
+
+      swept.push({
+        token: balance.token,
+        amount_in: balance.available.toString(),
+        amount_out: amountOut.toString(),
+        route: quote.route,
+      });
+      totalProceeds += amountOut;
+    }
+
+    if (!preview && swept.length > 0) {
+      this.logger.log(`Swept ${swept.length} dust balances for ${user} into ${targetToken}`);
+    }
+
+    return {
+      target_token: targetToken,
+      preview,
+      swept,
+      skipped,
+      total_proceeds: totalProceeds.toString(),
+    };
+  }
+}
@@ -0,0 +1,84 @@
+import { BadRequestException, Injectable, Logger } from '@nestjs/common';
+import { randomUUID } from 'crypto';
+
+export interface FeeCampaign {
+  id: string;
+  /** Exactly one of pool_id or market (e.g. "KTA/USDT") scopes the campaign. */
+  pool_id?: string;
+  market?: string;
+  /** Fraction of the swap fee waived, 0..1. */
+  discount: number;
+  starts_at: string;
+  ends_at: string;
+  created_at: string;
+}
+
+export interface RebateLine {
+  campaign_id: string;
+  pool_id: string;
+  token: string;
+  rebated_fees: string;
+}
+
+/**
+ * Temporary fee discount campaigns. Rebated fee volume is tracked separately
+ * from regular fee accrual so revenue reports can distinguish the two.
+ */
+@Injectable()
+export class FeeCampaignsService {
+  private readonly logger = new Logger(FeeCampaignsService.name);
+  private readonly campaigns = new Map<string, FeeCampaign>();
+  private readonly rebates = new Map<string, number>();
+
+  listCampaigns(): FeeCampaign[] {
+    return Array.from(this.campaigns.values());
+  }
+
+  createCampaign(input: Omit<FeeCampaign, 'id' | 'created_at'>): FeeCampaign {
+    if (!input.pool_id && !input.market) {
+      throw new BadRequestException('Campaign needs a pool_id or market scope');
+    }
+    if (!(input.discount > 0) || input.discount > 1) {
+      throw new BadRequestException('discount must be within (0, 1]');
+    }
+    if (Date.parse(input.ends_at) <= Date.parse(input.starts_at)) {
+      throw new BadRequestException('ends_at must be after starts_at');
+    }
+
+    const campaign: FeeCampaign = {
+      ...input,
+      id: randomUUID(),
+      created_at: new Date().toISOString(),
+    };
+    this.campaigns.set(campaign.id, campaign);
+    this.logger.log(`Created fee campaign ${campaign.id} (${(campaign.discount * 100).toFixed(0)}% off)`);
+    return campaign;
+  }
+
+  /** The single best active discount for the pool/pair, if any. */
+  activeCampaign(poolId: string, market: string): FeeCampaign | undefined {
+    const now = Date.now();
+    let best: FeeCampaign | undefined;
+    for (const campaign of this.campaigns.values()) {
+      if (Date.parse(campaign.starts_at) > now || Date.parse(campaign.ends_at) < now) continue;
+      const matches = campaign.pool_id === poolId || campaign.market === market;
+      if (!matches) continue;
+      if (!best || campaign.discount > best.discount) {
+        best = campaign;
+      }
+    }
+    return best;
+  }
+
+  recordRebate(campaignId: string, poolId: string, token: string, amount: number): void {
+    const key = `${campaignId}|${poolId}|${token}`;
+    this.rebates.set(key, (this.rebates.get(key) ?? 0) + amount);
+  }
+
+  rebateReport(): RebateLine[] {
+    return Array.from(this.rebates.entries()).map(([key, amount]) => {
+      const [campaignId, poolId, token] = key.split('|');
+      return { campaign_id: campaignId, pool_id: poolId, token, rebated_fees: amount.toString() };
+    });
+  }
+}
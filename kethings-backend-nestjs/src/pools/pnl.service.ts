@@ -0,0 +1,90 @@
+import { Injectable, NotFoundException } from '@nestjs/common';
+
+import { PoolsService } from './pools.service';
+import { PositionsService } from './positions.service';
+
+export interface PositionPnl {
+  position_id: string;
+  lp_amount: string;
+  /** Current redeemable amounts at pool reserves. */
+  current_a: string;
+  current_b: string;
+  /** What the original deposit would be worth if simply held. */
+  hodl_value_quote: string;
+  current_value_quote: string;
+  fees_earned_a: string;
+  fees_earned_b: string;
+  /** Divergence loss vs holding, excluding earned fees. */
+  impermanent_loss_quote: string;
+  impermanent_loss_percent: string;
+  /** Full PnL vs holding, fees included. */
+  net_pnl_quote: string;
+}
+
+/**
+ * Position PnL and impermanent loss, valued in the pool's token B so both
+ * sides collapse to one number. Fees already sit inside the reserves, so the
+ * redeemable value includes them; impermanent loss is reported with the fee
+ * component stripped back out, which is the number LPs actually compare
+ * against simply holding.
+ */
+@Injectable()
+export class PnlService {
+  constructor(
+    private readonly pools: PoolsService,
+    private readonly positions: PositionsService,
+  ) {}
+
+  report(poolId: string, wallet: string) {
+    const pool = this.pools.getPool(poolId);
+    const positions = this.positions.listPositions(wallet, poolId);
+    if (positions.length === 0) {
+      throw new NotFoundException(`No positions for ${wallet} in pool ${poolId}`);
+    }
+    const price = pool.reserveA > 0 ? pool.reserveB / pool.reserveA : 0;
+
+    const perPosition: PositionPnl[] = positions.map((position) => {
+      const share = pool.totalLpSupply > 0 ? position.lp_amount / pool.totalLpSupply : 0;
+      const currentA = pool.reserveA * share;
+      const currentB = pool.reserveB * share;
+      const feesA = (pool.feeGrowthGlobalA - position.fee_checkpoint_a) * position.lp_amount;
+      const feesB = (pool.feeGrowthGlobalB - position.fee_checkpoint_b) * position.lp_amount;
+
+      const currentValue = currentA * price + currentB;
+      const hodlValue = position.deposited_a * price + position.deposited_b;
+      const feesValue = feesA * price + feesB;
+      const impermanentLoss = currentValue - feesValue - hodlValue;
+
+      return {
+        position_id: position.id,
+        lp_amount: position.lp_amount.toString(),
+        current_a: currentA.toString(),
+        current_b: currentB.toString(),
+        hodl_value_quote: hodlValue.toString(),
+        current_value_quote: currentValue.toString(),
+        fees_earned_a: feesA.toString(),
+        fees_earned_b: feesB.toString(),
+        impermanent_loss_quote: impermanentLoss.toString(),
+        impermanent_loss_percent: (hodlValue > 0 ? impermanentLoss / hodlValue : 0).toString(),
+        net_pnl_quote: (currentValue - hodlValue).toString(),
+      };
+    });
+
+    const sum = (select: (entry: PositionPnl) => string) =>
+      perPosition.reduce((total, entry) => total + Number(select(entry)), 0);
+
+    return {
+      pool_id: pool.id,
+      wallet_address: wallet,
+      quote_token: pool.tokenB,
+      price_a_in_b: price.toString(),
+      positions: perPosition,
+      totals: {
+        hodl_value_quote: sum((entry) => entry.hodl_value_quote).toString(),
+        current_value_quote: sum((entry) => entry.current_value_quote).toString(),
+        impermanent_loss_quote: sum((entry) => entry.impermanent_loss_quote).toString(),
+        net_pnl_quote: sum((entry) => entry.net_pnl_quote).toString(),
+      },
+    };
+  }
+}
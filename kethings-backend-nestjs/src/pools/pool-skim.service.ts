@@ -0,0 +1,88 @@
+import { BadRequestException, Injectable, Logger } from '@nestjs/common';
+
+import { LedgerService } from '../ledger/ledger.service';
+import { PoolsService } from './pools.service';
+import { checkedAdd } from '../common/checked-math';
+
+export type SkimMode = 'fold_into_reserves' | 'protocol_fees';
+
+export interface SkimResult {
+  pool_id: string;
+  mode: SkimMode;
+  skimmed: Array<{ token: string; amount: string }>;
+  drift_cleared: boolean;
+  /** True when the pool stayed paused because it awaits a drift review. */
+  review_required: boolean;
+}
+
+/**
+ * Donation handling: tokens sent directly to a pool storage account outside
+ * normal flows show up as surplus against tracked reserves. Skimming folds
+ * that surplus into reserves or routes it to protocol fees, clearing the
+ * drift-induced pause.
+ */
+@Injectable()
+export class PoolSkimService {
+  private readonly logger = new Logger(PoolSkimService.name);
+
+  constructor(
+    private readonly pools: PoolsService,
+    private readonly ledger: LedgerService,
+  ) {}
+
+  async skim(poolId: string, mode: SkimMode): Promise<SkimResult> {
+    const pool = this.pools.getPool(poolId);
+
+    let onchain;
+    try {
+      onchain = await this.ledger.getBalance(pool.storageAccount);
+    } catch (error) {
+      throw new BadRequestException(
+        `Unable to read on-chain balances for storage account ${pool.storageAccount}: ${error instanceof Error ? error.message : 'unknown error'}`,
+      );
+    }
+
+    const holdings = new Map<string, number>();
+    for (const entry of onchain.allBalances) {
+      const amount = Number(entry.balance);
+      if (Number.isFinite(amount)) {
+        holdings.set(entry.token, amount);
+      }
+    }
+
+    const skimmed: Array<{ token: string; amount: string }> = [];
+    for (const [token, tracked] of [
+      [pool.tokenA, pool.reserveA],
+      [pool.tokenB, pool.reserveB],
+    ] as Array<[string, number]>) {
+      const held = holdings.get(token) ?? 0;
+      const surplus = held - tracked;
+      if (surplus <= 0) continue;
+
+      if (mode === 'fold_into_reserves') {
+        if (token === pool.tokenA) {
+          pool.reserveA = checkedAdd(pool.reserveA, surplus, 'skim reserveA');
+        } else {
+          pool.reserveB = checkedAdd(pool.reserveB, surplus, 'skim reserveB');
+        }
+      } else {
+        this.pools.creditProtocolFees(token, surplus);
+      }
+      skimmed.push({ token, amount: surplus.toString() });
+    }
+
+    // A review-locked pool stays paused: the skim still reconciles the
+    // surplus, but only the drift review is allowed to unpause.
+    const reviewRequired = pool.isPaused && pool.unpauseRequiresReview;
+    const driftCleared = pool.isPaused && !reviewRequired;
+    if (!reviewRequired) {
+      this.pools.setPaused(pool, false);
+    }
+    pool.pendingSettlement = false;
+
+    this.logger.log(
+      `Skimmed pool ${poolId} (${mode}): ${skimmed.map((line) => `${line.amount} ${line.token}`).join(', ') || 'no surplus'}${reviewRequired ? '; pool remains paused pending drift review' : ''}`,
+    );
+    return { pool_id: poolId, mode, skimmed, drift_cleared: driftCleared, review_required: reviewRequired };
+  }
+}
@@ -0,0 +1,216 @@
+import { BadRequestException, Body, Controller, Get, Param, Post, Query, Req, Res, UseGuards } from '@nestjs/common';
+import type { Request, Response } from 'express';
+
+import { PoolsService } from './pools.service';
+import { AprService } from './apr.service';
+import { keysetPage, respondWithEtag } from '../common/list-caching';
+import { DustSweepService } from './dust-sweep.service';
+import { PoolSkimService, SkimMode } from './pool-skim.service';
+import { SettlementCostsService } from '../settlement/settlement-costs.service';
+import { AdminGuard } from '../common/admin.guard';
+import { SkimPoolDto } from './dto/skim-pool.dto';
+import { FeeCampaignsService } from './fee-campaigns.service';
+import { QuoteSanityService } from './quote-sanity.service';
+import { SwapTelemetryService } from './swap-telemetry.service';
+import { SwapTelemetryDto } from './dto/swap-telemetry.dto';
+import { RouterService } from './router.service';
+import { PnlService } from './pnl.service';
+import { RouteCacheService } from './route-cache.service';
+import { RouteRequestDto } from './dto/route-request.dto';
+import { SimulateRouteDto } from './dto/simulate-route.dto';
+import { SimulationService } from './simulation.service';
+import { CreateCampaignDto } from './dto/create-campaign.dto';
+import { CreatePoolDto } from './dto/create-pool.dto';
+import { ReservePoolDto } from './dto/reserve-pool.dto';
+import { QuoteRequestDto } from './dto/quote-request.dto';
+import { DustSweepDto } from './dto/dust-sweep.dto';
+import { DEFAULT_TENANT_ID, TenantsService } from '../tenants/tenants.service';
+
+@Controller('pools')
+export class PoolsController {
+  constructor(
+    private readonly pools: PoolsService,
+    private readonly dustSweep: DustSweepService,
+    private readonly settlementCosts: SettlementCostsService,
+    private readonly skim: PoolSkimService,
+    private readonly campaigns: FeeCampaignsService,
+    private readonly quoteSanity: QuoteSanityService,
+    private readonly telemetry: SwapTelemetryService,
+    private readonly router: RouterService,
+    private readonly pnl: PnlService,
+    private readonly routeCache: RouteCacheService,
+    private readonly simulation: SimulationService,
+    private readonly apr: AprService,
+    private readonly tenants: TenantsService,
+  ) {}
+
+  /** Depth analysis: maximum tradable size within a price-impact budget. */
+  @Post('simulate')
+  simulate(@Body() body: SimulateRouteDto) {
+    return this.simulation.simulate(body.token_in, body.token_out, body.max_price_impact);
+  }
+
+  @Post('route/quote')
+  routeQuote(@Body() body: RouteRequestDto) {
+    return this.routeCache.quote(body.token_in, body.token_out, body.amount_in);
+  }
+
+  @Get('route/cache/metrics')
+  routeCacheMetrics() {
+    return this.routeCache.metrics();
+  }
+
+  @Post('route/swap')
+  routeSwap(@Body() body: RouteRequestDto) {
+    if (!body.user_address) {
+      throw new BadRequestException('user_address is required to execute a routed swap');
+    }
+    return this.router.routeSwap(body.user_address, body.token_in, body.token_out, body.amount_in);
+  }
+
+  @Post('route/split-quote')
+  splitQuote(@Body() body: RouteRequestDto) {
+    return this.router.splitQuote(body.token_in, body.token_out, body.amount_in);
+  }
+
+  @Post('route/split-swap')
+  splitSwap(@Body() body: RouteRequestDto) {
+    if (!body.user_address) {
+      throw new BadRequestException('user_address is required to execute a split swap');
+    }
+    return this.router.splitSwap(body.user_address, body.token_in, body.token_out, body.amount_in);
+  }
+
+  @Get('telemetry/quarantine')
+  telemetryQuarantine() {
+    return { quarantined: this.telemetry.quarantine() };
+  }
+
+  @Get('campaigns')
+  listCampaigns() {
+    return { campaigns: this.campaigns.listCampaigns() };
+  }
+
+  @Post('campaigns')
+  @UseGuards(AdminGuard)
+  createCampaign(@Body() body: CreateCampaignDto) {
+    return this.campaigns.createCampaign(body);
+  }
+
+  @Get('campaigns/rebates')
+  rebateReport() {
+    return { rebates: this.campaigns.rebateReport() };
+  }
+
+  @Get('list')
+  list(
+    @Req() req: Request,
+    @Res({ passthrough: true }) res: Response,
+    @Query('after') after?: string,
+    @Query('limit') limit?: string,
+    @Query('sort') sort?: string,
+  ) {
+    // Listings are scoped to the requesting tenant's namespace so one
+    // white-label venue never sees another's pools.
+    const tenantId = (req as unknown as { tenantId?: string }).tenantId ?? DEFAULT_TENANT_ID;
+    let pools = this.pools.listPools().filter((pool) => this.tenants.scopesPool(tenantId, pool.id));
+    if (sort) {
+      const match = /^apr_(24h|7d|30d)$/.exec(sort);
+      if (!match) {
+        throw new BadRequestException('sort must be one of apr_24h, apr_7d, apr_30d');
+      }
+      pools = [...pools].sort((a, b) => this.apr.aprPct(b.id, match[1]) - this.apr.aprPct(a.id, match[1]));
+    }
+    const page = keysetPage(pools, (pool) => pool.id, after, limit ? Number(limit) : undefined);
+    return respondWithEtag(req, res, { pools: page.items, next_cursor: page.next_cursor });
+  }
+
+  /** Step one of the race-safe creation protocol; see PoolsService.reserveCreation. */
+  @Post('reserve')
+  reserve(@Body() body: ReservePoolDto) {
+    return this.pools.reserveCreation(body.token_a, body.token_b, body.storage_account);
+  }
+
+  @Post('create')
+  create(@Body() body: CreatePoolDto) {
+    if ((body.weight_a === undefined) !== (body.weight_b === undefined)) {
+      throw new BadRequestException('weight_a and weight_b must be provided together');
+    }
+    return this.pools.createPool(
+      body.token_a,
+      body.token_b,
+      body.reserve_a,
+      body.reserve_b,
+      body.storage_account,
+      body.weight_a !== undefined && body.weight_b !== undefined
+        ? { weightA: body.weight_a, weightB: body.weight_b }
+        : undefined,
+      body.reservation_id,
+    );
+  }
+
+  @Get(':poolId/stats')
+  stats(@Param('poolId') poolId: string) {
+    const pool = this.pools.getPool(poolId);
+    return {
+      id: pool.id,
+      token_a: pool.tokenA,
+      token_b: pool.tokenB,
+      reserve_a: pool.reserveA.toString(),
+      reserve_b: pool.reserveB.toString(),
+      cumulative_fees_a: pool.cumFeesA.toString(),
+      cumulative_fees_b: pool.cumFeesB.toString(),
+      settlement_network_fees: this.settlementCosts.totalForPool(poolId).toString(),
+      apr: this.apr.report(poolId).windows,
+    };
+  }
+
+  @Get(':poolId/positions/:wallet/pnl')
+  positionPnl(@Param('poolId') poolId: string, @Param('wallet') wallet: string) {
+    return this.pnl.report(poolId, wallet);
+  }
+
+  @Get(':poolId/fees')
+  fees(@Param('poolId') poolId: string) {
+    return this.pools.feeReport(poolId);
+  }
+
+  @Post('quote')
+  quote(@Body() body: QuoteRequestDto) {
+    return this.pools.quote(body.pool_id, body.token_in, body.amount_in);
+  }
+
+  @Post('quote/sanity-check')
+  quoteSanityCheck(@Body() body: QuoteRequestDto) {
+    return this.quoteSanity.check(body.pool_id, body.token_in, body.amount_in);
+  }
+
+  @Post(':poolId/skim')
+  @UseGuards(AdminGuard)
+  skimPool(@Param('poolId') poolId: string, @Body() body: SkimPoolDto) {
+    return this.skim.skim(poolId, (body.mode ?? 'fold_into_reserves') as SkimMode);
+  }
+
+  @Post(':poolId/collect-fees')
+  @UseGuards(AdminGuard)
+  collectFees(@Param('poolId') poolId: string) {
+    try {
+      return this.pools.collectProtocolFees(poolId);
+    } catch (error) {
+      if (error instanceof Error && !(error as { status?: number }).status) {
+        throw new BadRequestException(error.message);
+      }
+      throw error;
+    }
+  }
+
+  @Post(':poolId/telemetry/swap')
+  recordSwapTelemetry(@Param('poolId') poolId: string, @Body() body: SwapTelemetryDto) {
+    return this.telemetry.recordSwap(poolId, body.user_address, body.tx_hash, body.token_in, body.amount_in, body.amount_out);
+  }
+
+  @Post('dust-sweep')
+  sweepDust(@Body() body: DustSweepDto) {
+    return this.dustSweep.sweep(body.user_address, body.target_token, body.preview ?? false);
+  }
+}
@@ -0,0 +1,31 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+import { PoolsService } from './pools.service';
+import { AprService } from './apr.service';
+import { DustSweepService } from './dust-sweep.service';
+import { PositionsService } from './positions.service';
+import { PoolSkimService } from './pool-skim.service';
+import { FeeCampaignsService } from './fee-campaigns.service';
+import { QuoteSanityService } from './quote-sanity.service';
+import { SwapTelemetryService } from './swap-telemetry.service';
+import { RouterService } from './router.service';
+import { PnlService } from './pnl.service';
+import { RouteCacheService } from './route-cache.service';
+import { SimulationService } from './simulation.service';
+import { AdminGuard } from '../common/admin.guard';
+import { LedgerModule } from '../ledger/ledger.module';
+import { PoolsController } from './pools.controller';
+import { PositionsController } from './positions.controller';
+import { BalancesModule } from '../balances/balances.module';
+import { TokensModule } from '../tokens/tokens.module';
+import { SettlementModule } from '../settlement/settlement.module';
+import { AuditModule } from '../audit/audit.module';
+import { TenantsModule } from '../tenants/tenants.module';
+
+@Module({
+  imports: [ConfigModule, BalancesModule, TokensModule, SettlementModule, LedgerModule, AuditModule, TenantsModule],
+  providers: [PoolsService, AprService, DustSweepService, PositionsService, PoolSkimService, FeeCampaignsService, QuoteSanityService, SwapTelemetryService, RouterService, PnlService, RouteCacheService, SimulationService, AdminGuard],
+  controllers: [PoolsController, PositionsController],
+  exports: [PoolsService, PositionsService, SwapTelemetryService],
+})
+export class PoolsModule {}
@@ -0,0 +1,778 @@
+import { ConflictException, Injectable, Logger, NotFoundException } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { randomUUID } from 'crypto';
+import { Subject } from 'rxjs';
+
+import { BalancesService } from '../balances/balances.service';
+import { TokensService } from '../tokens/tokens.service';
+import { FeeCampaignsService } from './fee-campaigns.service';
+import { SettlementOp, SettlementQueueService } from '../settlement/settlement-queue.service';
+import { AuditLogService } from '../audit/audit-log.service';
+import { WAD, divWadDown, mulDivDown, mulWadDown, powWad, sqrtWad, wadFromNumber, wadToNumber } from '../common/fixed-point';
+import { checkedAdd, checkedMul, checkedSub } from '../common/checked-math';
+
+export interface Pool {
+  id: string;
+  tokenA: string;
+  tokenB: string;
+  reserveA: number;
+  reserveB: number;
+  lpToken: string;
+  totalLpSupply: number;
+  feeRate: number;
+  poolType: string;
+  /** Normalized token weights for weighted pools; unset for constant product. */
+  weightA?: number;
+  weightB?: number;
+  storageAccount: string;
+  isPaused: boolean;
+  /** Set by the drift escalation policy; blocks addLiquidity until reviewed. */
+  liquidityAddsDisabled: boolean;
+  /** When set, unpausing requires an explicit admin drift review first. */
+  unpauseRequiresReview: boolean;
+  pendingSettlement: boolean;
+  /** On-chain tx references from confirmed settlement ops, newest first. */
+  settlementTxRefs: string[];
+  /** Cumulative swap fees collected per side, used for LP fee checkpoints. */
+  cumFeesA: number;
+  cumFeesB: number;
+  /** LP fees per unit of LP supply, Uniswap feeGrowthGlobal-style. */
+  feeGrowthGlobalA: number;
+  feeGrowthGlobalB: number;
+  /** Protocol's cut of swap fees, held in reserves until withdrawn. */
+  protocolFeesA: number;
+  protocolFeesB: number;
+}
+
+export type PoolHealthLevel = 'green' | 'yellow' | 'red';
+
+export interface PoolHealth {
+  level: PoolHealthLevel;
+  reasons: string[];
+  evaluated_at: string;
+}
+
+export interface PoolInfoResponse {
+  id: string;
+  token_a: string;
+  token_b: string;
+  reserve_a: string;
+  reserve_b: string;
+  lp_token: string;
+  total_lp_supply: string;
+  fee_rate: string;
+  pool_type: string;
+  weight_a?: string;
+  weight_b?: string;
+  storage_account: string;
+  is_paused: boolean;
+  liquidity_adds_disabled: boolean;
+  pending_settlement: boolean;
+  settlement_tx_refs?: string[];
+  token_a_display?: unknown;
+  token_b_display?: unknown;
+  /** Set once the background health evaluator has scored the pool. */
+  health?: PoolHealth;
+  protocol_fees_a?: string;
+  protocol_fees_b?: string;
+}
+
+export interface QuoteResult {
+  amount_out: string;
+  fee: string;
+  price_impact: string;
+  minimum_received: string;
+  route: string;
+  /** Itemized campaign discount, present only when a campaign applies. */
+  fee_discount?: string;
+  campaign_id?: string;
+}
+
+export interface SwapResult {
+  amountOut: number;
+  fee: number;
+  pool: Pool;
+}
+
+export interface SwapExecution {
+  user: string;
+  pool_id: string;
+  token_in: string;
+  token_out: string;
+  amount_in: number;
+  amount_out: number;
+  fee: number;
+  at: string;
+}
+
+export interface PoolEvent {
+  type: 'pool_created' | 'reserves_updated' | 'swap_confirmed' | 'paused' | 'unpaused' | 'settlement_confirmed';
+  pool_id: string;
+  data: Record<string, unknown>;
+  at: string;
+}
+
+const DEFAULT_FEE_RATE = 0.003;
+// Balancer-style safety bounds for weighted pools: a single swap may not
+// move more than this fraction of either reserve, and weights may not be so
+// lopsided that the power-function approximation loses precision.
+const MAX_WEIGHTED_IN_RATIO = 0.3;
+const MAX_WEIGHTED_OUT_RATIO = 0.3;
+const MIN_NORMALIZED_WEIGHT = 0.01;
+const CREATION_RESERVATION_TTL_MS = 30_000;
+const MAX_SETTLEMENT_TX_REFS = 50;
+const DEFAULT_SLIPPAGE = 0.005;
+const DEFAULT_PROTOCOL_FEE_SHARE = 0.1;
+
+@Injectable()
+export class PoolsService {
+  private readonly logger = new Logger(PoolsService.name);
+  private readonly pools = new Map<string, Pool>();
+  /** Canonical (pair, storage account) key -> pool id, for idempotent creation. */
+  private readonly creationKeys = new Map<string, string>();
+  private readonly creationReservations = new Map<string, { id: string; expiresAt: number }>();
+  /** Protocol-owned fees per token, e.g. skimmed donations. */
+  private readonly protocolFees = new Map<string, number>();
+  /** Latest health verdict per pool, written by the background evaluator. */
+  private readonly healthByPool = new Map<string, PoolHealth>();
+  /** Pool lifecycle stream consumed by the WebSocket gateway. */
+  readonly events$ = new Subject<PoolEvent>();
+  /**
+   * Per-user swap stream for downstream accounting (cost basis). Kept
+   * separate from events$ because that stream is broadcast publicly and
+   * must not carry user addresses.
+   */
+  readonly swaps$ = new Subject<SwapExecution>();
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly balances: BalancesService,
+    private readonly tokens: TokensService,
+    private readonly campaigns: FeeCampaignsService,
+    private readonly settlementQueue: SettlementQueueService,
+    private readonly auditLog: AuditLogService,
+  ) {
+    // Settlement-result channel: confirmed/failed PoolDeposit and
+    // PoolWithdraw ops flow back here so pending_settlement clears from
+    // actual completion rather than never.
+    this.settlementQueue.results$.subscribe((result) => this.onSettlementResult(result.op, result.outcome));
+  }
+
+  private onSettlementResult(op: SettlementOp, outcome: 'complete' | 'failed'): void {
+    if (op.kind !== 'pool_deposit' && op.kind !== 'pool_withdraw') {
+      return;
+    }
+    const poolId = typeof op.payload.pool_id === 'string' ? op.payload.pool_id : undefined;
+    const pool = poolId ? this.pools.get(poolId) : undefined;
+    if (!pool) {
+      return;
+    }
+    if (outcome === 'complete' && op.tx_ref) {
+      pool.settlementTxRefs.unshift(op.tx_ref);
+      if (pool.settlementTxRefs.length > MAX_SETTLEMENT_TX_REFS) {
+        pool.settlementTxRefs.length = MAX_SETTLEMENT_TX_REFS;
+      }
+    }
+    if (outcome === 'failed') {
+      this.logger.warn(`Settlement op ${op.id} (${op.kind}) for pool ${pool.id} failed: ${op.failure_reason ?? 'unknown reason'}`);
+    }
+    const stillPending = this.settlementQueue
+      .pendingOps()
+      .some((pending) => pending.payload.pool_id === pool.id);
+    if (pool.pendingSettlement && !stillPending) {
+      pool.pendingSettlement = false;
+      this.emit('settlement_confirmed', pool.id, {
+        op_id: op.id,
+        kind: op.kind,
+        outcome,
+        ...(op.tx_ref ? { tx_ref: op.tx_ref } : {}),
+      });
+    }
+  }
+
+  private defaultFeeRate(): number {
+    const raw = Number(this.config.get<string>('POOL_DEFAULT_FEE_RATE'));
+    return Number.isFinite(raw) && raw >= 0 && raw < 1 ? raw : DEFAULT_FEE_RATE;
+  }
+
+  /** Fraction of each swap fee accrued to the protocol instead of LPs. */
+  private protocolFeeShare(): number {
+    const raw = Number(this.config.get<string>('POOL_PROTOCOL_FEE_SHARE'));
+    return Number.isFinite(raw) && raw >= 0 && raw < 1 ? raw : DEFAULT_PROTOCOL_FEE_SHARE;
+  }
+
+  listPools(): PoolInfoResponse[] {
+    return Array.from(this.pools.values()).map((pool) => this.toPoolInfo(pool));
+  }
+
+  allPools(): Pool[] {
+    return Array.from(this.pools.values());
+  }
+
+  getPool(poolId: string): Pool {
+    const pool = this.pools.get(poolId);
+    if (!pool) {
+      throw new NotFoundException(`Pool ${poolId} not found`);
+    }
+    return pool;
+  }
+
+  /**
+   * Reserve the right to create a pool for a pair/storage-account pairing.
+   * Creation can then race freely: duplicate or concurrent attempts either
+   * converge to the already-created pool or are rejected while another
+   * holder's reservation is live, so at most one pool is ever tracked per key.
+   */
+  reserveCreation(tokenA: string, tokenB: string, storageAccount: string): { reservation_id: string; pair: string; storage_account: string; expires_at: string } {
+    const key = this.creationKey(tokenA, tokenB, storageAccount);
+    const existingId = this.creationKeys.get(key);
+    if (existingId !== undefined) {
+      throw new ConflictException({ code: 'POOL_EXISTS', pool_id: existingId, message: `A pool for this pair and storage account already exists` });
+    }
+    const held = this.creationReservations.get(key);
+    if (held !== undefined && held.expiresAt > Date.now()) {
+      throw new ConflictException({ code: 'POOL_CREATION_RESERVED', message: `Pool creation for this pair is already reserved; retry after the reservation expires` });
+    }
+    const reservation = { id: randomUUID(), expiresAt: Date.now() + CREATION_RESERVATION_TTL_MS };
+    this.creationReservations.set(key, reservation);
+    return {
+      reservation_id: reservation.id,
+      pair: key.split('@')[0],
+      storage_account: storageAccount,
+      expires_at: new Date(reservation.expiresAt).toISOString(),
+    };
+  }
+
+  createPool(
+    tokenA: string,
+    tokenB: string,
+    reserveA: number,
+    reserveB: number,
+    storageAccount: string,
+    weights?: { weightA: number; weightB: number },
+    reservationId?: string,
+  ): PoolInfoResponse {
+    const key = this.creationKey(tokenA, tokenB, storageAccount);
+    // Duplicate notifications and replays converge to the tracked pool
+    // instead of creating a second one.
+    const existingId = this.creationKeys.get(key);
+    if (existingId !== undefined) {
+      return this.toPoolInfo(this.getPool(existingId));
+    }
+    const held = this.creationReservations.get(key);
+    if (held !== undefined && held.expiresAt > Date.now() && held.id !== reservationId) {
+      throw new ConflictException({ code: 'POOL_CREATION_RESERVED', message: `Pool creation for this pair is reserved by another caller` });
+    }
+
+    const normalized = weights !== undefined ? this.normalizeWeights(weights) : undefined;
+    const id = randomUUID();
+    const pool: Pool = {
+      id,
+      tokenA,
+      tokenB,
+      reserveA,
+      reserveB,
+      lpToken: `LP-${tokenA}-${tokenB}`,
+      // LP supply starts at the pool invariant: √(a·b) for constant product,
+      // a^wA · b^wB for weighted — both in deterministic fixed point.
+      totalLpSupply:
+        normalized === undefined
+          ? wadToNumber(sqrtWad(wadFromNumber(reserveA * reserveB)))
+          : wadToNumber(
+              mulWadDown(
+                powWad(wadFromNumber(reserveA), wadFromNumber(normalized.weightA)),
+                powWad(wadFromNumber(reserveB), wadFromNumber(normalized.weightB)),
+              ),
+            ),
+      feeRate: this.defaultFeeRate(),
+      poolType: normalized === undefined ? 'constant_product' : 'weighted',
+      ...(normalized !== undefined ? { weightA: normalized.weightA, weightB: normalized.weightB } : {}),
+      storageAccount,
+      isPaused: false,
+      liquidityAddsDisabled: false,
+      unpauseRequiresReview: false,
+      pendingSettlement: false,
+      settlementTxRefs: [],
+      cumFeesA: 0,
+      cumFeesB: 0,
+      feeGrowthGlobalA: 0,
+      feeGrowthGlobalB: 0,
+      protocolFeesA: 0,
+      protocolFeesB: 0,
+    };
+    this.pools.set(id, pool);
+    this.creationKeys.set(key, id);
+    this.creationReservations.delete(key);
+    // The pool's storage account holds the initial LP supply until liquidity
+    // providers are tracked individually.
+    this.balances.credit(storageAccount, pool.lpToken, pool.totalLpSupply);
+    this.logger.log(`Created pool ${id} for ${tokenA}/${tokenB}`);
+    this.auditLog.record({
+      category: 'pool',
+      action: 'pool_created',
+      subject: pool.id,
+      after: { token_a: tokenA, token_b: tokenB, reserve_a: reserveA.toString(), reserve_b: reserveB.toString() },
+    });
+    this.emit('pool_created', pool.id, { token_a: tokenA, token_b: tokenB });
+    return this.toPoolInfo(pool);
+  }
+
+  /** Direct pool for the pair, picking the deepest when several exist. */
+  findPool(tokenIn: string, tokenOut: string): Pool | undefined {
+    let best: Pool | undefined;
+    for (const pool of this.pools.values()) {
+      if (pool.isPaused) continue;
+      const matches =
+        (pool.tokenA === tokenIn && pool.tokenB === tokenOut) ||
+        (pool.tokenB === tokenIn && pool.tokenA === tokenOut);
+      if (!matches) continue;
+      if (!best || pool.reserveA * pool.reserveB > best.reserveA * best.reserveB) {
+        best = pool;
+      }
+    }
+    return best;
+  }
+
+  quote(poolId: string, tokenIn: string, amountIn: number): QuoteResult {
+    const pool = this.getPool(poolId);
+    const campaign = this.campaigns.activeCampaign(pool.id, `${pool.tokenA}/${pool.tokenB}`);
+    const { amountOut, fee, waived, priceImpact } = this.computeSwap(pool, tokenIn, amountIn, campaign?.discount ?? 0);
+    return {
+      amount_out: amountOut.toString(),
+      fee: fee.toString(),
+      price_impact: priceImpact.toString(),
+      minimum_received: (amountOut * (1 - DEFAULT_SLIPPAGE)).toString(),
+      route: `${tokenIn} -> ${tokenIn === pool.tokenA ? pool.tokenB : pool.tokenA}`,
+      ...(campaign ? { fee_discount: waived.toString(), campaign_id: campaign.id } : {}),
+    };
+  }
+
+  /**
+   * Swap against a pool on behalf of a user, debiting/crediting the internal
+   * ledger and updating reserves.
+   */
+  swap(user: string, pool: Pool, tokenIn: string, amountIn: number): SwapResult {
+    const reservesBefore = { reserve_a: pool.reserveA.toString(), reserve_b: pool.reserveB.toString() };
+    const campaign = this.campaigns.activeCampaign(pool.id, `${pool.tokenA}/${pool.tokenB}`);
+    const { amountOut, fee, waived } = this.computeSwap(pool, tokenIn, amountIn, campaign?.discount ?? 0);
+    if (campaign && waived > 0) {
+      this.campaigns.recordRebate(campaign.id, pool.id, tokenIn, waived);
+    }
+    const protocolCut = fee * this.protocolFeeShare();
+    const lpFee = fee - protocolCut;
+    // Checked updates: compute every new value before posting the ledger
+    // legs, so an overflow or underflow rejects the swap before any state
+    // (balances or reserves) has moved.
+    const tokenOut = tokenIn === pool.tokenA ? pool.tokenB : pool.tokenA;
+    const nextReserveIn = checkedAdd(tokenIn === pool.tokenA ? pool.reserveA : pool.reserveB, amountIn, 'swap reserve in');
+    const nextReserveOut = checkedSub(tokenIn === pool.tokenA ? pool.reserveB : pool.reserveA, amountOut, 'swap reserve out');
+    this.balances.transaction((tx) => {
+      tx.debit(user, tokenIn, amountIn);
+      tx.credit(user, tokenOut, amountOut);
+    });
+    if (tokenIn === pool.tokenA) {
+      const reserveA = nextReserveIn;
+      const reserveB = nextReserveOut;
+      pool.cumFeesA = checkedAdd(pool.cumFeesA, fee, 'swap cumFeesA');
+      pool.protocolFeesA = checkedAdd(pool.protocolFeesA, protocolCut, 'swap protocolFeesA');
+      if (pool.totalLpSupply > 0) {
+        pool.feeGrowthGlobalA = checkedAdd(pool.feeGrowthGlobalA, lpFee / pool.totalLpSupply, 'swap feeGrowthA');
+      }
+      pool.reserveA = reserveA;
+      pool.reserveB = reserveB;
+    } else {
+      const reserveB = nextReserveIn;
+      const reserveA = nextReserveOut;
+      pool.cumFeesB = checkedAdd(pool.cumFeesB, fee, 'swap cumFeesB');
+      pool.protocolFeesB = checkedAdd(pool.protocolFeesB, protocolCut, 'swap protocolFeesB');
+      if (pool.totalLpSupply > 0) {
+        pool.feeGrowthGlobalB = checkedAdd(pool.feeGrowthGlobalB, lpFee / pool.totalLpSupply, 'swap feeGrowthB');
+      }
+      pool.reserveB = reserveB;
+      pool.reserveA = reserveA;
+    }
+    this.auditLog.record({
+      category: 'pool',
+      action: 'swap',
+      actor: user,
+      subject: pool.id,
+      before: reservesBefore,
+      after: { reserve_a: pool.reserveA.toString(), reserve_b: pool.reserveB.toString() },
+      data: { token_in: tokenIn, amount_in: amountIn.toString(), amount_out: amountOut.toString(), fee: fee.toString() },
+    });
+    this.emit('swap_confirmed', pool.id, {
+      token_in: tokenIn,
+      amount_in: amountIn.toString(),
+      amount_out: amountOut.toString(),
+      fee: fee.toString(),
+    });
+    this.emit('reserves_updated', pool.id, {
+      reserve_a: pool.reserveA.toString(),
+      reserve_b: pool.reserveB.toString(),
+    });
+    this.swaps$.next({
+      user,
+      pool_id: pool.id,
+      token_in: tokenIn,
+      token_out: tokenOut,
+      amount_in: amountIn,
+      amount_out: amountOut,
+      fee,
+      at: new Date().toISOString(),
+    });
+    return { amountOut, fee, pool };
+  }
+
+  /**
+   * Deposit both sides, mint LP pro-rata and update reserves in one step so
+   * no caller can observe reserves without the matching supply change. All
+   * liquidity paths must go through here rather than mutating reserves
+   * directly.
+   */
+  addLiquidity(pool: Pool, user: string, amountA: number, amountB: number): number {
+    if (pool.liquidityAddsDisabled) {
+      throw new Error(`Liquidity adds are disabled for pool ${pool.id} pending drift review`);
+    }
+    const lpMinted = checkedMul(pool.totalLpSupply, Math.min(amountA / pool.reserveA, amountB / pool.reserveB), 'addLiquidity lpMinted');
+    if (!(lpMinted > 0)) {
+      throw new Error('Deposit amounts too small to mint liquidity');
+    }
+    const nextReserveA = checkedAdd(pool.reserveA, amountA, 'addLiquidity reserveA');
+    const nextReserveB = checkedAdd(pool.reserveB, amountB, 'addLiquidity reserveB');
+    const nextLpSupply = checkedAdd(pool.totalLpSupply, lpMinted, 'addLiquidity lpSupply');
+    this.balances.transaction((tx) => {
+      tx.debit(user, pool.tokenA, amountA);
+      tx.debit(user, pool.tokenB, amountB);
+      tx.credit(user, pool.lpToken, lpMinted);
+    });
+    const reservesBefore = { reserve_a: pool.reserveA.toString(), reserve_b: pool.reserveB.toString(), lp_supply: pool.totalLpSupply.toString() };
+    pool.reserveA = nextReserveA;
+    pool.reserveB = nextReserveB;
+    pool.totalLpSupply = nextLpSupply;
+    this.auditLog.record({
+      category: 'pool',
+      action: 'liquidity_added',
+      actor: user,
+      subject: pool.id,
+      before: reservesBefore,
+      after: { reserve_a: pool.reserveA.toString(), reserve_b: pool.reserveB.toString(), lp_supply: pool.totalLpSupply.toString() },
+      data: { lp_minted: lpMinted.toString() },
+    });
+    // Per-wallet settlement tracking: the LP can follow this op from pending
+    // to complete (with tx reference) via their settlements endpoint.
+    this.settlementQueue.enqueue('pool_deposit', {
+      pool_id: pool.id,
+      wallet_address: user,
+      token_a: pool.tokenA,
+      amount_a: amountA.toString(),
+      token_b: pool.tokenB,
+      amount_b: amountB.toString(),
+    });
+    pool.pendingSettlement = true;
+    this.emit('reserves_updated', pool.id, {
+      reserve_a: pool.reserveA.toString(),
+      reserve_b: pool.reserveB.toString(),
+    });
+    return lpMinted;
+  }
+
+  /** Burn LP and withdraw the pro-rata share of both reserves in one step. */
+  removeLiquidity(pool: Pool, user: string, lpAmount: number): { amountA: number; amountB: number } {
+    if (!(lpAmount > 0) || lpAmount > pool.totalLpSupply) {
+      throw new Error(`Invalid LP amount ${lpAmount} against supply ${pool.totalLpSupply}`);
+    }
+    const share = lpAmount / pool.totalLpSupply;
+    const amountA = checkedMul(pool.reserveA, share, 'removeLiquidity amountA');
+    const amountB = checkedMul(pool.reserveB, share, 'removeLiquidity amountB');
+    const nextReserveA = checkedSub(pool.reserveA, amountA, 'removeLiquidity reserveA');
+    const nextReserveB = checkedSub(pool.reserveB, amountB, 'removeLiquidity reserveB');
+    const nextLpSupply = checkedSub(pool.totalLpSupply, lpAmount, 'removeLiquidity lpSupply');
+    this.balances.transaction((tx) => {
+      tx.debit(user, pool.lpToken, lpAmount);
+      tx.credit(user, pool.tokenA, amountA);
+      tx.credit(user, pool.tokenB, amountB);
+    });
+    const reservesBefore = { reserve_a: pool.reserveA.toString(), reserve_b: pool.reserveB.toString(), lp_supply: pool.totalLpSupply.toString() };
+    pool.reserveA = nextReserveA;
+    pool.reserveB = nextReserveB;
+    pool.totalLpSupply = nextLpSupply;
+    this.auditLog.record({
+      category: 'pool',
+      action: 'liquidity_removed',
+      actor: user,
+      subject: pool.id,
+      before: reservesBefore,
+      after: { reserve_a: pool.reserveA.toString(), reserve_b: pool.reserveB.toString(), lp_supply: pool.totalLpSupply.toString() },
+      data: { lp_burned: lpAmount.toString() },
+    });
+    this.settlementQueue.enqueue('pool_withdraw', {
+      pool_id: pool.id,
+      wallet_address: user,
+      token_a: pool.tokenA,
+      amount_a: amountA.toString(),
+      token_b: pool.tokenB,
+      amount_b: amountB.toString(),
+    });
+    pool.pendingSettlement = true;
+    this.emit('reserves_updated', pool.id, {
+      reserve_a: pool.reserveA.toString(),
+      reserve_b: pool.reserveB.toString(),
+    });
+    return { amountA, amountB };
+  }
+
+  /** Accrued fee state for a pool: gross, LP-attributed and protocol cuts. */
+  feeReport(poolId: string) {
+    const pool = this.getPool(poolId);
+    return {
+      pool_id: pool.id,
+      fee_rate: pool.feeRate.toString(),
+      protocol_fee_share: this.protocolFeeShare().toString(),
+      gross_fees: { [pool.tokenA]: pool.cumFeesA.toString(), [pool.tokenB]: pool.cumFeesB.toString() },
+      lp_fees: {
+        [pool.tokenA]: (pool.cumFeesA - pool.protocolFeesA).toString(),
+        [pool.tokenB]: (pool.cumFeesB - pool.protocolFeesB).toString(),
+      },
+      protocol_fees: { [pool.tokenA]: pool.protocolFeesA.toString(), [pool.tokenB]: pool.protocolFeesB.toString() },
+      fee_growth_global: { [pool.tokenA]: pool.feeGrowthGlobalA.toString(), [pool.tokenB]: pool.feeGrowthGlobalB.toString() },
+    };
+  }
+
+  /** Pause or resume a pool, publishing the transition to subscribers. */
+  setPaused(pool: Pool, paused: boolean): void {
+    if (pool.isPaused === paused) {
+      return;
+    }
+    if (!paused && pool.unpauseRequiresReview) {
+      throw new ConflictException({
+        code: 'DRIFT_REVIEW_REQUIRED',
+        message: `Pool ${pool.id} requires a drift review before it can be unpaused`,
+      });
+    }
+    pool.isPaused = paused;
+    this.auditLog.record({
+      category: 'pool',
+      action: paused ? 'pool_paused' : 'pool_unpaused',
+      subject: pool.id,
+      before: { is_paused: !paused },
+      after: { is_paused: paused },
+    });
+    this.emit(paused ? 'paused' : 'unpaused', pool.id, {});
+  }
+
+  /** Escalation hooks for the drift policy; cleared by an admin review. */
+  setLiquidityAddsDisabled(pool: Pool, disabled: boolean): void {
+    pool.liquidityAddsDisabled = disabled;
+  }
+
+  setUnpauseReviewRequired(pool: Pool, required: boolean): void {
+    pool.unpauseRequiresReview = required;
+  }
+
+  private emit(type: PoolEvent['type'], poolId: string, data: Record<string, unknown>): void {
+    this.events$.next({ type, pool_id: poolId, data, at: new Date().toISOString() });
+  }
+
+  creditProtocolFees(token: string, amount: number): void {
+    this.protocolFees.set(token, (this.protocolFees.get(token) ?? 0) + amount);
+  }
+
+  getProtocolFees(): Array<{ token: string; amount: string }> {
+    return Array.from(this.protocolFees.entries()).map(([token, amount]) => ({ token, amount: amount.toString() }));
+  }
+
+  /** Input amount of tokenIn required to receive exactly amountOut of the other token. */
+  quoteExactOut(pool: Pool, tokenIn: string, amountOut: number): number {
+    const [reserveIn, reserveOut] =
+      tokenIn === pool.tokenA
+        ? [pool.reserveA, pool.reserveB]
+        : tokenIn === pool.tokenB
+          ? [pool.reserveB, pool.reserveA]
+          : [0, 0];
+    if (reserveIn === 0) {
+      throw new Error(`Token ${tokenIn} is not part of pool ${pool.id}`);
+    }
+    if (amountOut >= reserveOut) {
+      throw new Error(`Requested output ${amountOut} exceeds pool reserves`);
+    }
+    if (pool.poolType === 'weighted') {
+      if (amountOut > reserveOut * MAX_WEIGHTED_OUT_RATIO) {
+        throw new Error(`Requested output ${amountOut} exceeds the weighted pool out ratio`);
+      }
+      const [weightIn, weightOut] = this.weightsFor(pool, tokenIn);
+      // in = rIn · ((rOut / (rOut − out))^(wOut/wIn) − 1)
+      const ratio = powWad(
+        divWadDown(wadFromNumber(reserveOut), wadFromNumber(reserveOut - amountOut)),
+        divWadDown(wadFromNumber(weightOut), wadFromNumber(weightIn)),
+      );
+      const amountInAfterFee = wadToNumber(mulWadDown(wadFromNumber(reserveIn), ratio - WAD));
+      return amountInAfterFee / (1 - pool.feeRate);
+    }
+    const amountInAfterFee = (reserveIn * amountOut) / (reserveOut - amountOut);
+    return amountInAfterFee / (1 - pool.feeRate);
+  }
+
+  private computeSwap(
+    pool: Pool,
+    tokenIn: string,
+    amountIn: number,
+    feeDiscount = 0,
+  ): { amountOut: number; fee: number; waived: number; priceImpact: number } {
+    if (pool.isPaused) {
+      throw new Error(`Pool ${pool.id} is paused`);
+    }
+    if (!(amountIn > 0)) {
+      throw new Error(`Swap amount must be positive: ${amountIn}`);
+    }
+    const [reserveIn, reserveOut] =
+      tokenIn === pool.tokenA
+        ? [pool.reserveA, pool.reserveB]
+        : tokenIn === pool.tokenB
+          ? [pool.reserveB, pool.reserveA]
+          : [0, 0];
+    if (reserveIn === 0) {
+      throw new Error(`Token ${tokenIn} is not part of pool ${pool.id}`);
+    }
+
+    const fullFee = amountIn * pool.feeRate;
+    const fee = fullFee * (1 - feeDiscount);
+    const waived = fullFee - fee;
+    const amountInAfterFee = amountIn - fee;
+    // The curve itself is evaluated in deterministic fixed point so quotes
+    // are bit-identical across platforms and match on-chain math.
+    let amountOut: number;
+    let spotPrice: number;
+    if (pool.poolType === 'weighted') {
+      if (amountInAfterFee > reserveIn * MAX_WEIGHTED_IN_RATIO) {
+        throw new Error(`Swap amount ${amountIn} exceeds the weighted pool in ratio`);
+      }
+      const [weightIn, weightOut] = this.weightsFor(pool, tokenIn);
+      // out = rOut · (1 − (rIn / (rIn + in))^(wIn/wOut))
+      const ratio = powWad(
+        divWadDown(wadFromNumber(reserveIn), wadFromNumber(reserveIn + amountInAfterFee)),
+        divWadDown(wadFromNumber(weightIn), wadFromNumber(weightOut)),
+      );
+      amountOut = wadToNumber(mulWadDown(wadFromNumber(reserveOut), WAD - ratio));
+      spotPrice = (reserveOut / weightOut) / (reserveIn / weightIn);
+    } else {
+      amountOut = wadToNumber(
+        mulDivDown(
+          wadFromNumber(reserveOut),
+          wadFromNumber(amountInAfterFee),
+          wadFromNumber(reserveIn + amountInAfterFee),
+        ),
+      );
+      spotPrice = reserveOut / reserveIn;
+    }
+    const executionPrice = amountOut / amountIn;
+    const priceImpact = spotPrice > 0 ? Math.max(0, 1 - executionPrice / spotPrice) : 0;
+
+    return { amountOut, fee, waived, priceImpact };
+  }
+
+  /** Order-insensitive creation key: sorted pair plus storage account. */
+  private creationKey(tokenA: string, tokenB: string, storageAccount: string): string {
+    const [first, second] = [tokenA, tokenB].sort();
+    return `${first}/${second}@${storageAccount}`;
+  }
+
+  /** Validate and normalize creation weights to fractions summing to one. */
+  private normalizeWeights(weights: { weightA: number; weightB: number }): { weightA: number; weightB: number } {
+    const { weightA, weightB } = weights;
+    if (!(weightA > 0) || !(weightB > 0) || !Number.isFinite(weightA) || !Number.isFinite(weightB)) {
+      throw new Error(`Pool weights must be positive finite numbers: ${weightA}, ${weightB}`);
+    }
+    const sum = weightA + weightB;
+    const normalized = { weightA: weightA / sum, weightB: weightB / sum };
+    if (normalized.weightA < MIN_NORMALIZED_WEIGHT || normalized.weightB < MIN_NORMALIZED_WEIGHT) {
+      throw new Error(`Normalized pool weights must each be at least ${MIN_NORMALIZED_WEIGHT}`);
+    }
+    return normalized;
+  }
+
+  /** [weightIn, weightOut] for a weighted pool, oriented by the input token. */
+  private weightsFor(pool: Pool, tokenIn: string): [number, number] {
+    if (pool.weightA === undefined || pool.weightB === undefined) {
+      throw new Error(`Pool ${pool.id} has no weights configured`);
+    }
+    return tokenIn === pool.tokenA ? [pool.weightA, pool.weightB] : [pool.weightB, pool.weightA];
+  }
+
+  private toPoolInfo(pool: Pool): PoolInfoResponse {
+    return {
+      id: pool.id,
+      token_a: pool.tokenA,
+      token_b: pool.tokenB,
+      reserve_a: pool.reserveA.toString(),
+      reserve_b: pool.reserveB.toString(),
+      lp_token: pool.lpToken,
+      total_lp_supply: pool.totalLpSupply.toString(),
+      fee_rate: pool.feeRate.toString(),
+      pool_type: pool.poolType,
+      ...(pool.weightA !== undefined && pool.weightB !== undefined
+        ? { weight_a: pool.weightA.toString(), weight_b: pool.weightB.toString() }
+        : {}),
+      storage_account: pool.storageAccount,
+      is_paused: pool.isPaused,
+      liquidity_adds_disabled: pool.liquidityAddsDisabled,
+      pending_settlement: pool.pendingSettlement,
+      settlement_tx_refs: pool.settlementTxRefs,
+      token_a_display: this.tokens.getDisplayMetadata(pool.tokenA),
+      token_b_display: this.tokens.getDisplayMetadata(pool.tokenB),
+      health: this.healthByPool.get(pool.id),
+      protocol_fees_a: pool.protocolFeesA.toString(),
+      protocol_fees_b: pool.protocolFeesB.toString(),
+    };
+  }
+
+  /**
+   * Queue settlement of a pool's accrued protocol fees to the treasury
+   * account and reset the accumulators. The fees sit in the pool's reserves
+   * until the queued withdrawal confirms on-chain, so a settlement failure
+   * loses nothing — the op can simply be retried.
+   */
+  collectProtocolFees(poolId: string): { pool_id: string; treasury: string; ops: Array<{ op_id: string; token: string; amount: string }> } {
+    const pool = this.getPool(poolId);
+    const treasury = this.config.get<string>('PROTOCOL_TREASURY_ACCOUNT');
+    if (!treasury) {
+      throw new Error('PROTOCOL_TREASURY_ACCOUNT is not configured');
+    }
+    if (pool.protocolFeesA <= 0 && pool.protocolFeesB <= 0) {
+      throw new Error(`Pool ${poolId} has no accrued protocol fees`);
+    }
+
+    const ops: Array<{ op_id: string; token: string; amount: string }> = [];
+    for (const [token, amount] of [
+      [pool.tokenA, pool.protocolFeesA],
+      [pool.tokenB, pool.protocolFeesB],
+    ] as Array<[string, number]>) {
+      if (amount <= 0) {
+        continue;
+      }
+      const op = this.settlementQueue.enqueue('pool_withdraw', {
+        pool_id: pool.id,
+        token,
+        amount: amount.toString(),
+        to: treasury,
+        reason: 'protocol fee collection',
+      });
+      ops.push({ op_id: op.id, token, amount: amount.toString() });
+    }
+    if (ops.length > 0) {
+      pool.pendingSettlement = true;
+    }
+    pool.protocolFeesA = 0;
+    pool.protocolFeesB = 0;
+    this.logger.log(`Queued protocol fee collection for pool ${poolId} to ${treasury}`);
+    return { pool_id: pool.id, treasury, ops };
+  }
+
+  /** Record the health verdict for a pool; surfaced via PoolInfo. */
+  setHealth(poolId: string, health: PoolHealth): void {
+    this.healthByPool.set(poolId, health);
+  }
+
+  getHealth(poolId: string): PoolHealth | undefined {
+    return this.healthByPool.get(poolId);
+  }
+}
@@ -0,0 +1,39 @@
+import { Body, Controller, Delete, Get, Param, Post, Query } from '@nestjs/common';
+
+import { PositionsService } from './positions.service';
+import { OpenPositionDto, TransferPositionDto } from './dto/position.dto';
+
+@Controller('pools/positions')
+export class PositionsController {
+  constructor(private readonly positions: PositionsService) {}
+
+  @Get()
+  list(@Query('owner') owner?: string, @Query('pool_id') poolId?: string) {
+    return { positions: this.positions.listPositions(owner, poolId) };
+  }
+
+  @Get('wallet/:walletAddress')
+  portfolio(@Param('walletAddress') walletAddress: string) {
+    return this.positions.portfolio(walletAddress);
+  }
+
+  @Get(':positionId')
+  get(@Param('positionId') positionId: string) {
+    return this.positions.getPosition(positionId);
+  }
+
+  @Post()
+  open(@Body() body: OpenPositionDto) {
+    return this.positions.openPosition(body);
+  }
+
+  @Post(':positionId/transfer')
+  transfer(@Param('positionId') positionId: string, @Body() body: TransferPositionDto) {
+    return this.positions.transferPosition(positionId, body.from, body.to);
+  }
+
+  @Delete(':positionId')
+  close(@Param('positionId') positionId: string, @Query('owner') owner: string) {
+    return this.positions.closePosition(positionId, owner);
+  }
+}
@@ -0,0 +1,201 @@
+import { BadRequestException, Injectable, Logger, NotFoundException } from '@nestjs/common';
+import { randomUUID } from 'crypto';
+
+import { BalancesService } from '../balances/balances.service';
+import { PoolsService } from './pools.service';
+
+export interface LpPosition {
+  id: string;
+  pool_id: string;
+  owner: string;
+  lp_amount: number;
+  deposited_a: number;
+  deposited_b: number;
+  /** Price range bounds for concentrated positions; unset means full range. */
+  lower_price?: number;
+  upper_price?: number;
+  /** ISO timestamp until which the position cannot be withdrawn or transferred. */
+  lock_until?: string;
+  transferable: boolean;
+  /** feeGrowthGlobal at open; fees earned are growth since then times LP held. */
+  fee_checkpoint_a: number;
+  fee_checkpoint_b: number;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface OpenPositionInput {
+  pool_id: string;
+  owner: string;
+  amount_a: number;
+  amount_b: number;
+  lower_price?: number;
+  upper_price?: number;
+  lock_until?: string;
+  transferable?: boolean;
+}
+
+/**
+ * Internal representation of LP positions with unique IDs, independent of any
+ * future on-chain tokenization. Fungible LP balances stay in the internal
+ * ledger; positions add ownership records, range/lock parameters and fee
+ * checkpoints on top.
+ */
+@Injectable()
+export class PositionsService {
+  private readonly logger = new Logger(PositionsService.name);
+  private readonly positions = new Map<string, LpPosition>();
+
+  constructor(
+    private readonly pools: PoolsService,
+    private readonly balances: BalancesService,
+  ) {}
+
+  listPositions(owner?: string, poolId?: string): LpPosition[] {
+    return Array.from(this.positions.values()).filter(
+      (position) => (!owner || position.owner === owner) && (!poolId || position.pool_id === poolId),
+    );
+  }
+
+  getPosition(positionId: string): LpPosition {
+    const position = this.positions.get(positionId);
+    if (!position) {
+      throw new NotFoundException(`Position ${positionId} not found`);
+    }
+    return position;
+  }
+
+  openPosition(input: OpenPositionInput): LpPosition {
+    const pool = this.pools.getPool(input.pool_id);
+    if (input.lower_price !== undefined && input.upper_price !== undefined && input.lower_price >= input.upper_price) {
+      throw new BadRequestException('lower_price must be below upper_price');
+    }
+
+    let lpMinted: number;
+    try {
+      lpMinted = this.pools.addLiquidity(pool, input.owner, input.amount_a, input.amount_b);
+    } catch (error) {
+      throw new BadRequestException(error instanceof Error ? error.message : 'Failed to add liquidity');
+    }
+
+    const now = new Date().toISOString();
+    const position: LpPosition = {
+      id: randomUUID(),
+      pool_id: pool.id,
+      owner: input.owner,
+      lp_amount: lpMinted,
+      deposited_a: input.amount_a,
+      deposited_b: input.amount_b,
+      lower_price: input.lower_price,
+      upper_price: input.upper_price,
+      lock_until: input.lock_until,
+      transferable: input.transferable ?? true,
+      fee_checkpoint_a: pool.feeGrowthGlobalA,
+      fee_checkpoint_b: pool.feeGrowthGlobalB,
+      created_at: now,
+      updated_at: now,
+    };
+    this.positions.set(position.id, position);
+    this.logger.log(`Opened position ${position.id} in pool ${pool.id} for ${input.owner}`);
+    return position;
+  }
+
+  transferPosition(positionId: string, from: string, to: string): LpPosition {
+    const position = this.getPosition(positionId);
+    if (position.owner !== from) {
+      throw new BadRequestException(`Position ${positionId} is not owned by ${from}`);
+    }
+    if (!position.transferable) {
+      throw new BadRequestException(`Position ${positionId} is non-transferable`);
+    }
+    this.assertUnlocked(position, 'transferred');
+
+    this.balances.debit(from, this.pools.getPool(position.pool_id).lpToken, position.lp_amount);
+    this.balances.credit(to, this.pools.getPool(position.pool_id).lpToken, position.lp_amount);
+    position.owner = to;
+    position.updated_at = new Date().toISOString();
+    return position;
+  }
+
+  closePosition(
+    positionId: string,
+    owner: string,
+  ): { position: LpPosition; amount_a: string; amount_b: string; fees_earned_a: string; fees_earned_b: string } {
+    const position = this.getPosition(positionId);
+    if (position.owner !== owner) {
+      throw new BadRequestException(`Position ${positionId} is not owned by ${owner}`);
+    }
+    this.assertUnlocked(position, 'closed');
+
+    const pool = this.pools.getPool(position.pool_id);
+    // Attribution only: the fees themselves sit in the reserves, so the
+    // withdrawn amounts already include this position's share.
+    const feesEarnedA = (pool.feeGrowthGlobalA - position.fee_checkpoint_a) * position.lp_amount;
+    const feesEarnedB = (pool.feeGrowthGlobalB - position.fee_checkpoint_b) * position.lp_amount;
+    const { amountA, amountB } = this.pools.removeLiquidity(pool, owner, position.lp_amount);
+
+    this.positions.delete(positionId);
+    this.logger.log(`Closed position ${positionId} in pool ${pool.id}`);
+    return {
+      position,
+      amount_a: amountA.toString(),
+      amount_b: amountB.toString(),
+      fees_earned_a: feesEarnedA.toString(),
+      fees_earned_b: feesEarnedB.toString(),
+    };
+  }
+
+  /**
+   * Everything a wallet holds across pools: tracked positions with cost
+   * basis and current redeemable value, plus any loose LP tokens held
+   * outside a tracked position (e.g. received via transfer of raw balance).
+   */
+  portfolio(wallet: string) {
+    const positions = this.listPositions(wallet).map((position) => {
+      const pool = this.pools.getPool(position.pool_id);
+      const share = pool.totalLpSupply > 0 ? position.lp_amount / pool.totalLpSupply : 0;
+      return {
+        position_id: position.id,
+        pool_id: position.pool_id,
+        lp_amount: position.lp_amount.toString(),
+        share_of_pool: share.toString(),
+        cost_basis_a: position.deposited_a.toString(),
+        cost_basis_b: position.deposited_b.toString(),
+        current_value_a: (pool.reserveA * share).toString(),
+        current_value_b: (pool.reserveB * share).toString(),
+        fees_earned_a: ((pool.feeGrowthGlobalA - position.fee_checkpoint_a) * position.lp_amount).toString(),
+        fees_earned_b: ((pool.feeGrowthGlobalB - position.fee_checkpoint_b) * position.lp_amount).toString(),
+        created_at: position.created_at,
+      };
+    });
+
+    const trackedByPool = new Map<string, number>();
+    for (const position of this.listPositions(wallet)) {
+      trackedByPool.set(position.pool_id, (trackedByPool.get(position.pool_id) ?? 0) + position.lp_amount);
+    }
+
+    const untracked: Array<{ pool_id: string; lp_token: string; lp_amount: string; redeemable_a: string; redeemable_b: string }> = [];
+    for (const pool of this.pools.allPools()) {
+      const held = this.balances.getBalance(wallet, pool.lpToken).available;
+      const loose = held - (trackedByPool.get(pool.id) ?? 0);
+      if (loose > 1e-12) {
+        const share = pool.totalLpSupply > 0 ? loose / pool.totalLpSupply : 0;
+        untracked.push({
+          pool_id: pool.id,
+          lp_token: pool.lpToken,
+          lp_amount: loose.toString(),
+          redeemable_a: (pool.reserveA * share).toString(),
+          redeemable_b: (pool.reserveB * share).toString(),
+        });
+      }
+    }
+
+    return { wallet_address: wallet, positions, untracked_lp: untracked };
+  }
+
+  private assertUnlocked(position: LpPosition, action: string): void {
+    if (position.lock_until && Date.parse(position.lock_until) > Date.now()) {
+      throw new BadRequestException(`Position ${position.id} is locked until ${position.lock_until} and cannot be ${action}`);
+    }
+  }
+}
@@ -0,0 +1,127 @@
+import { Injectable, Logger } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+import { PoolsService } from './pools.service';
+
+export interface QuoteSanityReport {
+  pool_id: string;
+  token_in: string;
+  amount_in: string;
+  quotes: {
+    constant_product: string;
+    stable_swap: string;
+    weighted: string;
+  };
+  max_divergence: string;
+  tolerance: string;
+  within_tolerance: boolean;
+}
+
+const DEFAULT_TOLERANCE = 0.05;
+const STABLE_AMPLIFICATION = 100;
+const NEWTON_ITERATIONS = 64;
+const CONVERGENCE = 1e-10;
+
+/**
+ * Regression tripwire for pool math: computes the same quote through the
+ * constant product, stable swap, and weighted formulas on identical reserves
+ * and flags divergence beyond tolerance. Used by staging validation — the
+ * formulas intentionally live here, independent of PoolsService internals, so
+ * a bug in the production path shows up as a divergence rather than agreeing
+ * with itself.
+ */
+@Injectable()
+export class QuoteSanityService {
+  private readonly logger = new Logger(QuoteSanityService.name);
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly pools: PoolsService,
+  ) {}
+
+  check(poolId: string, tokenIn: string, amountIn: number): QuoteSanityReport {
+    const pool = this.pools.getPool(poolId);
+    const [reserveIn, reserveOut] =
+      tokenIn === pool.tokenA ? [pool.reserveA, pool.reserveB] : [pool.reserveB, pool.reserveA];
+    const amountInAfterFee = amountIn * (1 - pool.feeRate);
+
+    const constantProduct = this.constantProductOut(reserveIn, reserveOut, amountInAfterFee);
+    const stableSwap = this.stableSwapOut(reserveIn, reserveOut, amountInAfterFee);
+    const weighted = this.weightedOut(reserveIn, reserveOut, amountInAfterFee, 0.5, 0.5);
+
+    const values = [constantProduct, stableSwap, weighted];
+    const min = Math.min(...values);
+    const max = Math.max(...values);
+    const maxDivergence = min > 0 ? (max - min) / min : 0;
+    const tolerance = Number(this.config.get<string>('QUOTE_SANITY_TOLERANCE')) || DEFAULT_TOLERANCE;
+    const withinTolerance = maxDivergence <= tolerance;
+
+    if (!withinTolerance) {
+      this.logger.warn(
+        `Quote divergence ${(maxDivergence * 100).toFixed(2)}% on pool ${poolId} for ${amountIn} ${tokenIn} exceeds ${(tolerance * 100).toFixed(1)}%`,
+      );
+    }
+
+    return {
+      pool_id: pool.id,
+      token_in: tokenIn,
+      amount_in: amountIn.toString(),
+      quotes: {
+        constant_product: constantProduct.toString(),
+        stable_swap: stableSwap.toString(),
+        weighted: weighted.toString(),
+      },
+      max_divergence: maxDivergence.toString(),
+      tolerance: tolerance.toString(),
+      within_tolerance: withinTolerance,
+    };
+  }
+
+  private constantProductOut(reserveIn: number, reserveOut: number, amountIn: number): number {
+    return (reserveOut * amountIn) / (reserveIn + amountIn);
+  }
+
+  /** Balancer-style weighted math; equal weights should agree with constant product. */
+  private weightedOut(reserveIn: number, reserveOut: number, amountIn: number, weightIn: number, weightOut: number): number {
+    return reserveOut * (1 - Math.pow(reserveIn / (reserveIn + amountIn), weightIn / weightOut));
+  }
+
+  /** Two-asset StableSwap with fixed amplification, solved by Newton iteration. */
+  private stableSwapOut(reserveIn: number, reserveOut: number, amountIn: number): number {
+    const d = this.stableInvariant(reserveIn, reserveOut);
+    const newReserveOut = this.stableOtherReserve(reserveIn + amountIn, d);
+    return reserveOut - newReserveOut;
+  }
+
+  private stableInvariant(x: number, y: number): number {
+    const sum = x + y;
+    if (sum === 0) return 0;
+    const ann = STABLE_AMPLIFICATION * 4;
+    let d = sum;
+    for (let i = 0; i < NEWTON_ITERATIONS; i += 1) {
+      const dp = (d * d * d) / (4 * x * y);
+      const next = ((ann * sum + dp * 2) * d) / ((ann - 1) * d + 3 * dp);
+      if (Math.abs(next - d) < CONVERGENCE * d) {
+        return next;
+      }
+      d = next;
+    }
+    return d;
+  }
+
+  /** Given one reserve and the invariant, solve for the other reserve. */
+  private stableOtherReserve(x: number, d: number): number {
+    const ann = STABLE_AMPLIFICATION * 4;
+    const c = (d * d * d) / (4 * x * ann);
+    const b = x + d / ann - d;
+    let y = d;
+    for (let i = 0; i < NEWTON_ITERATIONS; i += 1) {
+      const next = (y * y + c) / (2 * y + b);
+      if (Math.abs(next - y) < CONVERGENCE * Math.max(y, 1)) {
+        return next;
+      }
+      y = next;
+    }
+    return y;
+  }
+}
@@ -0,0 +1,179 @@
+import { Injectable, Logger, OnModuleDestroy, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { Subscription } from 'rxjs';
+
+import { PoolsService } from './pools.service';
+import { RouteQuote, RouterService } from './router.service';
+
+interface CachedRoute {
+  tokenIn: string;
+  tokenOut: string;
+  poolIds: string[];
+  /** Reserve snapshot per pool at cache time, for invalidation. */
+  reserves: Map<string, { reserveA: number; reserveB: number }>;
+  cachedAt: string;
+}
+
+export interface RouteCacheMetrics {
+  cached_pairs: number;
+  tracked_pairs: number;
+  hits: number;
+  misses: number;
+  refreshes: number;
+  invalidations: number;
+}
+
+const DEFAULT_TOP_PAIRS = 10;
+const DEFAULT_REFRESH_MS = 30_000;
+const DEFAULT_RESERVE_DELTA = 0.01;
+const PROBE_AMOUNT = 1;
+
+/**
+ * Warm route cache for popular pairs. Pair volume is tracked from swap
+ * events; a background pass precomputes the best path for the top pairs so
+ * hot quotes skip the path enumeration entirely and just price along the
+ * cached pools. Reserve moves past a threshold invalidate the affected
+ * entries immediately rather than waiting for the next refresh.
+ */
+@Injectable()
+export class RouteCacheService implements OnModuleInit, OnModuleDestroy {
+  private readonly logger = new Logger(RouteCacheService.name);
+  private readonly cache = new Map<string, CachedRoute>();
+  private readonly pairVolume = new Map<string, number>();
+  private subscription?: Subscription;
+  private timer?: ReturnType<typeof setInterval>;
+  private hits = 0;
+  private misses = 0;
+  private refreshes = 0;
+  private invalidations = 0;
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly pools: PoolsService,
+    private readonly router: RouterService,
+  ) {}
+
+  onModuleInit(): void {
+    this.subscription = this.pools.events$.subscribe((event) => {
+      if (event.type === 'swap_confirmed') {
+        this.recordVolume(event.pool_id, Number(event.data.amount_in));
+      } else if (event.type === 'reserves_updated') {
+        this.invalidateIfDrifted(event.pool_id);
+      }
+    });
+    const refreshMs = Number(this.config.get<string>('ROUTE_CACHE_REFRESH_MS')) || DEFAULT_REFRESH_MS;
+    this.timer = setInterval(() => this.refreshTopPairs(), refreshMs);
+  }
+
+  onModuleDestroy(): void {
+    this.subscription?.unsubscribe();
+    if (this.timer) {
+      clearInterval(this.timer);
+    }
+  }
+
+  /** Cached best-route quote, falling back to full path search on a miss. */
+  quote(tokenIn: string, tokenOut: string, amountIn: number): RouteQuote {
+    const cached = this.cache.get(this.key(tokenIn, tokenOut));
+    if (cached) {
+      try {
+        const path = cached.poolIds.map((poolId) => this.pools.getPool(poolId));
+        if (path.every((pool) => !pool.isPaused)) {
+          this.hits += 1;
+          return this.router.pricePath(path, tokenIn, amountIn);
+        }
+      } catch {
+        this.cache.delete(this.key(tokenIn, tokenOut));
+      }
+    }
+    this.misses += 1;
+    return this.router.bestRoute(tokenIn, tokenOut, amountIn);
+  }
+
+  refreshTopPairs(): void {
+    const topN = Number(this.config.get<string>('ROUTE_CACHE_TOP_PAIRS')) || DEFAULT_TOP_PAIRS;
+    const top = Array.from(this.pairVolume.entries())
+      .sort((a, b) => b[1] - a[1])
+      .slice(0, topN);
+    for (const [pairKey] of top) {
+      const [tokenIn, tokenOut] = pairKey.split('|');
+      this.refreshPair(tokenIn, tokenOut);
+      this.refreshPair(tokenOut, tokenIn);
+    }
+  }
+
+  metrics(): RouteCacheMetrics {
+    return {
+      cached_pairs: this.cache.size,
+      tracked_pairs: this.pairVolume.size,
+      hits: this.hits,
+      misses: this.misses,
+      refreshes: this.refreshes,
+      invalidations: this.invalidations,
+    };
+  }
+
+  private refreshPair(tokenIn: string, tokenOut: string): void {
+    try {
+      const quote = this.router.bestRoute(tokenIn, tokenOut, PROBE_AMOUNT);
+      const poolIds = quote.hops.map((hop) => hop.pool_id);
+      const reserves = new Map<string, { reserveA: number; reserveB: number }>();
+      for (const poolId of poolIds) {
+        const pool = this.pools.getPool(poolId);
+        reserves.set(poolId, { reserveA: pool.reserveA, reserveB: pool.reserveB });
+      }
+      this.cache.set(this.key(tokenIn, tokenOut), {
+        tokenIn,
+        tokenOut,
+        poolIds,
+        reserves,
+        cachedAt: new Date().toISOString(),
+      });
+      this.refreshes += 1;
+    } catch (error) {
+      this.logger.warn(`Route cache refresh failed for ${tokenIn}->${tokenOut}: ${error instanceof Error ? error.message : 'unknown'}`);
+    }
+  }
+
+  private invalidateIfDrifted(poolId: string): void {
+    const threshold = Number(this.config.get<string>('ROUTE_CACHE_RESERVE_DELTA')) || DEFAULT_RESERVE_DELTA;
+    let pool;
+    try {
+      pool = this.pools.getPool(poolId);
+    } catch {
+      return;
+    }
+    for (const [key, entry] of this.cache) {
+      const snapshot = entry.reserves.get(poolId);
+      if (!snapshot) {
+        continue;
+      }
+      const deltaA = snapshot.reserveA > 0 ? Math.abs(pool.reserveA - snapshot.reserveA) / snapshot.reserveA : 1;
+      const deltaB = snapshot.reserveB > 0 ? Math.abs(pool.reserveB - snapshot.reserveB) / snapshot.reserveB : 1;
+      if (deltaA > threshold || deltaB > threshold) {
+        this.cache.delete(key);
+        this.invalidations += 1;
+        this.refreshPair(entry.tokenIn, entry.tokenOut);
+      }
+    }
+  }
+
+  private recordVolume(poolId: string, amountIn: number): void {
+    if (!Number.isFinite(amountIn)) {
+      return;
+    }
+    let pool;
+    try {
+      pool = this.pools.getPool(poolId);
+    } catch {
+      return;
+    }
+    const [a, b] = [pool.tokenA, pool.tokenB].sort();
+    const key = `${a}|${b}`;
+    this.pairVolume.set(key, (this.pairVolume.get(key) ?? 0) + amountIn);
+  }
+
+  private key(tokenIn: string, tokenOut: string): string {
+    return `${tokenIn}|${tokenOut}`;
+  }
+}
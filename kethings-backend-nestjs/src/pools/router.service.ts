@@ -0,0 +1,249 @@
+import { BadRequestException, Injectable, Logger } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+import { BalancesService } from '../balances/balances.service';
+import { PoolsService, Pool } from './pools.service';
+
+export interface SplitLeg {
+  pool_id: string;
+  fraction: string;
+  amount_in: string;
+  amount_out: string;
+}
+
+export interface SplitPlan {
+  token_in: string;
+  token_out: string;
+  amount_in: string;
+  total_amount_out: string;
+  /** Output of sending everything through the single best pool, for comparison. */
+  single_pool_amount_out: string;
+  legs: SplitLeg[];
+}
+
+export interface RouteHop {
+  pool_id: string;
+  token_in: string;
+  token_out: string;
+  amount_in: string;
+  amount_out: string;
+  fee: string;
+}
+
+export interface RouteQuote {
+  token_in: string;
+  token_out: string;
+  amount_in: string;
+  amount_out: string;
+  cumulative_fee: string;
+  hops: RouteHop[];
+}
+
+const DEFAULT_MAX_ROUTES = 4;
+const DEFAULT_MAX_HOPS = 3;
+const SLICES = 100;
+
+/**
+ * Split router: large swaps get better execution spread across several pools
+ * of the same pair. The split is computed greedily — the input is cut into
+ * slices and each slice goes to the pool with the best marginal price given
+ * what has already been allocated to it — which for constant-product pools
+ * converges on the marginal-price-equalizing allocation.
+ */
+@Injectable()
+export class RouterService {
+  private readonly logger = new Logger(RouterService.name);
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly pools: PoolsService,
+    private readonly balances: BalancesService,
+  ) {}
+
+  splitQuote(tokenIn: string, tokenOut: string, amountIn: number): SplitPlan {
+    const candidates = this.candidatePools(tokenIn, tokenOut);
+    if (candidates.length === 0) {
+      throw new BadRequestException(`No pool found for ${tokenIn}/${tokenOut}`);
+    }
+
+    const maxRoutes = Number(this.config.get<string>('ROUTER_MAX_SPLIT_ROUTES')) || DEFAULT_MAX_ROUTES;
+    const allocations = this.allocate(candidates.slice(0, maxRoutes), tokenIn, amountIn);
+
+    const legs: SplitLeg[] = [];
+    let totalOut = 0;
+    for (const [pool, allocated] of allocations) {
+      if (allocated <= 0) continue;
+      const out = this.outputFor(pool, tokenIn, allocated);
+      totalOut += out;
+      legs.push({
+        pool_id: pool.id,
+        fraction: (allocated / amountIn).toString(),
+        amount_in: allocated.toString(),
+        amount_out: out.toString(),
+      });
+    }
+
+    const singleBest = Math.max(...candidates.map((pool) => this.outputFor(pool, tokenIn, amountIn)));
+    return {
+      token_in: tokenIn,
+      token_out: tokenOut,
+      amount_in: amountIn.toString(),
+      total_amount_out: totalOut.toString(),
+      single_pool_amount_out: singleBest.toString(),
+      legs,
+    };
+  }
+
+  /** Execute a freshly computed split, swapping each leg through its pool. */
+  splitSwap(user: string, tokenIn: string, tokenOut: string, amountIn: number): SplitPlan {
+    const plan = this.splitQuote(tokenIn, tokenOut, amountIn);
+    for (const leg of plan.legs) {
+      const pool = this.pools.getPool(leg.pool_id);
+      const result = this.pools.swap(user, pool, tokenIn, Number(leg.amount_in));
+      // Report executed amounts, which can differ slightly from the quote if
+      // reserves moved between legs.
+      leg.amount_out = result.amountOut.toString();
+    }
+    plan.total_amount_out = plan.legs.reduce((sum, leg) => sum + Number(leg.amount_out), 0).toString();
+    return plan;
+  }
+
+  /**
+   * Best route across the token graph for up to `ROUTER_MAX_HOPS` hops.
+   * Enumerates simple paths, prices each by composing the per-pool formula
+   * hop by hop, and returns the path with the highest final output together
+   * with the per-hop breakdown and cumulative fees in input-token terms.
+   */
+  bestRoute(tokenIn: string, tokenOut: string, amountIn: number): RouteQuote {
+    const maxHops = Number(this.config.get<string>('ROUTER_MAX_HOPS')) || DEFAULT_MAX_HOPS;
+    const paths = this.enumeratePaths(tokenIn, tokenOut, maxHops);
+    if (paths.length === 0) {
+      throw new BadRequestException(`No route found from ${tokenIn} to ${tokenOut} within ${maxHops} hops`);
+    }
+
+    let best: RouteQuote | undefined;
+    for (const path of paths) {
+      const quote = this.pricePath(path, tokenIn, amountIn);
+      if (!best || Number(quote.amount_out) > Number(best.amount_out)) {
+        best = quote;
+      }
+    }
+    return best!;
+  }
+
+  /** Execute the best route, swapping hop by hop through the internal ledger. */
+  routeSwap(user: string, tokenIn: string, tokenOut: string, amountIn: number): RouteQuote {
+    const quote = this.bestRoute(tokenIn, tokenOut, amountIn);
+    let carried = amountIn;
+    let cumulativeFee = 0;
+    for (const hop of quote.hops) {
+      const pool = this.pools.getPool(hop.pool_id);
+      const result = this.pools.swap(user, pool, hop.token_in, carried);
+      hop.amount_in = carried.toString();
+      hop.amount_out = result.amountOut.toString();
+      hop.fee = result.fee.toString();
+      cumulativeFee += result.fee * (carried > 0 ? amountIn / carried : 1);
+      carried = result.amountOut;
+    }
+    quote.amount_out = carried.toString();
+    quote.cumulative_fee = cumulativeFee.toString();
+    return quote;
+  }
+
+  /** All simple paths between two tokens; also used by the depth simulator. */
+  enumeratePaths(tokenIn: string, tokenOut: string, maxHops: number): Pool[][] {
+    const paths: Pool[][] = [];
+    const walk = (current: string, path: Pool[], visited: Set<string>) => {
+      if (path.length > maxHops) return;
+      if (current === tokenOut && path.length > 0) {
+        paths.push([...path]);
+        return;
+      }
+      if (path.length === maxHops) return;
+      for (const pool of this.pools.allPools()) {
+        if (pool.isPaused) continue;
+        const next = pool.tokenA === current ? pool.tokenB : pool.tokenB === current ? pool.tokenA : undefined;
+        if (next === undefined || visited.has(next) || path.includes(pool)) continue;
+        visited.add(next);
+        path.push(pool);
+        walk(next, path, visited);
+        path.pop();
+        visited.delete(next);
+      }
+    };
+    walk(tokenIn, [], new Set([tokenIn]));
+    return paths;
+  }
+
+  /** Price a known path hop by hop; also used by the warm route cache. */
+  pricePath(path: Pool[], tokenIn: string, amountIn: number): RouteQuote {
+    const hops: RouteHop[] = [];
+    let carried = amountIn;
+    let current = tokenIn;
+    let cumulativeFee = 0;
+    for (const pool of path) {
+      const next = pool.tokenA === current ? pool.tokenB : pool.tokenA;
+      const fee = carried * pool.feeRate;
+      const out = this.outputFor(pool, current, carried);
+      // Fees accrue in different tokens along the path; normalize to input
+      // token terms by scaling with the amount carried into each hop.
+      cumulativeFee += fee * (carried > 0 ? amountIn / carried : 1);
+      hops.push({
+        pool_id: pool.id,
+        token_in: current,
+        token_out: next,
+        amount_in: carried.toString(),
+        amount_out: out.toString(),
+        fee: fee.toString(),
+      });
+      carried = out;
+      current = next;
+    }
+    return {
+      token_in: tokenIn,
+      token_out: current,
+      amount_in: amountIn.toString(),
+      amount_out: carried.toString(),
+      cumulative_fee: cumulativeFee.toString(),
+      hops,
+    };
+  }
+
+  private candidatePools(tokenIn: string, tokenOut: string): Pool[] {
+    return this.pools
+      .allPools()
+      .filter(
+        (pool) =>
+          !pool.isPaused &&
+          ((pool.tokenA === tokenIn && pool.tokenB === tokenOut) || (pool.tokenB === tokenIn && pool.tokenA === tokenOut)),
+      )
+      .sort((a, b) => b.reserveA * b.reserveB - a.reserveA * a.reserveB);
+  }
+
+  private allocate(candidates: Pool[], tokenIn: string, amountIn: number): Map<Pool, number> {
+    const allocations = new Map<Pool, number>(candidates.map((pool) => [pool, 0]));
+    const slice = amountIn / SLICES;
+    for (let i = 0; i < SLICES; i += 1) {
+      let best: Pool | undefined;
+      let bestMarginal = -Infinity;
+      for (const pool of candidates) {
+        const allocated = allocations.get(pool)!;
+        const marginal = this.outputFor(pool, tokenIn, allocated + slice) - this.outputFor(pool, tokenIn, allocated);
+        if (marginal > bestMarginal) {
+          bestMarginal = marginal;
+          best = pool;
+        }
+      }
+      allocations.set(best!, allocations.get(best!)! + slice);
+    }
+    return allocations;
+  }
+
+  private outputFor(pool: Pool, tokenIn: string, amountIn: number): number {
+    if (amountIn <= 0) return 0;
+    const [reserveIn, reserveOut] =
+      tokenIn === pool.tokenA ? [pool.reserveA, pool.reserveB] : [pool.reserveB, pool.reserveA];
+    const amountInAfterFee = amountIn * (1 - pool.feeRate);
+    return (reserveOut * amountInAfterFee) / (reserveIn + amountInAfterFee);
+  }
+}
@@ -0,0 +1,168 @@
+import { BadRequestException, Injectable } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+import { Pool, PoolsService } from './pools.service';
+import { RouterService } from './router.service';
+
+export interface DepthPoint {
+  price_impact: string;
+  max_amount_in: string;
+  expected_amount_out: string;
+  route: string;
+}
+
+export interface SimulationReport {
+  token_in: string;
+  token_out: string;
+  max_price_impact: string;
+  /** Largest fill within the caller's impact budget, over the best route. */
+  max_amount_in: string;
+  expected_amount_out: string;
+  route: string;
+  depth_curve: DepthPoint[];
+  routes_considered: number;
+}
+
+const DEFAULT_MAX_HOPS = 3;
+/** Standard rungs of the depth curve; the caller's own budget is appended. */
+const DEPTH_CURVE_IMPACTS = [0.001, 0.005, 0.01, 0.02];
+const SEARCH_ITERATIONS = 48;
+
+/**
+ * Depth simulation: how much of a pair can be traded before price impact
+ * exceeds a budget. Every simple route (direct and multi-hop) is probed with
+ * a doubling-then-bisection search over the input amount — impact is
+ * monotonic in size, so the boundary is well defined — and each rung of the
+ * depth curve reports the route that absorbs the most input at that impact.
+ * Nothing here touches reserves; the simulation prices against live state
+ * through the same quote path real swaps use.
+ */
+@Injectable()
+export class SimulationService {
+  constructor(
+    private readonly config: ConfigService,
+    private readonly pools: PoolsService,
+    private readonly router: RouterService,
+  ) {}
+
+  simulate(tokenIn: string, tokenOut: string, maxPriceImpact: number): SimulationReport {
+    if (!(maxPriceImpact > 0) || maxPriceImpact >= 1) {
+      throw new BadRequestException('max_price_impact must be a fraction between 0 and 1');
+    }
+    const maxHops = Number(this.config.get<string>('ROUTER_MAX_HOPS')) || DEFAULT_MAX_HOPS;
+    const paths = this.router.enumeratePaths(tokenIn, tokenOut, maxHops);
+    if (paths.length === 0) {
+      throw new BadRequestException(`No route found from ${tokenIn} to ${tokenOut} within ${maxHops} hops`);
+    }
+
+    const impacts = [...DEPTH_CURVE_IMPACTS.filter((impact) => impact !== maxPriceImpact), maxPriceImpact].sort((a, b) => a - b);
+    const curve: DepthPoint[] = [];
+    let budgetPoint: DepthPoint | undefined;
+    for (const impact of impacts) {
+      const point = this.bestAtImpact(paths, tokenIn, impact);
+      curve.push(point);
+      if (impact === maxPriceImpact) {
+        budgetPoint = point;
+      }
+    }
+
+    return {
+      token_in: tokenIn,
+      token_out: tokenOut,
+      max_price_impact: maxPriceImpact.toString(),
+      max_amount_in: budgetPoint!.max_amount_in,
+      expected_amount_out: budgetPoint!.expected_amount_out,
+      route: budgetPoint!.route,
+      depth_curve: curve,
+      routes_considered: paths.length,
+    };
+  }
+
+  /** The route absorbing the most input at an impact level, with its output. */
+  private bestAtImpact(paths: Pool[][], tokenIn: string, impact: number): DepthPoint {
+    let bestIn = 0;
+    let bestOut = 0;
+    let bestRoute = '';
+    for (const path of paths) {
+      const amountIn = this.maxInputFor(path, tokenIn, impact);
+      if (amountIn <= bestIn) continue;
+      const outcome = this.priceRoute(path, tokenIn, amountIn);
+      if (!outcome) continue;
+      bestIn = amountIn;
+      bestOut = outcome.amountOut;
+      bestRoute = this.describe(path, tokenIn);
+    }
+    return {
+      price_impact: impact.toString(),
+      max_amount_in: bestIn.toString(),
+      expected_amount_out: bestOut.toString(),
+      route: bestRoute,
+    };
+  }
+
+  /**
+   * Largest input whose cumulative impact stays within the budget. The upper
+   * bound is found by doubling from a reserve-relative seed; weighted pools
+   * throw once a hop exceeds their in-ratio bound, which the search treats
+   * as impact beyond the budget.
+   */
+  private maxInputFor(path: Pool[], tokenIn: string, impactBudget: number): number {
+    const firstReserveIn = path[0].tokenA === tokenIn ? path[0].reserveA : path[0].reserveB;
+    if (!(firstReserveIn > 0)) return 0;
+
+    let lo = 0;
+    let hi = firstReserveIn * 1e-6;
+    let doublings = 0;
+    while (doublings < 60) {
+      const outcome = this.priceRoute(path, tokenIn, hi);
+      if (!outcome || outcome.impact > impactBudget) break;
+      lo = hi;
+      hi *= 2;
+      doublings += 1;
+    }
+    for (let i = 0; i < SEARCH_ITERATIONS; i += 1) {
+      const mid = (lo + hi) / 2;
+      const outcome = this.priceRoute(path, tokenIn, mid);
+      if (outcome && outcome.impact <= impactBudget) {
+        lo = mid;
+      } else {
+        hi = mid;
+      }
+    }
+    return lo;
+  }
+
+  /**
+   * Price a path through the public quote surface (so weighted-pool math and
+   * campaign discounts apply) and compose per-hop impacts multiplicatively.
+   * Returns undefined when any hop rejects the size.
+   */
+  private priceRoute(path: Pool[], tokenIn: string, amountIn: number): { amountOut: number; impact: number } | undefined {
+    if (!(amountIn > 0)) return { amountOut: 0, impact: 0 };
+    let carried = amountIn;
+    let current = tokenIn;
+    let keptFraction = 1;
+    for (const pool of path) {
+      try {
+        const quote = this.pools.quote(pool.id, current, carried);
+        keptFraction *= 1 - Number(quote.price_impact);
+        carried = Number(quote.amount_out);
+      } catch {
+        return undefined;
+      }
+      current = pool.tokenA === current ? pool.tokenB : pool.tokenA;
+      if (!(carried > 0)) return undefined;
+    }
+    return { amountOut: carried, impact: 1 - keptFraction };
+  }
+
+  private describe(path: Pool[], tokenIn: string): string {
+    const tokens = [tokenIn];
+    let current = tokenIn;
+    for (const pool of path) {
+      current = pool.tokenA === current ? pool.tokenB : pool.tokenA;
+      tokens.push(current);
+    }
+    return tokens.join(' -> ');
+  }
+}
@@ -0,0 +1,127 @@
+import { Injectable, Logger } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { randomUUID } from 'crypto';
+
+import { LedgerService } from '../ledger/ledger.service';
+import { PoolsService } from './pools.service';
+
+export type TelemetryStatus = 'confirmed' | 'quarantined';
+
+export interface SwapTelemetryRecord {
+  id: string;
+  pool_id: string;
+  user_address: string;
+  tx_hash: string;
+  token_in: string;
+  amount_in: number;
+  amount_out: number;
+  status: TelemetryStatus;
+  /** Why verification failed, for reconciliation review. */
+  quarantine_reason?: string;
+  recorded_at: string;
+}
+
+const DEFAULT_AMOUNT_TOLERANCE = 0.01;
+const HISTORY_LOOKBACK = 50;
+
+/**
+ * Swap telemetry is client-reported, so amounts and tx hashes cannot be
+ * trusted as-is. Before a swap is marked confirmed the referenced transaction
+ * is checked against Keeta: the staple must exist in the pool storage
+ * account's history and an operation must move a matching amount of the
+ * input token within tolerance. Anything unverifiable lands in quarantine
+ * for reconciliation review rather than being dropped.
+ */
+@Injectable()
+export class SwapTelemetryService {
+  private readonly logger = new Logger(SwapTelemetryService.name);
+  private readonly records = new Map<string, SwapTelemetryRecord>();
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly ledger: LedgerService,
+    private readonly pools: PoolsService,
+  ) {}
+
+  async recordSwap(
+    poolId: string,
+    userAddress: string,
+    txHash: string,
+    tokenIn: string,
+    amountIn: number,
+    amountOut: number,
+  ): Promise<SwapTelemetryRecord> {
+    const pool = this.pools.getPool(poolId);
+    const record: SwapTelemetryRecord = {
+      id: randomUUID(),
+      pool_id: poolId,
+      user_address: userAddress,
+      tx_hash: txHash,
+      token_in: tokenIn,
+      amount_in: amountIn,
+      amount_out: amountOut,
+      status: 'quarantined',
+      recorded_at: new Date().toISOString(),
+    };
+
+    try {
+      const history = await this.ledger.getHistory(pool.storageAccount, HISTORY_LOOKBACK, true);
+      const stapleFound = history.items.some((item) => item.stapleHash === txHash);
+      if (!stapleFound) {
+        record.quarantine_reason = `tx ${txHash} not found in storage account history`;
+      } else if (!this.hasMatchingOperation(history.relevantOps ?? [], pool.storageAccount, tokenIn, amountIn)) {
+        record.quarantine_reason = `no operation moving ~${amountIn} ${tokenIn} through ${pool.storageAccount}`;
+      } else {
+        record.status = 'confirmed';
+      }
+    } catch (error) {
+      record.quarantine_reason = `ledger verification failed: ${error instanceof Error ? error.message : 'unknown error'}`;
+    }
+
+    if (record.status === 'quarantined') {
+      this.logger.warn(`Quarantined swap telemetry for pool ${poolId}: ${record.quarantine_reason}`);
+    }
+    this.records.set(record.id, record);
+    return record;
+  }
+
+  quarantine(): SwapTelemetryRecord[] {
+    return Array.from(this.records.values()).filter((record) => record.status === 'quarantined');
+  }
+
+  listRecords(): SwapTelemetryRecord[] {
+    return Array.from(this.records.values());
+  }
+
+  recordCount(): number {
+    return this.records.size;
+  }
+
+  /** Remove and return records older than the cutoff, for retention pruning. */
+  pruneBefore(cutoff: string): SwapTelemetryRecord[] {
+    const pruned: SwapTelemetryRecord[] = [];
+    for (const [id, record] of this.records) {
+      if (record.recorded_at < cutoff) {
+        pruned.push(record);
+        this.records.delete(id);
+      }
+    }
+    return pruned;
+  }
+
+  private hasMatchingOperation(
+    ops: Array<{ from?: string | null; to?: string | null; amount?: string | null; token?: string | null }>,
+    storageAccount: string,
+    tokenIn: string,
+    amountIn: number,
+  ): boolean {
+    const tolerance = Number(this.config.get<string>('TELEMETRY_AMOUNT_TOLERANCE')) || DEFAULT_AMOUNT_TOLERANCE;
+    return ops.some((op) => {
+      if (op.from !== storageAccount && op.to !== storageAccount) return false;
+      if (op.token !== tokenIn) return false;
+      const amount = Number(op.amount);
+      if (!Number.isFinite(amount) || amount <= 0) return false;
+      return Math.abs(amount - amountIn) / amountIn <= tolerance;
+    });
+  }
+}
@@ -0,0 +1,317 @@
+import { BadRequestException, Injectable } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+import { EngineService } from '../engine/engine.service';
+import { PoolsService, SwapExecution } from '../pools/pools.service';
+import { TradeRecord, TradesService } from '../trades/trades.service';
+import { CostBasisMethod, PreferencesService } from '../users/preferences.service';
+
+export type LotSource = 'trade' | 'swap' | 'deposit';
+
+export interface TaxLot {
+  acquired_at: string;
+  quantity: number;
+  /** Cost per unit, denominated in cost_token. */
+  unit_cost: number;
+  cost_token: string;
+  source: LotSource;
+}
+
+export interface RealizedEntry {
+  at: string;
+  token: string;
+  quantity: string;
+  proceeds_token: string;
+  proceeds: string;
+  /** Omitted when the consumed lots were acquired in a different denomination. */
+  cost_basis?: string;
+  realized_pnl?: string;
+  method: CostBasisMethod;
+  source: LotSource;
+}
+
+export interface TokenPosition {
+  token: string;
+  quantity: string;
+  /** Aggregates are present only when every open lot shares one denomination. */
+  cost_token?: string;
+  cost_basis?: string;
+  average_cost?: string;
+  mark_price?: string;
+  unrealized_pnl?: string;
+  lots: Array<{ acquired_at: string; quantity: string; unit_cost: string; cost_token: string; source: LotSource }>;
+}
+
+export interface PortfolioView {
+  user_address: string;
+  method: CostBasisMethod;
+  positions: TokenPosition[];
+  realized: RealizedEntry[];
+  generated_at: string;
+}
+
+const DEFAULT_MAX_LOTS_PER_TOKEN = 500;
+const DEFAULT_MAX_REALIZED_ENTRIES = 1_000;
+
+/**
+ * Per-user acquisition cost tracking for tax reporting. Every exchange of
+ * one token for another — a book fill, an AMM swap — is recorded as a
+ * disposal of the token given up and an acquisition lot for the token
+ * received, with cost denominated in the counter-token. External deposits
+ * carry no on-ledger cost, so their basis is declared through the portfolio
+ * endpoint (defaulting to the current mark when one exists).
+ *
+ * Realized PnL is matched FIFO or against the weighted-average cost,
+ * per the user's cost_basis_method preference at disposal time. Balances
+ * that predate tracking (or arrive outside the recorded paths) have no
+ * lots and realize at full proceeds — the conservative tax treatment.
+ */
+@Injectable()
+export class CostBasisService {
+  /** user -> token -> open lots, oldest first. */
+  private readonly books = new Map<string, Map<string, TaxLot[]>>();
+  /** user -> realized disposals, newest first, bounded. */
+  private readonly realized = new Map<string, RealizedEntry[]>();
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly engine: EngineService,
+    private readonly pools: PoolsService,
+    private readonly trades: TradesService,
+    private readonly preferences: PreferencesService,
+  ) {
+    this.trades.events$.subscribe((trade) => this.onTrade(trade));
+    this.pools.swaps$.subscribe((swap) => this.onSwap(swap));
+  }
+
+  /** Declare the acquisition cost of an external deposit. */
+  recordDeposit(user: string, token: string, quantity: number, unitCost?: number, costToken?: string): TaxLot {
+    const denom = costToken ?? this.preferences.get(user).preferred_quote_token ?? this.defaultQuoteToken();
+    if (!denom) {
+      throw new BadRequestException('cost_token is required: no preferred quote token or COST_BASIS_QUOTE_TOKEN is configured');
+    }
+    let cost = unitCost;
+    if (cost === undefined) {
+      cost = this.markPrice(token, denom);
+      if (cost === undefined) {
+        throw new BadRequestException(`No market price for ${token}/${denom}; supply unit_cost explicitly`);
+      }
+    }
+    return this.acquire(user, token, quantity, cost, denom, 'deposit', new Date().toISOString());
+  }
+
+  portfolio(user: string): PortfolioView {
+    const book = this.books.get(user) ?? new Map<string, TaxLot[]>();
+    const positions: TokenPosition[] = [];
+    for (const [token, lots] of book) {
+      if (lots.length === 0) continue;
+      positions.push(this.toPosition(token, lots));
+    }
+    positions.sort((a, b) => a.token.localeCompare(b.token));
+    return {
+      user_address: user,
+      method: this.preferences.costBasisMethod(user),
+      positions,
+      realized: this.realized.get(user) ?? [],
+      generated_at: new Date().toISOString(),
+    };
+  }
+
+  /** Flat lot/disposal rows for the CSV statement export. */
+  exportRows(user: string): Array<Record<string, string>> {
+    const rows: Array<Record<string, string>> = [];
+    const view = this.portfolio(user);
+    for (const position of view.positions) {
+      for (const lot of position.lots) {
+        rows.push({
+          kind: 'open_lot',
+          token: position.token,
+          quantity: lot.quantity,
+          denomination: lot.cost_token,
+          unit_cost: lot.unit_cost,
+          proceeds: '',
+          cost_basis: '',
+          realized_pnl: '',
+          at: lot.acquired_at,
+          source: lot.source,
+          method: '',
+        });
+      }
+    }
+    for (const entry of view.realized) {
+      rows.push({
+        kind: 'realized',
+        token: entry.token,
+        quantity: entry.quantity,
+        denomination: entry.proceeds_token,
+        unit_cost: '',
+        proceeds: entry.proceeds,
+        cost_basis: entry.cost_basis ?? '',
+        realized_pnl: entry.realized_pnl ?? '',
+        at: entry.at,
+        source: entry.source,
+        method: entry.method,
+      });
+    }
+    return rows;
+  }
+
+  private onTrade(trade: TradeRecord): void {
+    // Pool-sourced fills also flow through pools.swap and arrive on the
+    // swap stream with the taker's actual token amounts; recording them
+    // here as well would double-count the lot.
+    if (trade.source === 'pool') return;
+    const [base, quote] = trade.market.split('/');
+    const buyer = trade.taker_side === 'buy' ? trade.taker_user : trade.maker_user;
+    const seller = trade.taker_side === 'buy' ? trade.maker_user : trade.taker_user;
+    const notional = trade.price * trade.quantity;
+    this.exchange(buyer, quote, notional, base, trade.quantity, 'trade', trade.executed_at);
+    this.exchange(seller, base, trade.quantity, quote, notional, 'trade', trade.executed_at);
+  }
+
+  private onSwap(swap: SwapExecution): void {
+    this.exchange(swap.user, swap.token_in, swap.amount_in, swap.token_out, swap.amount_out, 'swap', swap.at);
+  }
+
+  /** One exchange = a disposal of tokenIn plus an acquisition lot for tokenOut. */
+  private exchange(user: string, tokenIn: string, amountIn: number, tokenOut: string, amountOut: number, source: LotSource, at: string): void {
+    if (!(amountIn > 0) || !(amountOut > 0)) return;
+    this.dispose(user, tokenIn, amountIn, tokenOut, amountOut, source, at);
+    this.acquire(user, tokenOut, amountOut, amountIn / amountOut, tokenIn, source, at);
+  }
+
+  private acquire(user: string, token: string, quantity: number, unitCost: number, costToken: string, source: LotSource, at: string): TaxLot {
+    const lots = this.lotsFor(user, token);
+    const lot: TaxLot = { acquired_at: at, quantity, unit_cost: unitCost, cost_token: costToken, source };
+    lots.push(lot);
+    const cap = Number(this.config.get<string>('COST_BASIS_MAX_LOTS')) || DEFAULT_MAX_LOTS_PER_TOKEN;
+    while (lots.length > cap) {
+      // Merge the two oldest lots so total basis is preserved; when their
+      // denominations differ the older basis cannot be combined and is dropped.
+      const [first, second] = lots;
+      if (first.cost_token === second.cost_token) {
+        const merged = first.quantity + second.quantity;
+        second.unit_cost = (first.quantity * first.unit_cost + second.quantity * second.unit_cost) / merged;
+        second.quantity = merged;
+        second.acquired_at = first.acquired_at;
+      } else {
+        second.quantity += first.quantity;
+      }
+      lots.shift();
+    }
+    return lot;
+  }
+
+  private dispose(user: string, token: string, quantity: number, proceedsToken: string, proceeds: number, source: LotSource, at: string): void {
+    const lots = this.lotsFor(user, token);
+    const method = this.preferences.costBasisMethod(user);
+    let basis = 0;
+    let mixed = false;
+
+    if (method === 'average') {
+      // Weighted-average cost over every open lot; quantities are then
+      // reduced proportionally so acquisition dates stay meaningful.
+      const held = lots.reduce((sum, lot) => sum + lot.quantity, 0);
+      const covered = Math.min(quantity, held);
+      if (covered > 0) {
+        const totalCost = lots.reduce((sum, lot) => sum + lot.quantity * lot.unit_cost, 0);
+        basis = (totalCost / held) * covered;
+        mixed = lots.some((lot) => lot.cost_token !== proceedsToken);
+        const scale = (held - covered) / held;
+        for (const lot of lots) {
+          lot.quantity *= scale;
+        }
+      }
+    } else {
+      let remaining = quantity;
+      while (remaining > 0 && lots.length > 0) {
+        const lot = lots[0];
+        const consumed = Math.min(lot.quantity, remaining);
+        basis += consumed * lot.unit_cost;
+        if (lot.cost_token !== proceedsToken) mixed = true;
+        lot.quantity -= consumed;
+        remaining -= consumed;
+        if (lot.quantity <= 0) lots.shift();
+      }
+    }
+    while (lots.length > 0 && lots[0].quantity <= 0) lots.shift();
+
+    const entry: RealizedEntry = {
+      at,
+      token,
+      quantity: quantity.toString(),
+      proceeds_token: proceedsToken,
+      proceeds: proceeds.toString(),
+      ...(mixed ? {} : { cost_basis: basis.toString(), realized_pnl: (proceeds - basis).toString() }),
+      method,
+      source,
+    };
+    const history = this.realized.get(user) ?? [];
+    history.unshift(entry);
+    const cap = Number(this.config.get<string>('COST_BASIS_MAX_REALIZED')) || DEFAULT_MAX_REALIZED_ENTRIES;
+    if (history.length > cap) {
+      history.length = cap;
+    }
+    this.realized.set(user, history);
+  }
+
+  private toPosition(token: string, lots: TaxLot[]): TokenPosition {
+    const quantity = lots.reduce((sum, lot) => sum + lot.quantity, 0);
+    const position: TokenPosition = {
+      token,
+      quantity: quantity.toString(),
+      lots: lots.map((lot) => ({
+        acquired_at: lot.acquired_at,
+        quantity: lot.quantity.toString(),
+        unit_cost: lot.unit_cost.toString(),
+        cost_token: lot.cost_token,
+        source: lot.source,
+      })),
+    };
+    const denom = lots[0].cost_token;
+    if (lots.every((lot) => lot.cost_token === denom) && quantity > 0) {
+      const basis = lots.reduce((sum, lot) => sum + lot.quantity * lot.unit_cost, 0);
+      position.cost_token = denom;
+      position.cost_basis = basis.toString();
+      position.average_cost = (basis / quantity).toString();
+      const mark = this.markPrice(token, denom);
+      if (mark !== undefined) {
+        position.mark_price = mark.toString();
+        position.unrealized_pnl = (mark * quantity - basis).toString();
+      }
+    }
+    return position;
+  }
+
+  /** Last trade price for token/denom, falling back to the pool-implied price. */
+  private markPrice(token: string, denom: string): number | undefined {
+    const last = this.engine.getLastPrice(`${token}/${denom}`);
+    if (last !== undefined) {
+      return last;
+    }
+    const pool = this.pools.findPool(token, denom);
+    if (pool) {
+      const [reserveToken, reserveDenom] = pool.tokenA === token ? [pool.reserveA, pool.reserveB] : [pool.reserveB, pool.reserveA];
+      return reserveToken > 0 ? reserveDenom / reserveToken : undefined;
+    }
+    return undefined;
+  }
+
+  private defaultQuoteToken(): string | undefined {
+    return this.config.get<string>('COST_BASIS_QUOTE_TOKEN') || undefined;
+  }
+
+  private lotsFor(user: string, token: string): TaxLot[] {
+    let book = this.books.get(user);
+    if (!book) {
+      book = new Map<string, TaxLot[]>();
+      this.books.set(user, book);
+    }
+    let lots = book.get(token);
+    if (!lots) {
+      lots = [];
+      book.set(token, lots);
+    }
+    return lots;
+  }
+}
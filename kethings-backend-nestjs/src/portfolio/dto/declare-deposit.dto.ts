@@ -0,0 +1,24 @@
+import { Type } from 'class-transformer';
+import { IsNumber, IsOptional, IsPositive, IsString } from 'class-validator';
+
+export class DeclareDepositDto {
+  @IsString()
+  token!: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  quantity!: number;
+
+  /** Acquisition cost per unit; defaults to the current mark price. */
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  unit_cost?: number;
+
+  /** Denomination of unit_cost; defaults to the user's preferred quote token. */
+  @IsOptional()
+  @IsString()
+  cost_token?: string;
+}
@@ -0,0 +1,46 @@
+import { Body, Controller, Get, Param, Post, Query, Res } from '@nestjs/common';
+import type { Response } from 'express';
+
+import { CostBasisService } from './cost-basis.service';
+import { DeclareDepositDto } from './dto/declare-deposit.dto';
+
+const EXPORT_COLUMNS = ['kind', 'token', 'quantity', 'denomination', 'unit_cost', 'proceeds', 'cost_basis', 'realized_pnl', 'at', 'source', 'method'];
+
+@Controller('users/:userAddress/portfolio')
+export class PortfolioController {
+  constructor(private readonly costBasis: CostBasisService) {}
+
+  @Get()
+  portfolio(@Param('userAddress') userAddress: string) {
+    return this.costBasis.portfolio(userAddress);
+  }
+
+  /** Tax-lot statement: open lots plus realized disposals, as JSON or CSV. */
+  @Get('export')
+  export(
+    @Param('userAddress') userAddress: string,
+    @Res({ passthrough: true }) res: Response,
+    @Query('format') format?: string,
+  ) {
+    const rows = this.costBasis.exportRows(userAddress);
+    if (format === 'csv') {
+      res.setHeader('Content-Type', 'text/csv; charset=utf-8');
+      res.setHeader('Content-Disposition', `attachment; filename="portfolio-${userAddress}.csv"`);
+      const lines = [EXPORT_COLUMNS.join(',')];
+      for (const row of rows) {
+        lines.push(EXPORT_COLUMNS.map((column) => this.csvField(row[column] ?? '')).join(','));
+      }
+      return `${lines.join('\n')}\n`;
+    }
+    return { user_address: userAddress, rows, generated_at: new Date().toISOString() };
+  }
+
+  @Post('deposits')
+  declareDeposit(@Param('userAddress') userAddress: string, @Body() body: DeclareDepositDto) {
+    return this.costBasis.recordDeposit(userAddress, body.token, body.quantity, body.unit_cost, body.cost_token);
+  }
+
+  private csvField(value: string): string {
+    return /[",\n]/.test(value) ? `"${value.replace(/"/g, '""')}"` : value;
+  }
+}
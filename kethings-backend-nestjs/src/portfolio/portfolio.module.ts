@@ -0,0 +1,17 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+
+import { CostBasisService } from './cost-basis.service';
+import { PortfolioController } from './portfolio.controller';
+import { EngineModule } from '../engine/engine.module';
+import { PoolsModule } from '../pools/pools.module';
+import { TradesModule } from '../trades/trades.module';
+import { UsersModule } from '../users/users.module';
+
+@Module({
+  imports: [ConfigModule, EngineModule, PoolsModule, TradesModule, UsersModule],
+  providers: [CostBasisService],
+  controllers: [PortfolioController],
+  exports: [CostBasisService],
+})
+export class PortfolioModule {}
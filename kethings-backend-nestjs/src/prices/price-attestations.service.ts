@@ -0,0 +1,117 @@
+import { Injectable, Logger, OnModuleDestroy, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { KeyObject, generateKeyPairSync, randomUUID, sign } from 'crypto';
+
+import { EngineService } from '../engine/engine.service';
+import { PoolsService } from '../pools/pools.service';
+
+export interface PriceAttestation {
+  pair: string;
+  price: string;
+  source: 'book' | 'pool';
+  timestamp: string;
+  key_id: string;
+  /** Base64 ed25519 signature over `${pair}|${price}|${timestamp}`. */
+  signature: string;
+}
+
+export interface AttestationKey {
+  key_id: string;
+  public_key_pem: string;
+  active: boolean;
+  created_at: string;
+}
+
+const DEFAULT_ATTESTATION_INTERVAL_MS = 10_000;
+
+/**
+ * Periodically signs current prices so other services in the monorepo can
+ * consume engine/pool prices without trusting the transport. Keys can be
+ * rotated at runtime; old public keys stay listed for verification.
+ */
+@Injectable()
+export class PriceAttestationsService implements OnModuleInit, OnModuleDestroy {
+  private readonly logger = new Logger(PriceAttestationsService.name);
+  private readonly keys: Array<AttestationKey & { privateKey: KeyObject }> = [];
+  private attestations: PriceAttestation[] = [];
+  private timer?: ReturnType<typeof setInterval>;
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly engine: EngineService,
+    private readonly pools: PoolsService,
+  ) {
+    this.rotateKey();
+  }
+
+  onModuleInit(): void {
+    const intervalMs = Number(this.config.get<string>('PRICE_ATTESTATION_INTERVAL_MS')) || DEFAULT_ATTESTATION_INTERVAL_MS;
+    this.timer = setInterval(() => this.refresh(), intervalMs);
+  }
+
+  onModuleDestroy(): void {
+    if (this.timer) {
+      clearInterval(this.timer);
+    }
+  }
+
+  getAttestations(): PriceAttestation[] {
+    return this.attestations;
+  }
+
+  listKeys(): AttestationKey[] {
+    return this.keys.map(({ privateKey: _privateKey, ...key }) => key);
+  }
+
+  rotateKey(): AttestationKey {
+    const { publicKey, privateKey } = generateKeyPairSync('ed25519');
+    for (const key of this.keys) {
+      key.active = false;
+    }
+    const entry = {
+      key_id: randomUUID(),
+      public_key_pem: publicKey.export({ type: 'spki', format: 'pem' }).toString(),
+      active: true,
+      created_at: new Date().toISOString(),
+      privateKey,
+    };
+    this.keys.push(entry);
+    this.logger.log(`Rotated attestation key; active key is ${entry.key_id}`);
+    const { privateKey: _privateKey, ...publicEntry } = entry;
+    return publicEntry;
+  }
+
+  refresh(): void {
+    const active = this.keys.find((key) => key.active);
+    if (!active) {
+      return;
+    }
+
+    const prices: Array<{ pair: string; price: number; source: 'book' | 'pool' }> = [];
+    for (const pool of this.pools.allPools()) {
+      if (pool.reserveA > 0) {
+        prices.push({ pair: `${pool.tokenA}/${pool.tokenB}`, price: pool.reserveB / pool.reserveA, source: 'pool' });
+      }
+    }
+    for (const { pair } of prices.slice()) {
+      const last = this.engine.getLastPrice(pair);
+      if (last !== undefined) {
+        prices.push({ pair, price: last, source: 'book' });
+      }
+    }
+
+    const timestamp = new Date().toISOString();
+    this.attestations = prices.map(({ pair, price, source }) => {
+      const priceString = price.toString();
+      const message = Buffer.from(`${pair}|${priceString}|${timestamp}`);
+      return {
+        pair,
+        price: priceString,
+        source,
+        timestamp,
+        key_id: active.key_id,
+        signature: sign(null, message, active.privateKey).toString('base64'),
+      };
+    });
+  }
+}
@@ -0,0 +1,25 @@
+import { Controller, Get, Post, UseGuards } from '@nestjs/common';
+
+import { PriceAttestationsService } from './price-attestations.service';
+import { AdminGuard } from '../common/admin.guard';
+
+@Controller('prices')
+export class PricesController {
+  constructor(private readonly attestations: PriceAttestationsService) {}
+
+  @Get('attestations')
+  getAttestations() {
+    return { attestations: this.attestations.getAttestations() };
+  }
+
+  @Get('attestations/keys')
+  listKeys() {
+    return { keys: this.attestations.listKeys() };
+  }
+
+  @Post('attestations/rotate-key')
+  @UseGuards(AdminGuard)
+  rotateKey() {
+    return this.attestations.rotateKey();
+  }
+}
@@ -0,0 +1,15 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+import { PriceAttestationsService } from './price-attestations.service';
+import { PricesController } from './prices.controller';
+import { EngineModule } from '../engine/engine.module';
+import { PoolsModule } from '../pools/pools.module';
+import { AdminGuard } from '../common/admin.guard';
+
+@Module({
+  imports: [ConfigModule, EngineModule, PoolsModule],
+  providers: [PriceAttestationsService, AdminGuard],
+  controllers: [PricesController],
+  exports: [PriceAttestationsService],
+})
+export class PricesModule {}
@@ -0,0 +1,134 @@
+import {
+  BadRequestException,
+  Body,
+  Controller,
+  Delete,
+  ForbiddenException,
+  Get,
+  Param,
+  Post,
+  Query,
+  UseGuards,
+} from '@nestjs/common';
+
+import { AttestationService } from './attestation.service';
+import { DriftArchiveService } from './drift-archive.service';
+import { DriftThresholdsService } from './drift-thresholds.service';
+import { WithdrawalIntegrityService } from './withdrawal-integrity.service';
+import { DriftEscalationService } from './drift-escalation.service';
+import { SimulateDriftDto } from './dto/simulate-drift.dto';
+import { SetDriftThresholdDto } from './dto/set-drift-threshold.dto';
+import { ReviewEscalationDto } from './dto/review-escalation.dto';
+import { AdminGuard } from '../common/admin.guard';
+
+@Controller('admin/reconciliation')
+@UseGuards(AdminGuard)
+export class AdminReconciliationController {
+  constructor(
+    private readonly archive: DriftArchiveService,
+    private readonly attestations: AttestationService,
+    private readonly escalation: DriftEscalationService,
+    private readonly thresholds: DriftThresholdsService,
+    private readonly withdrawalIntegrity: WithdrawalIntegrityService,
+  ) {}
+
+  @Get('drift-thresholds')
+  driftThresholds() {
+    return this.thresholds.listOverrides();
+  }
+
+  @Post('drift-thresholds')
+  setDriftThreshold(@Body() body: SetDriftThresholdDto) {
+    if ((body.pool_id === undefined) === (body.token === undefined)) {
+      throw new BadRequestException('Provide exactly one of pool_id or token');
+    }
+    try {
+      if (body.pool_id !== undefined) {
+        this.thresholds.setPoolThreshold(body.pool_id, { abs: body.abs, pct: body.pct });
+      } else {
+        this.thresholds.setTokenThreshold(body.token!, { abs: body.abs, pct: body.pct });
+      }
+    } catch (error) {
+      throw new BadRequestException(error instanceof Error ? error.message : 'Invalid drift threshold');
+    }
+    return this.thresholds.listOverrides();
+  }
+
+  @Delete('drift-thresholds')
+  clearDriftThreshold(@Query('pool_id') poolId?: string, @Query('token') token?: string) {
+    if ((poolId === undefined) === (token === undefined)) {
+      throw new BadRequestException('Provide exactly one of pool_id or token');
+    }
+    const cleared = poolId !== undefined ? this.thresholds.clearPoolThreshold(poolId) : this.thresholds.clearTokenThreshold(token!);
+    return { cleared };
+  }
+
+  @Get('withdrawal-integrity')
+  withdrawalIntegrityHistory() {
+    return { reports: this.withdrawalIntegrity.getHistory() };
+  }
+
+  @Post('withdrawal-integrity/run')
+  runWithdrawalIntegrity() {
+    return this.withdrawalIntegrity.runCheck();
+  }
+
+  @Post('attestations/run')
+  async runAttestation() {
+    return this.attestations.generate();
+  }
+
+  @Get('escalations')
+  escalations() {
+    return {
+      escalations: this.escalation.listEscalations(),
+      reviews: this.escalation.reviewLog(),
+    };
+  }
+
+  @Post('pools/:poolId/review')
+  reviewEscalation(@Param('poolId') poolId: string, @Body() body: ReviewEscalationDto) {
+    return this.escalation.review(poolId, body.reviewer, body.note, body.unpause ?? false);
+  }
+
+  @Get('history')
+  history(@Query('pool_id') poolId?: string) {
+    return {
+      entries: this.archive.history(poolId),
+      trends: this.archive.trends(poolId),
+    };
+  }
+
+  @Post('run-cycle')
+  async runCycle() {
+    return { entries: await this.archive.runCycle() };
+  }
+
+  @Get('simulated-drift')
+  simulatedDrifts() {
+    this.assertSimulationEnabled();
+    return { scenarios: this.archive.listSimulatedDrifts() };
+  }
+
+  @Post('simulated-drift')
+  simulateDrift(@Body() body: SimulateDriftDto) {
+    this.assertSimulationEnabled();
+    try {
+      return this.archive.setSimulatedDrift(body.pool_id, body.token, body.offset);
+    } catch (error) {
+      throw new BadRequestException(error instanceof Error ? error.message : 'Failed to inject drift');
+    }
+  }
+
+  @Delete('simulated-drift')
+  clearSimulatedDrift(@Query('pool_id') poolId?: string) {
+    this.assertSimulationEnabled();
+    return { cleared: this.archive.clearSimulatedDrift(poolId) };
+  }
+
+  private assertSimulationEnabled(): void {
+    if (!this.archive.simulationEnabled()) {
+      throw new ForbiddenException('Drift simulation is disabled; set DRIFT_SIMULATION_ENABLED=true in dev/staging only');
+    }
+  }
+}
@@ -0,0 +1,112 @@
+import { Injectable, Logger, NotFoundException, OnModuleDestroy, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { KeyObject, createPrivateKey, createPublicKey, generateKeyPairSync, sign } from 'crypto';
+import { randomUUID } from 'crypto';
+
+import { SolvencyReport, SolvencyService } from './solvency.service';
+import { canonicalJson, contentHash } from '../audit/audit-hash.service';
+
+export interface Attestation {
+  id: string;
+  generated_at: string;
+  report: SolvencyReport;
+  /** sha256 over the canonical JSON of the report. */
+  report_hash: string;
+  /** ed25519 signature (base64) over the canonical report JSON. */
+  signature: string;
+  /** SPKI DER (base64) of the signing key, for independent verification. */
+  signing_public_key: string;
+}
+
+const DEFAULT_INTERVAL_MS = 3_600_000;
+const MAX_HISTORY = 168;
+
+/**
+ * Proof-of-reserves style attestations. Each run snapshots the solvency
+ * report (internal liabilities per token vs on-chain storage holdings) and
+ * signs its canonical JSON with the server's attestation key, so anyone can
+ * fetch a report, recompute the hash and verify the signature offline —
+ * no trust in the UI required. The key comes from ATTESTATION_PRIVATE_KEY
+ * (base64 PKCS8 DER); without one an ephemeral boot key is generated, which
+ * still proves integrity within a process lifetime but not across restarts.
+ */
+@Injectable()
+export class AttestationService implements OnModuleInit, OnModuleDestroy {
+  private readonly logger = new Logger(AttestationService.name);
+  private readonly history: Attestation[] = [];
+  private privateKey!: KeyObject;
+  private publicKeyBase64 = '';
+  private timer?: ReturnType<typeof setInterval>;
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly solvency: SolvencyService,
+  ) {}
+
+  onModuleInit(): void {
+    this.initKey();
+    const intervalMs = Number(this.config.get<string>('ATTESTATION_INTERVAL_MS')) || DEFAULT_INTERVAL_MS;
+    this.timer = setInterval(() => {
+      this.generate().catch((error) => this.logger.error('Attestation run failed', error));
+    }, intervalMs);
+  }
+
+  onModuleDestroy(): void {
+    if (this.timer) {
+      clearInterval(this.timer);
+    }
+  }
+
+  async generate(): Promise<Attestation> {
+    const report = await this.solvency.generateReport();
+    const payload = canonicalJson(report);
+    const attestation: Attestation = {
+      id: randomUUID(),
+      generated_at: report.generated_at,
+      report,
+      report_hash: contentHash(report),
+      signature: sign(null, Buffer.from(payload), this.privateKey).toString('base64'),
+      signing_public_key: this.publicKeyBase64,
+    };
+    this.history.unshift(attestation);
+    if (this.history.length > MAX_HISTORY) {
+      this.history.length = MAX_HISTORY;
+    }
+    return attestation;
+  }
+
+  latest(): Attestation {
+    if (this.history.length === 0) {
+      throw new NotFoundException('No attestation has been generated yet');
+    }
+    return this.history[0];
+  }
+
+  getHistory(): Attestation[] {
+    return this.history;
+  }
+
+  getAttestation(id: string): Attestation {
+    const attestation = this.history.find((entry) => entry.id === id);
+    if (!attestation) {
+      throw new NotFoundException(`Attestation ${id} not found`);
+    }
+    return attestation;
+  }
+
+  signingPublicKey(): string {
+    return this.publicKeyBase64;
+  }
+
+  private initKey(): void {
+    const configured = this.config.get<string>('ATTESTATION_PRIVATE_KEY');
+    if (configured) {
+      this.privateKey = createPrivateKey({ key: Buffer.from(configured, 'base64'), format: 'der', type: 'pkcs8' });
+    } else {
+      const pair = generateKeyPairSync('ed25519');
+      this.privateKey = pair.privateKey;
+      this.logger.warn('ATTESTATION_PRIVATE_KEY is not set; signing attestations with an ephemeral boot key');
+    }
+    this.publicKeyBase64 = createPublicKey(this.privateKey).export({ format: 'der', type: 'spki' }).toString('base64');
+  }
+}
@@ -0,0 +1,312 @@
+import { Injectable, Logger, OnModuleDestroy, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { randomUUID } from 'crypto';
+import { appendFileSync, existsSync, mkdirSync, readFileSync, writeFileSync } from 'fs';
+import { dirname } from 'path';
+
+import { LedgerService } from '../ledger/ledger.service';
+import { PoolsService } from '../pools/pools.service';
+import { DriftThresholdsService } from './drift-thresholds.service';
+import { DriftEscalationService } from './drift-escalation.service';
+import { OpsHooksService } from '../alerts/ops-hooks.service';
+
+export interface DriftEntry {
+  cycle_id: string;
+  generated_at: string;
+  pool_id: string;
+  token: string;
+  tracked: string;
+  onchain: string;
+  drift: string;
+  /** Set when a staging drift scenario skewed the on-chain figure. */
+  simulated?: boolean;
+}
+
+export interface SimulatedDrift {
+  pool_id: string;
+  token: string;
+  offset: number;
+  injected_at: string;
+}
+
+export interface ReconcileCycleReport {
+  cycle_id: string;
+  generated_at: string;
+  entries: DriftEntry[];
+}
+
+export interface DriftTrend {
+  pool_id: string;
+  token: string;
+  cycles: number;
+  cycles_with_drift: number;
+  mean_drift: string;
+  max_abs_drift: string;
+  latest_drift: string;
+}
+
+const DEFAULT_ARCHIVE_PATH = 'data/reconciliation-archive.log';
+const DEFAULT_INTERVAL_MS = 60_000;
+const DRIFT_EPSILON = 1e-6;
+
+/**
+ * Per-cycle reconciliation archive. Each cycle compares tracked pool reserves
+ * against on-chain holdings and appends the per-pool/per-token drift to a
+ * persistent log, replayed on startup. The trend API surfaces recurring small
+ * drifts — the signature of a systematic bug — that a single overwritten
+ * report would hide.
+ */
+@Injectable()
+export class DriftArchiveService implements OnModuleInit, OnModuleDestroy {
+  private readonly logger = new Logger(DriftArchiveService.name);
+  private readonly entries: DriftEntry[] = [];
+  private readonly simulatedOffsets = new Map<string, SimulatedDrift>();
+  private archivePath = DEFAULT_ARCHIVE_PATH;
+  private timer?: ReturnType<typeof setInterval>;
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly pools: PoolsService,
+    private readonly ledger: LedgerService,
+    private readonly thresholds: DriftThresholdsService,
+    private readonly escalation: DriftEscalationService,
+    private readonly opsHooks: OpsHooksService,
+  ) {}
+
+  onModuleInit(): void {
+    this.archivePath = this.config.get<string>('RECONCILIATION_ARCHIVE_PATH') || DEFAULT_ARCHIVE_PATH;
+    this.load();
+    if (this.config.get<string>('RECONCILIATION_ARCHIVE_ENABLED') === 'true') {
+      const intervalMs = Number(this.config.get<string>('RECONCILIATION_ARCHIVE_INTERVAL_MS')) || DEFAULT_INTERVAL_MS;
+      this.timer = setInterval(() => {
+        this.runCycle().catch((error) => this.logger.error('Reconciliation archive cycle failed', error));
+      }, intervalMs);
+      this.logger.log(`Reconciliation archive running every ${intervalMs}ms`);
+    }
+  }
+
+  onModuleDestroy(): void {
+    if (this.timer) {
+      clearInterval(this.timer);
+    }
+  }
+
+  history(poolId?: string): DriftEntry[] {
+    return this.entries.filter((entry) => !poolId || entry.pool_id === poolId);
+  }
+
+  /**
+   * Reconciliation runs grouped back into the cycles that produced them,
+   * newest first, bounded so the full archive never goes over the wire.
+   */
+  cycles(limit: number, poolId?: string): ReconcileCycleReport[] {
+    const grouped = new Map<string, ReconcileCycleReport>();
+    for (const entry of this.history(poolId)) {
+      const report = grouped.get(entry.cycle_id) ?? { cycle_id: entry.cycle_id, generated_at: entry.generated_at, entries: [] };
+      report.entries.push(entry);
+      grouped.set(entry.cycle_id, report);
+    }
+    return Array.from(grouped.values())
+      .sort((a, b) => b.generated_at.localeCompare(a.generated_at))
+      .slice(0, limit);
+  }
+
+  trends(poolId?: string): DriftTrend[] {
+    const grouped = new Map<string, DriftEntry[]>();
+    for (const entry of this.history(poolId)) {
+      const key = `${entry.pool_id}|${entry.token}`;
+      const bucket = grouped.get(key) ?? [];
+      bucket.push(entry);
+      grouped.set(key, bucket);
+    }
+
+    return Array.from(grouped.entries()).map(([key, bucket]) => {
+      const [pool, token] = key.split('|');
+      const drifts = bucket.map((entry) => Number(entry.drift));
+      const withDrift = drifts.filter((drift) => Math.abs(drift) > DRIFT_EPSILON).length;
+      const mean = drifts.reduce((sum, drift) => sum + drift, 0) / drifts.length;
+      const maxAbs = Math.max(...drifts.map((drift) => Math.abs(drift)));
+      return {
+        pool_id: pool,
+        token,
+        cycles: bucket.length,
+        cycles_with_drift: withDrift,
+        mean_drift: mean.toString(),
+        max_abs_drift: maxAbs.toString(),
+        latest_drift: bucket[bucket.length - 1].drift,
+      };
+    });
+  }
+
+  entryCount(): number {
+    return this.entries.length;
+  }
+
+  /**
+   * Staging-only drift injection so operators can rehearse the pause →
+   * investigate → emergency-withdraw/unpause runbook without touching the
+   * chain. The offset is added to the on-chain figure during each cycle and
+   * the resulting entries are flagged as simulated; nothing in the pool or
+   * ledger state is modified. Gated behind DRIFT_SIMULATION_ENABLED=true.
+   */
+  simulationEnabled(): boolean {
+    return this.config.get<string>('DRIFT_SIMULATION_ENABLED') === 'true';
+  }
+
+  setSimulatedDrift(poolId: string, token: string, offset: number): SimulatedDrift {
+    const pool = this.pools.getPool(poolId);
+    if (token !== pool.tokenA && token !== pool.tokenB) {
+      throw new Error(`Token ${token} is not part of pool ${poolId}`);
+    }
+    if (!Number.isFinite(offset) || offset === 0) {
+      throw new Error(`Drift offset must be a non-zero finite number: ${offset}`);
+    }
+    const scenario: SimulatedDrift = { pool_id: poolId, token, offset, injected_at: new Date().toISOString() };
+    this.simulatedOffsets.set(`${poolId}|${token}`, scenario);
+    this.logger.warn(`Simulating ${offset} drift on pool ${poolId} token ${token}`);
+    return scenario;
+  }
+
+  clearSimulatedDrift(poolId?: string): number {
+    let cleared = 0;
+    for (const key of Array.from(this.simulatedOffsets.keys())) {
+      if (!poolId || key.startsWith(`${poolId}|`)) {
+        this.simulatedOffsets.delete(key);
+        cleared += 1;
+      }
+    }
+    return cleared;
+  }
+
+  listSimulatedDrifts(): SimulatedDrift[] {
+    return Array.from(this.simulatedOffsets.values());
+  }
+
+  /**
+   * Remove and return entries older than the cutoff, rewriting the archive
+   * file so the on-disk log shrinks along with memory.
+   */
+  pruneBefore(cutoff: string): DriftEntry[] {
+    const pruned = this.entries.filter((entry) => entry.generated_at < cutoff);
+    if (pruned.length === 0) {
+      return pruned;
+    }
+    const kept = this.entries.filter((entry) => entry.generated_at >= cutoff);
+    this.entries.length = 0;
+    this.entries.push(...kept);
+    try {
+      writeFileSync(this.archivePath, kept.map((entry) => `${JSON.stringify(entry)}\n`).join(''));
+    } catch (error) {
+      this.logger.error('Failed to rewrite reconciliation archive after pruning', error);
+    }
+    return pruned;
+  }
+
+  async runCycle(): Promise<DriftEntry[]> {
+    const cycleId = randomUUID();
+    const generatedAt = new Date().toISOString();
+    const cycle: DriftEntry[] = [];
+
+    for (const pool of this.pools.allPools()) {
+      let holdings: Map<string, number>;
+      try {
+        const balance = await this.ledger.getBalance(pool.storageAccount);
+        holdings = new Map(
+          balance.allBalances
+            .map((entry) => [entry.token, Number(entry.balance)] as [string, number])
+            .filter(([, amount]) => Number.isFinite(amount)),
+        );
+      } catch (error) {
+        this.logger.warn(
+          `Skipping pool ${pool.id} this cycle: ${error instanceof Error ? error.message : 'ledger read failed'}`,
+        );
+        continue;
+      }
+
+      for (const [token, tracked] of [
+        [pool.tokenA, pool.reserveA],
+        [pool.tokenB, pool.reserveB],
+      ] as Array<[string, number]>) {
+        const scenario = this.simulationEnabled() ? this.simulatedOffsets.get(`${pool.id}|${token}`) : undefined;
+        const onchain = (holdings.get(token) ?? 0) + (scenario?.offset ?? 0);
+        cycle.push({
+          cycle_id: cycleId,
+          generated_at: generatedAt,
+          pool_id: pool.id,
+          token,
+          tracked: tracked.toString(),
+          onchain: onchain.toString(),
+          drift: (onchain - tracked).toString(),
+          ...(scenario ? { simulated: true } : {}),
+        });
+      }
+    }
+
+    for (const entry of cycle) {
+      this.entries.push(entry);
+      this.append(entry);
+    }
+    this.evaluateThresholds(cycle);
+    return cycle;
+  }
+
+  /**
+   * Alert on drift beyond the configured tolerance and hand breaches to the
+   * escalation policy. Thresholds resolve per pool, then per token, then
+   * globally.
+   */
+  private evaluateThresholds(cycle: DriftEntry[]): void {
+    for (const entry of cycle) {
+      const threshold = this.thresholds.resolve(entry.pool_id, entry.token);
+      if (!this.thresholds.exceeds(Number(entry.drift), Number(entry.tracked), threshold)) {
+        continue;
+      }
+      this.logger.warn(
+        `Drift ${entry.drift} on pool ${entry.pool_id} token ${entry.token} exceeds ${threshold.source} threshold`,
+      );
+      this.opsHooks.fire('drift_exceeded', {
+        pool_id: entry.pool_id,
+        token: entry.token,
+        drift: entry.drift,
+        tracked: entry.tracked,
+        onchain: entry.onchain,
+        threshold_abs: threshold.abs?.toString(),
+        threshold_pct: threshold.pct?.toString(),
+        threshold_source: threshold.source,
+        ...(entry.simulated ? { simulated: true } : {}),
+      });
+      this.escalation.recordBreach(entry);
+    }
+  }
+
+  private append(entry: DriftEntry): void {
+    try {
+      const dir = dirname(this.archivePath);
+      if (dir && dir !== '.' && !existsSync(dir)) {
+        mkdirSync(dir, { recursive: true });
+      }
+      appendFileSync(this.archivePath, `${JSON.stringify(entry)}\n`);
+    } catch (error) {
+      this.logger.error('Failed to append reconciliation archive entry', error);
+    }
+  }
+
+  private load(): void {
+    if (!existsSync(this.archivePath)) {
+      return;
+    }
+    try {
+      const lines = readFileSync(this.archivePath, 'utf8').split('\n').filter(Boolean);
+      for (const line of lines) {
+        try {
+          this.entries.push(JSON.parse(line) as DriftEntry);
+        } catch {
+          this.logger.warn('Skipping corrupt reconciliation archive line');
+        }
+      }
+      this.logger.log(`Loaded ${this.entries.length} archived reconciliation entries`);
+    } catch (error) {
+      this.logger.error(`Failed to read reconciliation archive at ${this.archivePath}`, error);
+    }
+  }
+}
@@ -0,0 +1,144 @@
+import { Injectable, Logger, NotFoundException } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+import { PoolsService } from '../pools/pools.service';
+import { OpsHooksService } from '../alerts/ops-hooks.service';
+import { DriftEntry } from './drift-archive.service';
+
+export type EscalationLevel = 'paused' | 'locked';
+
+export interface PoolEscalation {
+  pool_id: string;
+  level: EscalationLevel;
+  breaches_in_window: number;
+  first_breach_at: string;
+  last_breach_at: string;
+  review_required: boolean;
+}
+
+export interface DriftReview {
+  pool_id: string;
+  reviewer: string;
+  note?: string;
+  unpaused: boolean;
+  reviewed_at: string;
+}
+
+const DEFAULT_WINDOW_MS = 86_400_000;
+const DEFAULT_LOCK_AFTER = 2;
+const MAX_REVIEW_LOG = 100;
+
+/**
+ * Escalation policy for repeat drift offenders. A first breach pauses the
+ * pool — trading stops but the state is recoverable by any admin action. A
+ * repeat breach inside the window means something systematic is wrong:
+ * liquidity adds are disabled too, and the pool stays locked until a named
+ * admin records a review, so "unpause and hope" is no longer an option.
+ * Window and repeat count come from DRIFT_ESCALATION_WINDOW_MS and
+ * DRIFT_ESCALATION_LOCK_AFTER; DRIFT_AUTO_PAUSE=false disables the policy.
+ */
+@Injectable()
+export class DriftEscalationService {
+  private readonly logger = new Logger(DriftEscalationService.name);
+  /** Breach timestamps per pool, pruned to the configured window. */
+  private readonly breaches = new Map<string, number[]>();
+  private readonly escalations = new Map<string, PoolEscalation>();
+  private readonly reviews: DriftReview[] = [];
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly pools: PoolsService,
+    private readonly opsHooks: OpsHooksService,
+  ) {}
+
+  recordBreach(entry: DriftEntry): void {
+    if (this.config.get<string>('DRIFT_AUTO_PAUSE') === 'false') {
+      return;
+    }
+    const windowMs = Number(this.config.get<string>('DRIFT_ESCALATION_WINDOW_MS')) || DEFAULT_WINDOW_MS;
+    const lockAfter = Number(this.config.get<string>('DRIFT_ESCALATION_LOCK_AFTER')) || DEFAULT_LOCK_AFTER;
+    const now = Date.now();
+    const history = (this.breaches.get(entry.pool_id) ?? []).filter((at) => at > now - windowMs);
+    history.push(now);
+    this.breaches.set(entry.pool_id, history);
+
+    const pool = this.pools.getPool(entry.pool_id);
+    const level: EscalationLevel = history.length >= lockAfter ? 'locked' : 'paused';
+    const escalation: PoolEscalation = {
+      pool_id: entry.pool_id,
+      level,
+      breaches_in_window: history.length,
+      first_breach_at: new Date(history[0]).toISOString(),
+      last_breach_at: new Date(now).toISOString(),
+      review_required: level === 'locked',
+    };
+    this.escalations.set(entry.pool_id, escalation);
+
+    if (!pool.isPaused) {
+      this.pools.setPaused(pool, true);
+      this.logger.warn(`Auto-paused pool ${entry.pool_id} on drift breach`);
+      this.opsHooks.fire('pool_auto_paused', {
+        pool_id: entry.pool_id,
+        token: entry.token,
+        drift: entry.drift,
+        ...(entry.simulated ? { simulated: true } : {}),
+      });
+    }
+    if (level === 'locked' && !pool.unpauseRequiresReview) {
+      this.pools.setLiquidityAddsDisabled(pool, true);
+      this.pools.setUnpauseReviewRequired(pool, true);
+      this.logger.warn(
+        `Locked pool ${entry.pool_id} after ${history.length} drift breaches in the window; manual review required`,
+      );
+      this.opsHooks.fire('pool_escalated', {
+        pool_id: entry.pool_id,
+        token: entry.token,
+        drift: entry.drift,
+        breaches_in_window: history.length,
+        ...(entry.simulated ? { simulated: true } : {}),
+      });
+    }
+  }
+
+  listEscalations(): PoolEscalation[] {
+    return Array.from(this.escalations.values());
+  }
+
+  reviewLog(): DriftReview[] {
+    return this.reviews;
+  }
+
+  /**
+   * Close out an escalation: the reviewer goes on record, locks are lifted,
+   * the breach window resets, and the pool optionally unpauses in the same
+   * step.
+   */
+  review(poolId: string, reviewer: string, note?: string, unpause = false): { escalation: PoolEscalation; review: DriftReview } {
+    const pool = this.pools.getPool(poolId);
+    const escalation = this.escalations.get(poolId);
+    if (!escalation) {
+      throw new NotFoundException(`Pool ${poolId} has no drift escalation to review`);
+    }
+    this.pools.setUnpauseReviewRequired(pool, false);
+    this.pools.setLiquidityAddsDisabled(pool, false);
+    if (unpause) {
+      this.pools.setPaused(pool, false);
+    }
+    this.escalations.delete(poolId);
+    this.breaches.delete(poolId);
+
+    const review: DriftReview = {
+      pool_id: poolId,
+      reviewer,
+      note,
+      unpaused: unpause,
+      reviewed_at: new Date().toISOString(),
+    };
+    this.reviews.unshift(review);
+    if (this.reviews.length > MAX_REVIEW_LOG) {
+      this.reviews.length = MAX_REVIEW_LOG;
+    }
+    this.logger.log(`Drift escalation on pool ${poolId} reviewed by ${reviewer}${unpause ? ' and unpaused' : ''}`);
+    return { escalation, review };
+  }
+}
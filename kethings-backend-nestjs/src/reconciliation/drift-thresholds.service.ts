@@ -0,0 +1,94 @@
+import { Injectable } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+export interface DriftThreshold {
+  /** Absolute drift ceiling, in token units. */
+  abs?: number;
+  /** Drift ceiling as a percentage of the tracked reserve. */
+  pct?: number;
+}
+
+export interface ResolvedDriftThreshold extends DriftThreshold {
+  source: 'pool' | 'token' | 'default';
+}
+
+const DEFAULT_ABS_THRESHOLD = 0.01;
+const DEFAULT_PCT_THRESHOLD = 1;
+
+/**
+ * Drift tolerance configuration. A stablecoin pool and a long-tail pool
+ * should not share one hard-coded tolerance: overrides can be set per pool
+ * (most specific, wins) or per token, each as an absolute amount and/or a
+ * percentage of the tracked reserve, falling back to the global defaults.
+ */
+@Injectable()
+export class DriftThresholdsService {
+  private readonly poolOverrides = new Map<string, DriftThreshold>();
+  private readonly tokenOverrides = new Map<string, DriftThreshold>();
+
+  constructor(private readonly config: ConfigService) {}
+
+  resolve(poolId: string, token: string): ResolvedDriftThreshold {
+    const poolOverride = this.poolOverrides.get(poolId);
+    if (poolOverride) {
+      return { ...poolOverride, source: 'pool' };
+    }
+    const tokenOverride = this.tokenOverrides.get(token);
+    if (tokenOverride) {
+      return { ...tokenOverride, source: 'token' };
+    }
+    return {
+      abs: Number(this.config.get<string>('DRIFT_THRESHOLD_ABS')) || DEFAULT_ABS_THRESHOLD,
+      pct: Number(this.config.get<string>('DRIFT_THRESHOLD_PCT')) || DEFAULT_PCT_THRESHOLD,
+      source: 'default',
+    };
+  }
+
+  /** Whether the drift breaches either the absolute or percentage ceiling. */
+  exceeds(drift: number, tracked: number, threshold: DriftThreshold): boolean {
+    const magnitude = Math.abs(drift);
+    if (threshold.abs !== undefined && magnitude > threshold.abs) {
+      return true;
+    }
+    if (threshold.pct !== undefined && tracked > 0 && (magnitude / tracked) * 100 > threshold.pct) {
+      return true;
+    }
+    return false;
+  }
+
+  setPoolThreshold(poolId: string, threshold: DriftThreshold): void {
+    this.assertValid(threshold);
+    this.poolOverrides.set(poolId, threshold);
+  }
+
+  setTokenThreshold(token: string, threshold: DriftThreshold): void {
+    this.assertValid(threshold);
+    this.tokenOverrides.set(token, threshold);
+  }
+
+  clearPoolThreshold(poolId: string): boolean {
+    return this.poolOverrides.delete(poolId);
+  }
+
+  clearTokenThreshold(token: string): boolean {
+    return this.tokenOverrides.delete(token);
+  }
+
+  listOverrides(): { pools: Record<string, DriftThreshold>; tokens: Record<string, DriftThreshold> } {
+    return {
+      pools: Object.fromEntries(this.poolOverrides),
+      tokens: Object.fromEntries(this.tokenOverrides),
+    };
+  }
+
+  private assertValid(threshold: DriftThreshold): void {
+    if (threshold.abs === undefined && threshold.pct === undefined) {
+      throw new Error('A drift threshold needs at least one of abs or pct');
+    }
+    for (const value of [threshold.abs, threshold.pct]) {
+      if (value !== undefined && (!Number.isFinite(value) || value <= 0)) {
+        throw new Error(`Drift threshold values must be positive finite numbers: ${value}`);
+      }
+    }
+  }
+}
@@ -0,0 +1,14 @@
+import { IsBoolean, IsOptional, IsString } from 'class-validator';
+
+export class ReviewEscalationDto {
+  @IsString()
+  reviewer!: string;
+
+  @IsOptional()
+  @IsString()
+  note?: string;
+
+  @IsOptional()
+  @IsBoolean()
+  unpause?: boolean;
+}
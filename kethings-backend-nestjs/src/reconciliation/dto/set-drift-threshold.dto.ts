@@ -0,0 +1,25 @@
+import { Type } from 'class-transformer';
+import { IsNumber, IsOptional, IsPositive, IsString, ValidateIf } from 'class-validator';
+
+export class SetDriftThresholdDto {
+  /** Exactly one of pool_id or token selects the override scope. */
+  @ValidateIf((dto: SetDriftThresholdDto) => dto.token === undefined)
+  @IsString()
+  pool_id?: string;
+
+  @ValidateIf((dto: SetDriftThresholdDto) => dto.pool_id === undefined)
+  @IsString()
+  token?: string;
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  abs?: number;
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  pct?: number;
+}
@@ -0,0 +1,17 @@
+import { Type } from 'class-transformer';
+import { IsNotEmpty, IsNumber, IsString } from 'class-validator';
+
+export class SimulateDriftDto {
+  @IsString()
+  @IsNotEmpty()
+  pool_id!: string;
+
+  @IsString()
+  @IsNotEmpty()
+  token!: string;
+
+  /** Amount added to the observed on-chain balance each cycle; may be negative. */
+  @Type(() => Number)
+  @IsNumber()
+  offset!: number;
+}
@@ -0,0 +1,105 @@
+import { Injectable, Logger, OnModuleDestroy, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+import { PoolHealth, PoolHealthLevel, PoolsService } from '../pools/pools.service';
+import { DriftArchiveService } from './drift-archive.service';
+import { SettlementQueueService } from '../settlement/settlement-queue.service';
+
+const DEFAULT_INTERVAL_MS = 30_000;
+const DEFAULT_DRIFT_THRESHOLD = 0.01;
+const DEFAULT_STALENESS_MS = 5 * 60_000;
+const DEFAULT_MIN_RESERVE = 1;
+const DEFAULT_MAX_PENDING_OPS = 5;
+
+/**
+ * Background pool health evaluator. Folds drift history, reconciliation
+ * staleness, queued settlements, pause state and liquidity depth into a
+ * green/yellow/red verdict and writes it back onto PoolInfo, so UIs can warn
+ * users before they trade into an unhealthy pool. Red means trading is
+ * unsafe or impossible; yellow means degraded but functional.
+ */
+@Injectable()
+export class PoolHealthService implements OnModuleInit, OnModuleDestroy {
+  private readonly logger = new Logger(PoolHealthService.name);
+  private timer?: ReturnType<typeof setInterval>;
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly pools: PoolsService,
+    private readonly driftArchive: DriftArchiveService,
+    private readonly settlementQueue: SettlementQueueService,
+  ) {}
+
+  onModuleInit(): void {
+    const intervalMs = Number(this.config.get<string>('POOL_HEALTH_INTERVAL_MS')) || DEFAULT_INTERVAL_MS;
+    this.timer = setInterval(() => this.evaluateAll(), intervalMs);
+  }
+
+  onModuleDestroy(): void {
+    if (this.timer) {
+      clearInterval(this.timer);
+    }
+  }
+
+  evaluateAll(): void {
+    for (const pool of this.pools.allPools()) {
+      const health = this.evaluate(pool.id);
+      this.pools.setHealth(pool.id, health);
+      if (health.level !== 'green') {
+        this.logger.warn(`Pool ${pool.id} health ${health.level}: ${health.reasons.join('; ')}`);
+      }
+    }
+  }
+
+  evaluate(poolId: string): PoolHealth {
+    const pool = this.pools.getPool(poolId);
+    const red: string[] = [];
+    const yellow: string[] = [];
+
+    if (pool.isPaused) {
+      red.push('pool is paused');
+    }
+    if (pool.reserveA <= 0 || pool.reserveB <= 0) {
+      red.push('pool has an empty reserve');
+    } else {
+      const minReserve = Number(this.config.get<string>('POOL_HEALTH_MIN_RESERVE')) || DEFAULT_MIN_RESERVE;
+      if (Math.min(pool.reserveA, pool.reserveB) < minReserve) {
+        yellow.push(`liquidity depth below ${minReserve}`);
+      }
+    }
+    if (pool.pendingSettlement) {
+      yellow.push('pool has a pending settlement');
+    }
+
+    const maxPending = Number(this.config.get<string>('POOL_HEALTH_MAX_PENDING_OPS')) || DEFAULT_MAX_PENDING_OPS;
+    const queued = this.settlementQueue
+      .listOps()
+      .filter((op) => (op.status === 'pending' || op.status === 'in_flight') && op.payload.pool_id === poolId).length;
+    if (queued > maxPending) {
+      yellow.push(`${queued} settlement ops queued against the pool`);
+    }
+
+    const driftThreshold = Number(this.config.get<string>('POOL_HEALTH_DRIFT_THRESHOLD')) || DEFAULT_DRIFT_THRESHOLD;
+    const trends = this.driftArchive.trends(poolId);
+    for (const trend of trends) {
+      const latest = Math.abs(Number(trend.latest_drift));
+      if (latest > driftThreshold) {
+        red.push(`reserve drift ${trend.latest_drift} on ${trend.token} exceeds threshold`);
+      } else if (trend.cycles_with_drift > 0) {
+        yellow.push(`recurring drift on ${trend.token} (${trend.cycles_with_drift}/${trend.cycles} cycles)`);
+      }
+    }
+
+    const stalenessMs = Number(this.config.get<string>('POOL_HEALTH_STALENESS_MS')) || DEFAULT_STALENESS_MS;
+    const entries = this.driftArchive.history(poolId);
+    if (entries.length > 0) {
+      const latestAt = Date.parse(entries[entries.length - 1].generated_at);
+      if (Number.isFinite(latestAt) && Date.now() - latestAt > stalenessMs) {
+        yellow.push('reconciliation data is stale');
+      }
+    }
+
+    const level: PoolHealthLevel = red.length > 0 ? 'red' : yellow.length > 0 ? 'yellow' : 'green';
+    return { level, reasons: [...red, ...yellow], evaluated_at: new Date().toISOString() };
+  }
+}
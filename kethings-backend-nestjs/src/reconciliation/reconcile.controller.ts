@@ -0,0 +1,47 @@
+import { Controller, Get, Param, Query } from '@nestjs/common';
+
+import { DriftArchiveService } from './drift-archive.service';
+import { PoolsService } from '../pools/pools.service';
+
+const DEFAULT_HISTORY_LIMIT = 20;
+const MAX_HISTORY_LIMIT = 100;
+
+/**
+ * Read API over the reconciliation archive. Until now cycle results only
+ * landed in logs and the drift trend rollup; these endpoints return the
+ * latest full report and a bounded history of past runs so dashboards and
+ * operators can inspect raw drift values without grepping.
+ */
+@Controller('reconcile')
+export class ReconcileController {
+  constructor(
+    private readonly driftArchive: DriftArchiveService,
+    private readonly pools: PoolsService,
+  ) {}
+
+  @Get('reports')
+  reports(@Query('limit') limit?: string) {
+    const reports = this.driftArchive.cycles(this.boundedLimit(limit));
+    return { latest: reports[0] ?? null, reports };
+  }
+
+  @Get('pools/:poolId')
+  poolReports(@Param('poolId') poolId: string, @Query('limit') limit?: string) {
+    this.pools.getPool(poolId);
+    const reports = this.driftArchive.cycles(this.boundedLimit(limit), poolId);
+    return {
+      pool_id: poolId,
+      latest: reports[0] ?? null,
+      reports,
+      trends: this.driftArchive.trends(poolId),
+    };
+  }
+
+  private boundedLimit(limit?: string): number {
+    const parsed = Number(limit);
+    if (!Number.isFinite(parsed) || parsed <= 0) {
+      return DEFAULT_HISTORY_LIMIT;
+    }
+    return Math.min(Math.floor(parsed), MAX_HISTORY_LIMIT);
+  }
+}
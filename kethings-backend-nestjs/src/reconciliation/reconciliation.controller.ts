@@ -0,0 +1,42 @@
+import { Controller, Get, Param } from '@nestjs/common';
+
+import { SolvencyService } from './solvency.service';
+import { AttestationService } from './attestation.service';
+
+@Controller('reconciliation')
+export class ReconciliationController {
+  constructor(
+    private readonly solvency: SolvencyService,
+    private readonly attestations: AttestationService,
+  ) {}
+
+  @Get('solvency')
+  async solvencyReport() {
+    return this.solvency.generateReport();
+  }
+
+  @Get('solvency/history')
+  solvencyHistory() {
+    return { reports: this.solvency.getHistory() };
+  }
+
+  // Attestations are deliberately public: the whole point is that users can
+  // verify the signed backing report without trusting this server's UI.
+  @Get('attestations')
+  attestationHistory() {
+    return {
+      signing_public_key: this.attestations.signingPublicKey(),
+      attestations: this.attestations.getHistory(),
+    };
+  }
+
+  @Get('attestations/latest')
+  latestAttestation() {
+    return this.attestations.latest();
+  }
+
+  @Get('attestations/:id')
+  attestation(@Param('id') id: string) {
+    return this.attestations.getAttestation(id);
+  }
+}
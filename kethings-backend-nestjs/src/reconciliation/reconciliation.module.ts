@@ -0,0 +1,27 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+import { SolvencyService } from './solvency.service';
+import { AttestationService } from './attestation.service';
+import { DriftArchiveService } from './drift-archive.service';
+import { DriftThresholdsService } from './drift-thresholds.service';
+import { DriftEscalationService } from './drift-escalation.service';
+import { PoolHealthService } from './pool-health.service';
+import { WithdrawalIntegrityService } from './withdrawal-integrity.service';
+import { AdminGuard } from '../common/admin.guard';
+import { ReconciliationController } from './reconciliation.controller';
+import { ReconcileController } from './reconcile.controller';
+import { AdminReconciliationController } from './admin-reconciliation.controller';
+import { BalancesModule } from '../balances/balances.module';
+import { PoolsModule } from '../pools/pools.module';
+import { LedgerModule } from '../ledger/ledger.module';
+import { SettlementModule } from '../settlement/settlement.module';
+import { AuditModule } from '../audit/audit.module';
+import { AlertsModule } from '../alerts/alerts.module';
+
+@Module({
+  imports: [ConfigModule, BalancesModule, PoolsModule, LedgerModule, SettlementModule, AuditModule, AlertsModule],
+  providers: [SolvencyService, AttestationService, DriftArchiveService, DriftThresholdsService, DriftEscalationService, PoolHealthService, WithdrawalIntegrityService, AdminGuard],
+  controllers: [ReconciliationController, ReconcileController, AdminReconciliationController],
+  exports: [SolvencyService, DriftArchiveService, DriftThresholdsService, PoolHealthService, WithdrawalIntegrityService],
+})
+export class ReconciliationModule {}
@@ -0,0 +1,122 @@
+import { Injectable, Logger } from '@nestjs/common';
+
+import { BalancesService } from '../balances/balances.service';
+import { PoolsService } from '../pools/pools.service';
+import { LedgerService } from '../ledger/ledger.service';
+
+export interface SolvencyLine {
+  token: string;
+  internal_liabilities: string;
+  onchain_holdings: string;
+  surplus: string;
+}
+
+export interface SolvencyReport {
+  generated_at: string;
+  lines: SolvencyLine[];
+  errors: string[];
+}
+
+const MAX_HISTORY = 100;
+
+/**
+ * Protocol-level solvency reporting: per token, every internal claim (user
+ * balances plus LP claims on pool reserves) compared against on-chain
+ * holdings across all pool storage accounts. The macro counterpart of
+ * per-account reconciliation.
+ */
+@Injectable()
+export class SolvencyService {
+  private readonly logger = new Logger(SolvencyService.name);
+  private readonly history: SolvencyReport[] = [];
+
+  constructor(
+    private readonly balances: BalancesService,
+    private readonly pools: PoolsService,
+    private readonly ledger: LedgerService,
+  ) {}
+
+  getHistory(): SolvencyReport[] {
+    return this.history;
+  }
+
+  reportCount(): number {
+    return this.history.length;
+  }
+
+  /** Remove and return reports older than the cutoff, for retention pruning. */
+  pruneBefore(cutoff: string): SolvencyReport[] {
+    const pruned = this.history.filter((report) => report.generated_at < cutoff);
+    if (pruned.length > 0) {
+      const kept = this.history.filter((report) => report.generated_at >= cutoff);
+      this.history.length = 0;
+      this.history.push(...kept);
+    }
+    return pruned;
+  }
+
+  async generateReport(): Promise<SolvencyReport> {
+    const liabilities = new Map<string, number>();
+    const errors: string[] = [];
+
+    // Direct internal balances. LP tokens are claims on reserves, not
+    // liabilities in their own right, so they are expanded below instead.
+    for (const entry of this.balances.snapshot()) {
+      if (entry.token.startsWith('LP-')) continue;
+      const total = entry.available + entry.reserved + entry.vault;
+      liabilities.set(entry.token, (liabilities.get(entry.token) ?? 0) + total);
+    }
+
+    // LP claims: the full pool reserves are owed to LP holders pro-rata, so
+    // the liability is simply the reserves themselves.
+    for (const pool of this.pools.allPools()) {
+      liabilities.set(pool.tokenA, (liabilities.get(pool.tokenA) ?? 0) + pool.reserveA);
+      liabilities.set(pool.tokenB, (liabilities.get(pool.tokenB) ?? 0) + pool.reserveB);
+    }
+
+    // On-chain holdings across all storage accounts.
+    const holdings = new Map<string, number>();
+    const storageAccounts = new Set(this.pools.allPools().map((pool) => pool.storageAccount));
+    for (const storageAccount of storageAccounts) {
+      try {
+        const balance = await this.ledger.getBalance(storageAccount);
+        for (const entry of balance.allBalances) {
+          const amount = Number(entry.balance);
+          if (Number.isFinite(amount)) {
+            holdings.set(entry.token, (holdings.get(entry.token) ?? 0) + amount);
+          }
+        }
+      } catch (error) {
+        errors.push(`Failed to read on-chain balances for ${storageAccount}: ${error instanceof Error ? error.message : 'unknown error'}`);
+      }
+    }
+
+    const tokens = new Set([...liabilities.keys(), ...holdings.keys()]);
+    const lines: SolvencyLine[] = Array.from(tokens).map((token) => {
+      const owed = liabilities.get(token) ?? 0;
+      const held = holdings.get(token) ?? 0;
+      return {
+        token,
+        internal_liabilities: owed.toString(),
+        onchain_holdings: held.toString(),
+        surplus: (held - owed).toString(),
+      };
+    });
+
+    const report: SolvencyReport = {
+      generated_at: new Date().toISOString(),
+      lines,
+      errors,
+    };
+    this.history.unshift(report);
+    if (this.history.length > MAX_HISTORY) {
+      this.history.length = MAX_HISTORY;
+    }
+
+    const deficits = lines.filter((line) => Number(line.surplus) < 0);
+    if (deficits.length > 0) {
+      this.logger.warn(`Solvency report found deficits in ${deficits.map((line) => line.token).join(', ')}`);
+    }
+    return report;
+  }
+}
@@ -0,0 +1,166 @@
+import { Injectable, Logger, OnModuleDestroy, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+import { BalancesService } from '../balances/balances.service';
+import { SettlementQueueService } from '../settlement/settlement-queue.service';
+import { AuditHashService } from '../audit/audit-hash.service';
+
+export type WithdrawalIntegrityFindingKind =
+  | 'duplicate_transition'
+  | 'conflicting_terminal_state'
+  | 'missing_enqueue'
+  | 'negative_balance';
+
+export interface WithdrawalIntegrityFinding {
+  kind: WithdrawalIntegrityFindingKind;
+  op_id?: string;
+  user_address?: string;
+  token?: string;
+  detail: string;
+}
+
+export interface WithdrawalIntegrityReport {
+  generated_at: string;
+  ops_checked: number;
+  findings: WithdrawalIntegrityFinding[];
+  clean: boolean;
+}
+
+const DEFAULT_CHECK_INTERVAL_MS = 300_000;
+const MAX_HISTORY = 100;
+
+/**
+ * Integrity job for withdrawal balance adjustments. Completions and
+ * reversions each apply a manual ledger posting, so a replayed or repeated
+ * transition silently double-applies funds. This job recomputes what the
+ * settlement journal says should have happened — each withdraw op enqueued
+ * once, terminal state reached once — and cross-checks the live ledger for
+ * impossible states (negative balances). Findings are appended to the
+ * `withdrawal-integrity` audit stream and logged as warnings.
+ */
+@Injectable()
+export class WithdrawalIntegrityService implements OnModuleInit, OnModuleDestroy {
+  private readonly logger = new Logger(WithdrawalIntegrityService.name);
+  private readonly history: WithdrawalIntegrityReport[] = [];
+  private checkTimer?: ReturnType<typeof setInterval>;
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly balances: BalancesService,
+    private readonly settlementQueue: SettlementQueueService,
+    private readonly audit: AuditHashService,
+  ) {}
+
+  onModuleInit(): void {
+    const intervalMs = Number(this.config.get<string>('WITHDRAWAL_INTEGRITY_INTERVAL_MS')) || DEFAULT_CHECK_INTERVAL_MS;
+    this.checkTimer = setInterval(() => this.runCheck(), intervalMs);
+  }
+
+  onModuleDestroy(): void {
+    if (this.checkTimer) {
+      clearInterval(this.checkTimer);
+    }
+  }
+
+  getHistory(): WithdrawalIntegrityReport[] {
+    return this.history;
+  }
+
+  runCheck(): WithdrawalIntegrityReport {
+    const findings: WithdrawalIntegrityFinding[] = [];
+    const entries = this.settlementQueue.readJournal();
+
+    // Replay the journal as an event log, tracking how many times each
+    // withdraw op hit each state. The collapsed ops map cannot show a
+    // transition applied twice — only the raw log can.
+    const withdrawOps = new Map<string, { user?: string; token?: string; complete: number; failed: number }>();
+    for (const entry of entries) {
+      if (entry.type === 'enqueue') {
+        if (entry.op.kind !== 'withdraw') continue;
+        if (withdrawOps.has(entry.op.id)) {
+          findings.push({
+            kind: 'duplicate_transition',
+            op_id: entry.op.id,
+            detail: `Withdraw op ${entry.op.id} enqueued more than once`,
+          });
+          continue;
+        }
+        withdrawOps.set(entry.op.id, {
+          user: typeof entry.op.payload.user_address === 'string' ? entry.op.payload.user_address : undefined,
+          token: typeof entry.op.payload.token === 'string' ? entry.op.payload.token : undefined,
+          complete: 0,
+          failed: 0,
+        });
+        continue;
+      }
+      if (entry.type !== 'complete' && entry.type !== 'failed') continue;
+      const tracked = withdrawOps.get(entry.id);
+      if (!tracked) {
+        // Non-withdraw ops land here too; only flag ids the journal never
+        // enqueued at all, since those transitions adjusted nothing we know of.
+        if (!entries.some((other) => other.type === 'enqueue' && other.op.id === entry.id)) {
+          findings.push({
+            kind: 'missing_enqueue',
+            op_id: entry.id,
+            detail: `Journal has a ${entry.type} transition for op ${entry.id} with no enqueue record`,
+          });
+        }
+        continue;
+      }
+      if (entry.type === 'complete') tracked.complete += 1;
+      else tracked.failed += 1;
+    }
+
+    for (const [opId, tracked] of withdrawOps) {
+      if (tracked.complete > 1 || tracked.failed > 1) {
+        findings.push({
+          kind: 'duplicate_transition',
+          op_id: opId,
+          user_address: tracked.user,
+          token: tracked.token,
+          detail: `Withdraw op ${opId} has ${tracked.complete} complete and ${tracked.failed} failed transitions; balance adjustment may have double-applied`,
+        });
+      } else if (tracked.complete > 0 && tracked.failed > 0) {
+        findings.push({
+          kind: 'conflicting_terminal_state',
+          op_id: opId,
+          user_address: tracked.user,
+          token: tracked.token,
+          detail: `Withdraw op ${opId} was both completed and reverted; funds were debited and refunded for the same op`,
+        });
+      }
+    }
+
+    // A double-applied debit or reversion shows up as an impossible ledger
+    // state: no sequence of valid postings can take a balance negative.
+    for (const entry of this.balances.snapshot()) {
+      if (entry.available < 0 || entry.reserved < 0 || entry.vault < 0) {
+        findings.push({
+          kind: 'negative_balance',
+          user_address: entry.user,
+          token: entry.token,
+          detail: `Ledger balance for ${entry.user}/${entry.token} is negative: available ${entry.available}, reserved ${entry.reserved}, vault ${entry.vault}`,
+        });
+      }
+    }
+
+    const report: WithdrawalIntegrityReport = {
+      generated_at: new Date().toISOString(),
+      ops_checked: withdrawOps.size,
+      findings,
+      clean: findings.length === 0,
+    };
+    this.history.unshift(report);
+    if (this.history.length > MAX_HISTORY) {
+      this.history.length = MAX_HISTORY;
+    }
+
+    if (findings.length > 0) {
+      this.logger.warn(`Withdrawal integrity check found ${findings.length} issue(s) across ${withdrawOps.size} ops`);
+      for (const finding of findings) {
+        this.audit.append('withdrawal-integrity', finding);
+      }
+    }
+    return report;
+  }
+}
@@ -0,0 +1,15 @@
+import { IsObject, IsString } from 'class-validator';
+
+export class RelayRequestDto {
+  /** The typed intent exactly as signed; field order follows typed-intents.ts. */
+  @IsObject()
+  intent!: Record<string, string>;
+
+  /** Base64 ed25519 signature over the intent digest. */
+  @IsString()
+  signature!: string;
+
+  /** Base64 raw 32-byte ed25519 public key. */
+  @IsString()
+  public_key!: string;
+}
@@ -0,0 +1,30 @@
+import { Body, Controller, Get, Param, Post, Query } from '@nestjs/common';
+
+import { RelayService } from './relay.service';
+import { RelayRequestDto } from './dto/relay-request.dto';
+import { AclSetupIntent, WithdrawalIntent } from '../intents/typed-intents';
+
+@Controller('relay')
+export class RelayController {
+  constructor(private readonly relay: RelayService) {}
+
+  @Get('status')
+  status(@Query('user_address') userAddress?: string) {
+    return this.relay.status(userAddress);
+  }
+
+  @Get('ops/:userAddress')
+  ops(@Param('userAddress') userAddress: string) {
+    return { user_address: userAddress, ops: this.relay.listOps(userAddress) };
+  }
+
+  @Post('withdrawal')
+  withdrawal(@Body() body: RelayRequestDto) {
+    return this.relay.relayWithdrawal(body.intent as unknown as WithdrawalIntent, body.signature, body.public_key);
+  }
+
+  @Post('acl-setup')
+  aclSetup(@Body() body: RelayRequestDto) {
+    return this.relay.relayAclSetup(body.intent as unknown as AclSetupIntent, body.signature, body.public_key);
+  }
+}
@@ -0,0 +1,16 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+
+import { RelayService } from './relay.service';
+import { RelayController } from './relay.controller';
+import { AuthModule } from '../auth/auth.module';
+import { KeetaModule } from '../keeta/keeta.module';
+import { WithdrawalsModule } from '../withdrawals/withdrawals.module';
+
+@Module({
+  imports: [ConfigModule, AuthModule, KeetaModule, WithdrawalsModule],
+  providers: [RelayService],
+  controllers: [RelayController],
+  exports: [RelayService],
+})
+export class RelayModule {}
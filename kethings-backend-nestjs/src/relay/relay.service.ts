@@ -0,0 +1,273 @@
+import {
+  BadRequestException,
+  ConflictException,
+  HttpException,
+  HttpStatus,
+  Injectable,
+  Logger,
+  ServiceUnavailableException,
+  UnauthorizedException,
+} from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { randomUUID } from 'crypto';
+
+import { AuthService } from '../auth/auth.service';
+import { AclSetupIntent, WithdrawalIntent, intentDigest } from '../intents/typed-intents';
+import { KeetaSdkService } from '../keeta/keeta-sdk.service';
+import { WithdrawalsService } from '../withdrawals/withdrawals.service';
+
+export type RelayedOpKind = 'withdrawal' | 'acl_setup';
+export type RelayedOpStatus = 'queued' | 'submitted' | 'failed';
+
+export interface RelayedOp {
+  id: string;
+  user_address: string;
+  kind: RelayedOpKind;
+  status: RelayedOpStatus;
+  intent_digest: string;
+  /** Settlement op id for relayed withdrawals. */
+  settlement_op_id?: string;
+  failure_reason?: string;
+  created_at: string;
+}
+
+export interface RelayStatus {
+  enabled: boolean;
+  daily_quota: number;
+  max_withdrawal_amount: number;
+  used_today?: number;
+}
+
+const DEFAULT_DAILY_QUOTA = 5;
+const DEFAULT_MAX_WITHDRAWAL = 10;
+const DEFAULT_MIN_INTERVAL_MS = 10_000;
+const MAX_OPS_PER_USER = 100;
+const MAX_CONSUMED_DIGESTS = 10_000;
+
+/**
+ * Gasless relay for small operations. Users without the base token for
+ * network fees submit a signed typed intent; the relayer account wraps it
+ * in a fee-paying transaction. Only low-value withdrawals and deposit ACL
+ * setup are eligible — everything else still pays its own way. Abuse is
+ * bounded three ways: a per-user daily quota, a minimum interval between
+ * a user's relayed ops, and a per-withdrawal value cap, all configurable.
+ * Intent digests are consumed on acceptance so a captured request cannot
+ * be replayed.
+ */
+@Injectable()
+export class RelayService {
+  private readonly logger = new Logger(RelayService.name);
+  private readonly opsByUser = new Map<string, RelayedOp[]>();
+  private readonly usage = new Map<string, { day: string; count: number; lastAt: number }>();
+  /** Accepted digests, insertion-ordered so the oldest prune first. */
+  private readonly consumedDigests = new Set<string>();
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly auth: AuthService,
+    private readonly withdrawals: WithdrawalsService,
+    private readonly keeta: KeetaSdkService,
+  ) {}
+
+  status(user?: string): RelayStatus {
+    return {
+      enabled: this.enabled(),
+      daily_quota: this.dailyQuota(),
+      max_withdrawal_amount: this.maxWithdrawal(),
+      ...(user ? { used_today: this.usedToday(user) } : {}),
+    };
+  }
+
+  listOps(user: string): RelayedOp[] {
+    return this.opsByUser.get(user) ?? [];
+  }
+
+  async relayWithdrawal(intent: WithdrawalIntent, signatureBase64: string, publicKeyBase64: string): Promise<RelayedOp> {
+    if (intent?.type !== 'withdrawal') {
+      throw new BadRequestException('intent.type must be "withdrawal"');
+    }
+    const digest = await this.admit(intent, signatureBase64, publicKeyBase64);
+
+    const amount = Number(intent.amount);
+    if (!(amount > 0)) {
+      throw new BadRequestException('intent amount must be positive');
+    }
+    if (amount > this.maxWithdrawal()) {
+      throw new BadRequestException({
+        code: 'RELAY_VALUE_TOO_HIGH',
+        message: `Relayed withdrawals are capped at ${this.maxWithdrawal()}; submit a regular withdrawal instead`,
+      });
+    }
+
+    this.consume(intent.user_address, digest);
+    const op = this.track(intent.user_address, 'withdrawal', digest);
+    try {
+      const result = await this.withdrawals.requestWithdrawal(
+        intent.user_address,
+        intent.token,
+        amount,
+        intent.destination,
+        undefined,
+        this.relayerAccount(),
+      );
+      op.settlement_op_id = result.op_id;
+      op.status = 'submitted';
+    } catch (error) {
+      op.status = 'failed';
+      op.failure_reason = error instanceof Error ? error.message : 'withdrawal intake failed';
+      throw error;
+    }
+    this.logger.log(`Relayed withdrawal ${op.settlement_op_id} for ${intent.user_address} (${amount} ${intent.token})`);
+    return op;
+  }
+
+  async relayAclSetup(intent: AclSetupIntent, signatureBase64: string, publicKeyBase64: string): Promise<RelayedOp> {
+    if (intent?.type !== 'acl_setup') {
+      throw new BadRequestException('intent.type must be "acl_setup"');
+    }
+    const seed = this.config.get<string>('RELAY_SIGNER_SEED');
+    if (!seed) {
+      throw new ServiceUnavailableException({
+        code: 'RELAY_SIGNER_UNCONFIGURED',
+        message: 'The relay has no signing account configured for on-chain operations',
+      });
+    }
+    const digest = await this.admit(intent, signatureBase64, publicKeyBase64);
+
+    this.consume(intent.user_address, digest);
+    const op = this.track(intent.user_address, 'acl_setup', digest);
+    try {
+      const signer = await this.keeta.accountFromSeed(seed);
+      await this.keeta.buildAndSubmit(signer, (builder) => {
+        const update = builder?.updatePermissions ?? builder?.modifyPermissions;
+        if (typeof update !== 'function') {
+          throw new Error('Keeta builder does not expose a permissions update');
+        }
+        update.call(builder, {
+          account: intent.user_address,
+          storage_account: intent.storage_account,
+          permissions: intent.permissions.split(','),
+        });
+      });
+      op.status = 'submitted';
+      this.logger.log(`Relayed ACL setup for ${intent.user_address} on ${intent.storage_account.substring(0, 20)}...`);
+    } catch (error) {
+      // The quota stays spent: a failed submission still consumed relayer
+      // attention, and refunding it would let a bad payload retry for free.
+      op.status = 'failed';
+      op.failure_reason = error instanceof Error ? error.message : 'submission failed';
+      this.logger.warn(`Relayed ACL setup failed for ${intent.user_address}: ${op.failure_reason}`);
+    }
+    return op;
+  }
+
+  /** Shared admission checks: enabled, signature, key binding, replay, quota, pacing. */
+  private async admit(intent: WithdrawalIntent | AclSetupIntent, signatureBase64: string, publicKeyBase64: string): Promise<string> {
+    if (!this.enabled()) {
+      throw new ServiceUnavailableException({
+        code: 'RELAY_DISABLED',
+        message: 'The gasless relay is not enabled on this deployment',
+      });
+    }
+    const digest = intentDigest(intent);
+    if (!this.auth.verifyPayloadSignature(digest, signatureBase64, publicKeyBase64)) {
+      throw new UnauthorizedException({
+        code: 'INVALID_SIGNATURE',
+        message: 'Intent signature does not verify against the provided public key',
+      });
+    }
+    // The signature alone proves nothing about user_address: any keypair can
+    // sign an intent naming someone else. The key must be the one encoded in
+    // the address, and a failed lookup rejects rather than waves through.
+    let keyBound = false;
+    try {
+      keyBound = await this.keeta.addressMatchesPublicKey(intent.user_address, publicKeyBase64);
+    } catch (error) {
+      this.logger.warn(
+        `Key binding check failed for ${intent.user_address}: ${error instanceof Error ? error.message : 'unknown error'}`,
+      );
+    }
+    if (!keyBound) {
+      throw new UnauthorizedException({
+        code: 'KEY_ADDRESS_MISMATCH',
+        message: 'Public key is not the key for intent.user_address',
+      });
+    }
+    if (this.consumedDigests.has(digest)) {
+      throw new ConflictException({ code: 'INTENT_REPLAYED', message: 'This intent has already been relayed' });
+    }
+
+    const used = this.usedToday(intent.user_address);
+    if (used >= this.dailyQuota()) {
+      throw new HttpException(
+        { code: 'RELAY_QUOTA_EXHAUSTED', message: `Daily relay quota of ${this.dailyQuota()} reached; retry tomorrow` },
+        HttpStatus.TOO_MANY_REQUESTS,
+      );
+    }
+    const entry = this.usage.get(intent.user_address);
+    const minIntervalMs = Number(this.config.get<string>('RELAY_MIN_INTERVAL_MS')) || DEFAULT_MIN_INTERVAL_MS;
+    if (entry && Date.now() - entry.lastAt < minIntervalMs) {
+      throw new HttpException(
+        { code: 'RELAY_THROTTLED', message: 'Relayed operations are rate limited; slow down' },
+        HttpStatus.TOO_MANY_REQUESTS,
+      );
+    }
+    return digest;
+  }
+
+  private consume(user: string, digest: string): void {
+    this.consumedDigests.add(digest);
+    while (this.consumedDigests.size > MAX_CONSUMED_DIGESTS) {
+      const oldest = this.consumedDigests.values().next().value as string;
+      this.consumedDigests.delete(oldest);
+    }
+    const day = new Date().toISOString().slice(0, 10);
+    const entry = this.usage.get(user);
+    if (entry && entry.day === day) {
+      entry.count += 1;
+      entry.lastAt = Date.now();
+    } else {
+      this.usage.set(user, { day, count: 1, lastAt: Date.now() });
+    }
+  }
+
+  private track(user: string, kind: RelayedOpKind, digest: string): RelayedOp {
+    const op: RelayedOp = {
+      id: randomUUID(),
+      user_address: user,
+      kind,
+      status: 'queued',
+      intent_digest: digest,
+      created_at: new Date().toISOString(),
+    };
+    const ops = this.opsByUser.get(user) ?? [];
+    ops.unshift(op);
+    if (ops.length > MAX_OPS_PER_USER) {
+      ops.length = MAX_OPS_PER_USER;
+    }
+    this.opsByUser.set(user, ops);
+    return op;
+  }
+
+  private usedToday(user: string): number {
+    const entry = this.usage.get(user);
+    const day = new Date().toISOString().slice(0, 10);
+    return entry && entry.day === day ? entry.count : 0;
+  }
+
+  private enabled(): boolean {
+    return this.config.get<string>('RELAY_ENABLED') === 'true';
+  }
+
+  private dailyQuota(): number {
+    return Number(this.config.get<string>('RELAY_DAILY_QUOTA')) || DEFAULT_DAILY_QUOTA;
+  }
+
+  private maxWithdrawal(): number {
+    return Number(this.config.get<string>('RELAY_MAX_WITHDRAWAL')) || DEFAULT_MAX_WITHDRAWAL;
+  }
+
+  private relayerAccount(): string {
+    return this.config.get<string>('RELAY_ACCOUNT') || 'relay';
+  }
+}
@@ -0,0 +1,25 @@
+import { Controller, Get, Param, Post, UseGuards } from '@nestjs/common';
+
+import { RetentionService } from './retention.service';
+import { AdminGuard } from '../common/admin.guard';
+
+@Controller('admin/retention')
+@UseGuards(AdminGuard)
+export class RetentionController {
+  constructor(private readonly retention: RetentionService) {}
+
+  @Get()
+  status() {
+    return this.retention.status();
+  }
+
+  @Post('prune')
+  pruneAll() {
+    return { classes: this.retention.pruneAll() };
+  }
+
+  @Post('prune/:dataClass')
+  pruneClass(@Param('dataClass') dataClass: string) {
+    return this.retention.pruneClass(dataClass);
+  }
+}
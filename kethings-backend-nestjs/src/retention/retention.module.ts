@@ -0,0 +1,15 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+import { RetentionService } from './retention.service';
+import { RetentionController } from './retention.controller';
+import { AdminGuard } from '../common/admin.guard';
+import { ReconciliationModule } from '../reconciliation/reconciliation.module';
+import { SettlementModule } from '../settlement/settlement.module';
+import { PoolsModule } from '../pools/pools.module';
+
+@Module({
+  imports: [ConfigModule, ReconciliationModule, SettlementModule, PoolsModule],
+  providers: [RetentionService, AdminGuard],
+  controllers: [RetentionController],
+})
+export class RetentionModule {}
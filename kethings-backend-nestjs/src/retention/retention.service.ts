@@ -0,0 +1,160 @@
+import { Injectable, Logger, NotFoundException, OnModuleDestroy, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { appendFileSync, existsSync, mkdirSync } from 'fs';
+import { join } from 'path';
+
+import { DriftArchiveService } from '../reconciliation/drift-archive.service';
+import { SolvencyService } from '../reconciliation/solvency.service';
+import { SettlementCostsService } from '../settlement/settlement-costs.service';
+import { SwapTelemetryService } from '../pools/swap-telemetry.service';
+
+interface DataClass {
+  name: string;
+  defaultRetentionDays: number;
+  count: () => number;
+  pruneBefore: (cutoff: string) => unknown[];
+}
+
+export interface RetentionStatusLine {
+  data_class: string;
+  retention_days: number;
+  records: number;
+  pruned_last_run: number;
+}
+
+const DEFAULT_PRUNE_INTERVAL_MS = 3_600_000;
+
+/**
+ * Retention policies and pruning for the unbounded in-memory/disk data
+ * classes. Each class has a configurable retention window; the pruning job
+ * exports expired records to a cold-storage log before deleting them, so
+ * nothing is lost — it just stops costing memory. Policies come from
+ * RETENTION_POLICY_DAYS as a {class: days} JSON object.
+ */
+@Injectable()
+export class RetentionService implements OnModuleInit, OnModuleDestroy {
+  private readonly logger = new Logger(RetentionService.name);
+  private readonly dataClasses: DataClass[];
+  private readonly lastPruned = new Map<string, number>();
+  private timer?: ReturnType<typeof setInterval>;
+  private lastRunAt?: string;
+
+  constructor(
+    private readonly config: ConfigService,
+    driftArchive: DriftArchiveService,
+    solvency: SolvencyService,
+    settlementCosts: SettlementCostsService,
+    swapTelemetry: SwapTelemetryService,
+  ) {
+    this.dataClasses = [
+      {
+        name: 'reconciliation_drift',
+        defaultRetentionDays: 30,
+        count: () => driftArchive.entryCount(),
+        pruneBefore: (cutoff) => driftArchive.pruneBefore(cutoff),
+      },
+      {
+        name: 'solvency_reports',
+        defaultRetentionDays: 30,
+        count: () => solvency.reportCount(),
+        pruneBefore: (cutoff) => solvency.pruneBefore(cutoff),
+      },
+      {
+        name: 'settlement_costs',
+        defaultRetentionDays: 90,
+        count: () => settlementCosts.recordCount(),
+        pruneBefore: (cutoff) => settlementCosts.pruneBefore(cutoff),
+      },
+      {
+        name: 'swap_telemetry',
+        defaultRetentionDays: 14,
+        count: () => swapTelemetry.recordCount(),
+        pruneBefore: (cutoff) => swapTelemetry.pruneBefore(cutoff),
+      },
+    ];
+  }
+
+  onModuleInit(): void {
+    const intervalMs = Number(this.config.get<string>('RETENTION_PRUNE_INTERVAL_MS')) || DEFAULT_PRUNE_INTERVAL_MS;
+    this.timer = setInterval(() => this.pruneAll(), intervalMs);
+  }
+
+  onModuleDestroy(): void {
+    if (this.timer) {
+      clearInterval(this.timer);
+    }
+  }
+
+  status(): { last_run_at?: string; classes: RetentionStatusLine[] } {
+    return {
+      last_run_at: this.lastRunAt,
+      classes: this.dataClasses.map((dataClass) => ({
+        data_class: dataClass.name,
+        retention_days: this.retentionDays(dataClass),
+        records: dataClass.count(),
+        pruned_last_run: this.lastPruned.get(dataClass.name) ?? 0,
+      })),
+    };
+  }
+
+  pruneAll(): RetentionStatusLine[] {
+    for (const dataClass of this.dataClasses) {
+      this.prune(dataClass);
+    }
+    this.lastRunAt = new Date().toISOString();
+    return this.status().classes;
+  }
+
+  pruneClass(name: string): RetentionStatusLine {
+    const dataClass = this.dataClasses.find((candidate) => candidate.name === name);
+    if (!dataClass) {
+      throw new NotFoundException(`Unknown data class ${name}`);
+    }
+    this.prune(dataClass);
+    return {
+      data_class: dataClass.name,
+      retention_days: this.retentionDays(dataClass),
+      records: dataClass.count(),
+      pruned_last_run: this.lastPruned.get(dataClass.name) ?? 0,
+    };
+  }
+
+  private prune(dataClass: DataClass): void {
+    const cutoff = new Date(Date.now() - this.retentionDays(dataClass) * 86_400_000).toISOString();
+    const pruned = dataClass.pruneBefore(cutoff);
+    this.lastPruned.set(dataClass.name, pruned.length);
+    if (pruned.length === 0) {
+      return;
+    }
+    this.exportToColdStorage(dataClass.name, pruned);
+    this.logger.log(`Pruned ${pruned.length} ${dataClass.name} records older than ${cutoff}`);
+  }
+
+  private retentionDays(dataClass: DataClass): number {
+    const raw = this.config.get<string>('RETENTION_POLICY_DAYS');
+    if (raw) {
+      try {
+        const policies = JSON.parse(raw) as Record<string, number>;
+        const days = policies[dataClass.name];
+        if (typeof days === 'number' && days > 0) {
+          return days;
+        }
+      } catch {
+        this.logger.warn('Failed to parse RETENTION_POLICY_DAYS JSON; using defaults');
+      }
+    }
+    return dataClass.defaultRetentionDays;
+  }
+
+  private exportToColdStorage(name: string, records: unknown[]): void {
+    const dir = this.config.get<string>('RETENTION_EXPORT_DIR') || 'data/cold';
+    try {
+      if (!existsSync(dir)) {
+        mkdirSync(dir, { recursive: true });
+      }
+      appendFileSync(join(dir, `${name}.log`), records.map((record) => `${JSON.stringify(record)}\n`).join(''));
+    } catch (error) {
+      this.logger.error(`Failed to export pruned ${name} records to cold storage`, error);
+    }
+  }
+}
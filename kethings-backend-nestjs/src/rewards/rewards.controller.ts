@@ -0,0 +1,13 @@
+import { Controller, Get, Param } from '@nestjs/common';
+
+import { RewardsService } from './rewards.service';
+
+@Controller('rewards')
+export class RewardsController {
+  constructor(private readonly rewards: RewardsService) {}
+
+  @Get(':userAddress')
+  summary(@Param('userAddress') userAddress: string) {
+    return this.rewards.getRewardsSummary(userAddress);
+  }
+}
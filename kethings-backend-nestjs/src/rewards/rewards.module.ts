@@ -0,0 +1,11 @@
+import { Module } from '@nestjs/common';
+import { RewardsService } from './rewards.service';
+import { RewardsController } from './rewards.controller';
+import { PoolsModule } from '../pools/pools.module';
+
+@Module({
+  imports: [PoolsModule],
+  providers: [RewardsService],
+  controllers: [RewardsController],
+})
+export class RewardsModule {}
@@ -0,0 +1,70 @@
+import { Injectable } from '@nestjs/common';
+
+import { PositionsService } from '../pools/positions.service';
+
+export interface PositionBoost {
+  position_id: string;
+  pool_id: string;
+  held_days: number;
+  multiplier: number;
+}
+
+export interface RewardsSummary {
+  user_address: string;
+  positions: PositionBoost[];
+  /** LP-amount-weighted average multiplier across the user's open positions. */
+  current_multiplier: number;
+}
+
+// Boost tiers for continuously provided liquidity, keyed by minimum days held.
+const BOOST_TIERS: Array<{ minDays: number; multiplier: number }> = [
+  { minDays: 30, multiplier: 1.5 },
+  { minDays: 7, multiplier: 1.25 },
+  { minDays: 0, multiplier: 1.0 },
+];
+
+const MS_PER_DAY = 24 * 60 * 60 * 1000;
+
+@Injectable()
+export class RewardsService {
+  constructor(private readonly positions: PositionsService) {}
+
+  getRewardsSummary(userAddress: string): RewardsSummary {
+    const open = this.positions.listPositions(userAddress);
+    const now = Date.now();
+
+    const boosts: PositionBoost[] = open.map((position) => {
+      const heldDays = Math.max(0, (now - Date.parse(position.created_at)) / MS_PER_DAY);
+      return {
+        position_id: position.id,
+        pool_id: position.pool_id,
+        held_days: Math.floor(heldDays),
+        multiplier: this.multiplierForDays(heldDays),
+      };
+    });
+
+    const totalLp = open.reduce((sum, position) => sum + position.lp_amount, 0);
+    const weighted =
+      totalLp > 0
+        ? open.reduce(
+            (sum, position, index) => sum + position.lp_amount * boosts[index].multiplier,
+            0,
+          ) / totalLp
+        : 1.0;
+
+    return {
+      user_address: userAddress,
+      positions: boosts,
+      current_multiplier: Number(weighted.toFixed(4)),
+    };
+  }
+
+  private multiplierForDays(days: number): number {
+    for (const tier of BOOST_TIERS) {
+      if (days >= tier.minDays) {
+        return tier.multiplier;
+      }
+    }
+    return 1.0;
+  }
+}
@@ -0,0 +1,62 @@
+import { Type } from 'class-transformer';
+import { IsBoolean, IsIn, IsNumber, IsObject, IsOptional, IsPositive, IsString } from 'class-validator';
+
+export class RfqMakerMetaDto {
+  @IsString()
+  id!: string;
+
+  @IsOptional()
+  @IsString()
+  display_name?: string;
+}
+
+export class CreateRfqOrderDto {
+  @IsOptional()
+  @IsString()
+  id?: string;
+
+  @IsString()
+  pair!: string;
+
+  @IsIn(['buy', 'sell'])
+  side!: 'buy' | 'sell';
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  price!: number;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  size!: number;
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  min_fill?: number;
+
+  @IsString()
+  expiry!: string;
+
+  @IsObject()
+  @Type(() => RfqMakerMetaDto)
+  maker!: RfqMakerMetaDto;
+
+  @IsOptional()
+  @IsString()
+  unsigned_block?: string;
+
+  @IsOptional()
+  @IsString()
+  maker_signature?: string;
+
+  @IsOptional()
+  @IsString()
+  storage_account?: string;
+
+  @IsOptional()
+  @IsBoolean()
+  allowlisted?: boolean;
+}
@@ -0,0 +1,46 @@
+import { Type } from 'class-transformer';
+import { IsBoolean, IsNumber, IsOptional, IsPositive, IsString } from 'class-validator';
+
+export class DeclareIntentionDto {
+  @IsString()
+  taker_address!: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  fill_amount!: number;
+
+  @IsOptional()
+  @IsString()
+  unsigned_atomic_swap_block?: string;
+
+  /** ed25519 signature (base64) over the canonical declaration payload. */
+  @IsOptional()
+  @IsString()
+  taker_signature?: string;
+
+  @IsOptional()
+  @IsString()
+  taker_public_key?: string;
+
+  /** Explicit opt-in to fill an order flagged as priced off the pool mid. */
+  @IsOptional()
+  @IsBoolean()
+  accept_off_market?: boolean;
+}
+
+export class ApproveDeclarationDto {
+  @IsString()
+  declaration_id!: string;
+
+  @IsBoolean()
+  approved!: boolean;
+
+  @IsOptional()
+  @IsString()
+  maker_block_hash?: string;
+
+  @IsOptional()
+  @IsString()
+  maker_signed_block?: string;
+}
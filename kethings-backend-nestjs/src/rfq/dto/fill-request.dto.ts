@@ -0,0 +1,22 @@
+import { Type } from 'class-transformer';
+import { IsBoolean, IsNumber, IsOptional, IsPositive, IsString } from 'class-validator';
+
+export class FillRequestDto {
+  @IsOptional()
+  @IsString()
+  taker_address?: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  taker_amount!: number;
+
+  @IsOptional()
+  @IsBoolean()
+  auto_publish?: boolean;
+
+  /** Explicit opt-in to fill an order flagged as priced off the pool mid. */
+  @IsOptional()
+  @IsBoolean()
+  accept_off_market?: boolean;
+}
@@ -0,0 +1,15 @@
+import { Type } from 'class-transformer';
+import { IsNumber, IsObject, IsOptional, IsPositive } from 'class-validator';
+
+export class MakerRiskLimitsDto {
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  max_open_notional?: number;
+
+  /** Pair symbol → notional cap; values validated as positive in the service. */
+  @IsOptional()
+  @IsObject()
+  per_pair_notional?: Record<string, number>;
+}
@@ -0,0 +1,28 @@
+import { IsNotEmpty, IsOptional, IsString } from 'class-validator';
+
+export class RegisterMakerDto {
+  @IsString()
+  @IsNotEmpty()
+  maker_id!: string;
+
+  @IsString()
+  @IsNotEmpty()
+  wallet_address!: string;
+
+  @IsOptional()
+  @IsString()
+  display_name?: string;
+
+  /** Challenge nonce previously issued by /api/auth/challenge. */
+  @IsString()
+  @IsNotEmpty()
+  nonce!: string;
+
+  @IsString()
+  @IsNotEmpty()
+  signature!: string;
+
+  @IsString()
+  @IsNotEmpty()
+  public_key!: string;
+}
@@ -0,0 +1,9 @@
+import { IsString, IsUrl } from 'class-validator';
+
+export class RegisterWebhookDto {
+  @IsUrl({ require_tld: false })
+  url!: string;
+
+  @IsString()
+  secret!: string;
+}
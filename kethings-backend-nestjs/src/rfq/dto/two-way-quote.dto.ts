@@ -0,0 +1,78 @@
+import { Type } from 'class-transformer';
+import { IsIn, IsNumber, IsOptional, IsPositive, IsString } from 'class-validator';
+
+export class CreateTwoWayQuoteDto {
+  @IsString()
+  pair!: string;
+
+  /** Optional: identity comes from x-maker-key; if present it must match. */
+  @IsOptional()
+  @IsString()
+  maker_id?: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  bid_price!: number;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  bid_size!: number;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  ask_price!: number;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  ask_size!: number;
+
+  @IsString()
+  expiry!: string;
+}
+
+export class ReplaceTwoWayQuoteDto {
+  /** Optional: identity comes from x-maker-key; if present it must match. */
+  @IsOptional()
+  @IsString()
+  maker_id?: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  bid_price!: number;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  bid_size!: number;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  ask_price!: number;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  ask_size!: number;
+
+  @IsString()
+  expiry!: string;
+}
+
+export class FillTwoWayQuoteDto {
+  @IsIn(['bid', 'ask'])
+  side!: 'bid' | 'ask';
+
+  @IsString()
+  taker_address!: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  amount!: number;
+}
@@ -0,0 +1,35 @@
+import { CanActivate, ExecutionContext, ForbiddenException, Injectable, UnauthorizedException } from '@nestjs/common';
+
+import { RfqMakersService } from './rfq-makers.service';
+
+/**
+ * Authenticates maker-only RFQ endpoints with the x-maker-key header issued
+ * at registration. When the request names a maker (body maker_id, body
+ * maker.id or query maker_id) it must match the key's owner, so one maker
+ * cannot act on another's orders. The resolved maker id is stashed on the
+ * request for handlers that need it.
+ */
+@Injectable()
+export class MakerAuthGuard implements CanActivate {
+  constructor(private readonly makers: RfqMakersService) {}
+
+  canActivate(context: ExecutionContext): boolean {
+    const request = context.switchToHttp().getRequest();
+    const apiKey = request.headers['x-maker-key'];
+    if (typeof apiKey !== 'string' || !apiKey) {
+      throw new UnauthorizedException({ code: 'MAKER_KEY_REQUIRED', message: 'x-maker-key header is required' });
+    }
+    const makerId = this.makers.resolveApiKey(apiKey);
+    if (!makerId) {
+      throw new UnauthorizedException({ code: 'INVALID_MAKER_KEY', message: 'Unknown or rotated maker API key' });
+    }
+
+    const claimed =
+      request.body?.maker_id ?? request.body?.maker?.id ?? (typeof request.query?.maker_id === 'string' ? request.query.maker_id : undefined);
+    if (claimed !== undefined && claimed !== makerId) {
+      throw new ForbiddenException({ code: 'MAKER_MISMATCH', message: `Key belongs to maker ${makerId}, not ${claimed}` });
+    }
+    request.makerId = makerId;
+    return true;
+  }
+}
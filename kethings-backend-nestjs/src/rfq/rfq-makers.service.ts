@@ -0,0 +1,200 @@
+import { Inject, Injectable, Logger, OnModuleDestroy, OnModuleInit, forwardRef } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { createHash, randomBytes } from 'crypto';
+
+import { MakerRiskLimits, RfqMaker, RfqMakerMeta } from './rfq.types';
+import { RfqService } from './rfq.service';
+
+const DEFAULT_HEARTBEAT_TIMEOUT_MS = 90_000;
+const DEFAULT_LIVENESS_SWEEP_MS = 15_000;
+const DEFAULT_MAX_MISSED_SLA = 3;
+
+@Injectable()
+export class RfqMakersService implements OnModuleInit, OnModuleDestroy {
+  private readonly logger = new Logger(RfqMakersService.name);
+  private readonly makers = new Map<string, RfqMaker>();
+  /** sha256(api key) → maker id; only the hash is retained server-side. */
+  private readonly apiKeyHashes = new Map<string, string>();
+  private sweepTimer?: ReturnType<typeof setInterval>;
+
+  constructor(
+    private readonly config: ConfigService,
+    @Inject(forwardRef(() => RfqService))
+    private readonly rfq: RfqService,
+  ) {}
+
+  onModuleInit(): void {
+    this.sweepTimer = setInterval(() => this.sweepLiveness(), DEFAULT_LIVENESS_SWEEP_MS);
+  }
+
+  onModuleDestroy(): void {
+    if (this.sweepTimer) {
+      clearInterval(this.sweepTimer);
+    }
+  }
+
+  listMakers(): RfqMaker[] {
+    return Array.from(this.makers.values());
+  }
+
+  getOrRegister(makerId: string): RfqMaker {
+    let maker = this.makers.get(makerId);
+    if (!maker) {
+      maker = {
+        id: makerId,
+        display_name: makerId,
+        verified: false,
+        reputation_score: 0,
+        auto_sign_sla_ms: 0,
+        fills_completed: 0,
+        failure_rate: 0,
+        online: false,
+        suspended: false,
+        missed_sla_count: 0,
+      };
+      this.makers.set(makerId, maker);
+    }
+    return maker;
+  }
+
+  toMeta(maker: RfqMaker): RfqMakerMeta {
+    return {
+      id: maker.id,
+      display_name: maker.display_name,
+      verified: maker.verified,
+      reputation_score: maker.reputation_score,
+      auto_sign_sla_ms: maker.auto_sign_sla_ms,
+      fills_completed: maker.fills_completed,
+      failure_rate: maker.failure_rate,
+      allowlist_label: maker.allowlist_label,
+    };
+  }
+
+  /**
+   * Complete onboarding for a maker whose wallet proof has already been
+   * verified by the caller. Issues a fresh API key — registering again
+   * rotates it, invalidating the old one. Only the key's hash is stored;
+   * the plaintext is returned once and never again.
+   */
+  register(makerId: string, walletAddress: string, displayName?: string, publicKey?: string): { maker: RfqMaker; api_key: string } {
+    const maker = this.getOrRegister(makerId);
+    if (maker.wallet_address && maker.wallet_address !== walletAddress) {
+      throw new Error(`Maker ${makerId} is already registered to a different wallet`);
+    }
+    for (const [hash, id] of this.apiKeyHashes) {
+      if (id === makerId) {
+        this.apiKeyHashes.delete(hash);
+      }
+    }
+    const apiKey = `rfqmk_${randomBytes(24).toString('hex')}`;
+    this.apiKeyHashes.set(hashKey(apiKey), makerId);
+    maker.wallet_address = walletAddress;
+    if (publicKey) {
+      maker.public_key = publicKey;
+    }
+    maker.registered_at = maker.registered_at ?? new Date().toISOString();
+    if (displayName) {
+      maker.display_name = displayName;
+    }
+    this.logger.log(`Registered maker ${makerId} for wallet ${walletAddress}`);
+    return { maker, api_key: apiKey };
+  }
+
+  /** Resolve an API key to its maker id, or undefined when unknown. */
+  resolveApiKey(apiKey: string): string | undefined {
+    return this.apiKeyHashes.get(hashKey(apiKey));
+  }
+
+  /**
+   * Fold a real fill outcome into the maker's public reputation fields.
+   * Success and failure counts drive failure_rate directly; the reputation
+   * score is the success share scaled to 0–100.
+   */
+  recordFillOutcome(makerId: string, success: boolean): void {
+    const maker = this.getOrRegister(makerId);
+    if (success) {
+      maker.fills_completed += 1;
+    } else {
+      maker.missed_sla_count += 1;
+    }
+    const attempts = maker.fills_completed + maker.missed_sla_count;
+    maker.failure_rate = attempts > 0 ? maker.missed_sla_count / attempts : 0;
+    maker.reputation_score = Math.round((1 - maker.failure_rate) * 100);
+  }
+
+  /**
+   * Replace a maker's risk limits. Omitted fields clear the corresponding
+   * cap; enforcement happens in RfqService when orders and quotes are
+   * created or repriced.
+   */
+  setRiskLimits(makerId: string, limits: MakerRiskLimits): RfqMaker {
+    if (limits.max_open_notional !== undefined && !(limits.max_open_notional > 0)) {
+      throw new Error(`max_open_notional must be positive: ${limits.max_open_notional}`);
+    }
+    for (const [pair, cap] of Object.entries(limits.per_pair_notional ?? {})) {
+      if (!(cap > 0)) {
+        throw new Error(`Per-pair cap for ${pair} must be positive: ${cap}`);
+      }
+    }
+    const maker = this.getOrRegister(makerId);
+    maker.risk_limits = {
+      max_open_notional: limits.max_open_notional,
+      per_pair_notional: limits.per_pair_notional ? { ...limits.per_pair_notional } : undefined,
+    };
+    this.logger.log(`Updated risk limits for maker ${makerId}`);
+    return maker;
+  }
+
+  getRiskLimits(makerId: string): MakerRiskLimits {
+    return this.getOrRegister(makerId).risk_limits ?? {};
+  }
+
+  /** Register a liveness ping from a maker, resuming auto-suspended orders. */
+  heartbeat(makerId: string): RfqMaker {
+    const maker = this.getOrRegister(makerId);
+    maker.last_heartbeat_at = new Date().toISOString();
+    maker.online = true;
+    if (maker.suspended) {
+      maker.suspended = false;
+      maker.missed_sla_count = 0;
+      this.rfq.setMakerOrdersSuspended(makerId, false);
+      this.logger.log(`Maker ${makerId} back online; orders resumed`);
+    }
+    return maker;
+  }
+
+  /**
+   * Record an SLA miss (e.g. a declaration the maker let expire). Repeated
+   * misses suspend the maker's open orders until the next heartbeat.
+   */
+  recordSlaMiss(makerId: string): void {
+    const maker = this.getOrRegister(makerId);
+    maker.missed_sla_count += 1;
+    const maxMissed = Number(this.config.get<string>('RFQ_MAX_MISSED_SLA')) || DEFAULT_MAX_MISSED_SLA;
+    if (maker.missed_sla_count >= maxMissed && !maker.suspended) {
+      this.suspend(maker, `missed SLA on ${maker.missed_sla_count} declarations`);
+    }
+  }
+
+  private sweepLiveness(): void {
+    const timeoutMs = Number(this.config.get<string>('RFQ_HEARTBEAT_TIMEOUT_MS')) || DEFAULT_HEARTBEAT_TIMEOUT_MS;
+    const cutoff = Date.now() - timeoutMs;
+    for (const maker of this.makers.values()) {
+      if (!maker.online || maker.suspended) continue;
+      if (!maker.last_heartbeat_at || Date.parse(maker.last_heartbeat_at) < cutoff) {
+        maker.online = false;
+        this.suspend(maker, 'heartbeat timed out');
+      }
+    }
+  }
+
+  private suspend(maker: RfqMaker, reason: string): void {
+    maker.suspended = true;
+    this.rfq.setMakerOrdersSuspended(maker.id, true);
+    this.logger.warn(`Suspended maker ${maker.id}: ${reason}`);
+  }
+}
+
+function hashKey(apiKey: string): string {
+  return createHash('sha256').update(apiKey).digest('hex');
+}
@@ -0,0 +1,66 @@
+import { Injectable } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+import { PoolsService } from '../pools/pools.service';
+import { RfqSide } from './rfq.types';
+
+export interface OffMarketCheck {
+  off_market: boolean;
+  pool_mid?: string;
+  /** How much worse than the pool mid the price is for the taker, in percent. */
+  deviation_pct?: string;
+  threshold_pct: number;
+}
+
+const DEFAULT_THRESHOLD_PCT = 5;
+
+/**
+ * Cross-checks RFQ prices against the pool mid for the same pair, when one
+ * exists. A maker selling well above — or bidding well below — the pool is
+ * not necessarily malicious, but a taker who can get a better price on-pool
+ * should have to opt in explicitly rather than fill it by accident.
+ */
+@Injectable()
+export class RfqPricingService {
+  constructor(
+    private readonly config: ConfigService,
+    private readonly pools: PoolsService,
+  ) {}
+
+  thresholdPct(): number {
+    return Number(this.config.get<string>('RFQ_OFF_MARKET_THRESHOLD_PCT')) || DEFAULT_THRESHOLD_PCT;
+  }
+
+  check(pair: string, side: RfqSide, price: number): OffMarketCheck {
+    const threshold = this.thresholdPct();
+    const mid = this.poolMid(pair);
+    if (mid === undefined || !(price > 0)) {
+      return { off_market: false, threshold_pct: threshold };
+    }
+    // Worse for the taker means: maker sells above mid, or maker bids below it.
+    const deviation = side === 'sell' ? ((price - mid) / mid) * 100 : ((mid - price) / mid) * 100;
+    return {
+      off_market: deviation > threshold,
+      pool_mid: mid.toString(),
+      deviation_pct: deviation.toFixed(4),
+      threshold_pct: threshold,
+    };
+  }
+
+  /** Pool mid (quote per base) for a BASE/QUOTE pair; undefined without a pool. */
+  private poolMid(pair: string): number | undefined {
+    const [base, quote] = pair.split('/');
+    if (!base || !quote) {
+      return undefined;
+    }
+    for (const pool of this.pools.allPools()) {
+      if (pool.tokenA === base && pool.tokenB === quote && pool.reserveA > 0) {
+        return pool.reserveB / pool.reserveA;
+      }
+      if (pool.tokenA === quote && pool.tokenB === base && pool.reserveB > 0) {
+        return pool.reserveA / pool.reserveB;
+      }
+    }
+    return undefined;
+  }
+}
@@ -0,0 +1,117 @@
+import { BadRequestException, Injectable, Logger } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { createPublicKey, verify as verifySignature } from 'crypto';
+
+import { canonicalJson } from '../audit/audit-hash.service';
+
+/** SPKI DER prefix for a raw 32-byte ed25519 public key. */
+const ED25519_SPKI_PREFIX = Buffer.from('302a300506032b6570032100', 'hex');
+
+export interface SwapBlockExpectations {
+  taker_address?: string;
+  storage_account?: string;
+  amount: number;
+}
+
+/**
+ * Cryptographic validation of RFQ payloads. Maker order signatures and taker
+ * declaration signatures are ed25519 over the canonical JSON of the payload
+ * (same key ordering as the audit chain), so both sides can reproduce the
+ * exact bytes. Validation runs whenever a signature is supplied; setting
+ * RFQ_REQUIRE_SIGNATURES=true additionally rejects unsigned payloads.
+ */
+@Injectable()
+export class RfqSignaturesService {
+  private readonly logger = new Logger(RfqSignaturesService.name);
+
+  constructor(private readonly config: ConfigService) {}
+
+  required(): boolean {
+    return this.config.get<string>('RFQ_REQUIRE_SIGNATURES') === 'true';
+  }
+
+  /** Canonical bytes a maker signs when creating an order. */
+  orderPayload(input: { pair: string; side: string; price: number; size: number; min_fill?: number; expiry: string; maker_id: string }): string {
+    return canonicalJson({
+      type: 'rfq_order',
+      pair: input.pair,
+      side: input.side,
+      price: input.price,
+      size: input.size,
+      min_fill: input.min_fill,
+      expiry: input.expiry,
+      maker_id: input.maker_id,
+    });
+  }
+
+  /** Canonical bytes a taker signs when declaring intent to fill. */
+  declarationPayload(orderId: string, takerAddress: string, fillAmount: number): string {
+    return canonicalJson({
+      type: 'rfq_declaration',
+      order_id: orderId,
+      taker_address: takerAddress,
+      fill_amount: fillAmount,
+    });
+  }
+
+  assertValidSignature(payload: string, signatureBase64: string, publicKeyBase64: string, subject: string): void {
+    if (!this.verify(payload, signatureBase64, publicKeyBase64)) {
+      throw new BadRequestException({
+        code: 'INVALID_PAYLOAD_SIGNATURE',
+        message: `${subject} signature does not verify against the canonical payload`,
+      });
+    }
+  }
+
+  /**
+   * Decode an unsigned atomic swap block (base64 JSON) and check it
+   * references the accounts and amount the fill is actually settling, so an
+   * approved declaration cannot smuggle a block that pays someone else.
+   */
+  assertSwapBlockMatches(unsignedBlockBase64: string, expected: SwapBlockExpectations): void {
+    let block: Record<string, unknown>;
+    try {
+      block = JSON.parse(Buffer.from(unsignedBlockBase64, 'base64').toString('utf8')) as Record<string, unknown>;
+    } catch {
+      throw new BadRequestException({
+        code: 'UNSIGNED_BLOCK_INVALID',
+        message: 'Unsigned atomic swap block is not valid base64-encoded JSON',
+      });
+    }
+
+    const mismatches: string[] = [];
+    if (expected.taker_address !== undefined && block.taker_address !== expected.taker_address) {
+      mismatches.push(`taker_address ${String(block.taker_address)} != ${expected.taker_address}`);
+    }
+    if (expected.storage_account !== undefined && block.storage_account !== expected.storage_account) {
+      mismatches.push(`storage_account ${String(block.storage_account)} != ${expected.storage_account}`);
+    }
+    if (block.amount !== undefined && Number(block.amount) !== expected.amount) {
+      mismatches.push(`amount ${String(block.amount)} != ${expected.amount}`);
+    }
+    if (mismatches.length > 0) {
+      throw new BadRequestException({
+        code: 'UNSIGNED_BLOCK_MISMATCH',
+        message: `Unsigned block does not match the fill: ${mismatches.join('; ')}`,
+      });
+    }
+  }
+
+  private verify(payload: string, signatureBase64: string, publicKeyBase64: string): boolean {
+    try {
+      const rawKey = Buffer.from(publicKeyBase64, 'base64');
+      if (rawKey.length !== 32) {
+        return false;
+      }
+      const publicKey = createPublicKey({
+        key: Buffer.concat([ED25519_SPKI_PREFIX, rawKey]),
+        format: 'der',
+        type: 'spki',
+      });
+      return verifySignature(null, Buffer.from(payload, 'utf8'), publicKey, Buffer.from(signatureBase64, 'base64'));
+    } catch (error) {
+      this.logger.warn(`Payload signature verification errored: ${error instanceof Error ? error.message : 'unknown error'}`);
+      return false;
+    }
+  }
+}
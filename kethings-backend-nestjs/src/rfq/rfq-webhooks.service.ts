@@ -0,0 +1,112 @@
+import { Injectable, Logger, NotFoundException } from '@nestjs/common';
+import { createHmac } from 'crypto';
+
+export interface MakerWebhook {
+  maker_id: string;
+  url: string;
+  secret: string;
+  registered_at: string;
+}
+
+export interface WebhookDelivery {
+  event: string;
+  url: string;
+  attempt: number;
+  status?: number;
+  ok: boolean;
+  error?: string;
+  delivered_at: string;
+}
+
+const MAX_ATTEMPTS = 3;
+const RETRY_DELAYS_MS = [1_000, 5_000];
+const MAX_LOG_ENTRIES = 200;
+
+/**
+ * Outbound webhooks for professional makers: payloads are signed with the
+ * maker's HMAC secret so external market-making systems can authenticate
+ * pushes for declarations, fills and expiries.
+ */
+@Injectable()
+export class RfqWebhooksService {
+  private readonly logger = new Logger(RfqWebhooksService.name);
+  private readonly webhooks = new Map<string, MakerWebhook>();
+  private readonly deliveryLogs = new Map<string, WebhookDelivery[]>();
+
+  register(makerId: string, url: string, secret: string): MakerWebhook {
+    const webhook: MakerWebhook = {
+      maker_id: makerId,
+      url,
+      secret,
+      registered_at: new Date().toISOString(),
+    };
+    this.webhooks.set(makerId, webhook);
+    return webhook;
+  }
+
+  unregister(makerId: string): void {
+    if (!this.webhooks.delete(makerId)) {
+      throw new NotFoundException(`No webhook registered for maker ${makerId}`);
+    }
+  }
+
+  getDeliveries(makerId: string): WebhookDelivery[] {
+    return this.deliveryLogs.get(makerId) ?? [];
+  }
+
+  /** Fire-and-forget delivery with retries; failures only affect the log. */
+  fire(makerId: string, event: string, payload: Record<string, unknown>): void {
+    const webhook = this.webhooks.get(makerId);
+    if (!webhook) {
+      return;
+    }
+    void this.deliver(webhook, event, payload, 1);
+  }
+
+  private async deliver(
+    webhook: MakerWebhook,
+    event: string,
+    payload: Record<string, unknown>,
+    attempt: number,
+  ): Promise<void> {
+    const body = JSON.stringify({ event, fired_at: new Date().toISOString(), data: payload });
+    const signature = createHmac('sha256', webhook.secret).update(body).digest('hex');
+
+    let status: number | undefined;
+    let ok = false;
+    let errorMessage: string | undefined;
+    try {
+      const response = await fetch(webhook.url, {
+        method: 'POST',
+        headers: {
+          'Content-Type': 'application/json',
+          'X-RFQ-Event': event,
+          'X-RFQ-Signature': signature,
+        },
+        body,
+      });
+      status = response.status;
+      ok = response.ok;
+    } catch (error) {
+      errorMessage = error instanceof Error ? error.message : 'delivery failed';
+    }
+
+    this.log(webhook.maker_id, { event, url: webhook.url, attempt, status, ok, error: errorMessage, delivered_at: new Date().toISOString() });
+
+    if (!ok && attempt < MAX_ATTEMPTS) {
+      const delay = RETRY_DELAYS_MS[attempt - 1] ?? RETRY_DELAYS_MS[RETRY_DELAYS_MS.length - 1];
+      setTimeout(() => void this.deliver(webhook, event, payload, attempt + 1), delay);
+    } else if (!ok) {
+      this.logger.warn(`Webhook delivery for maker ${webhook.maker_id} event ${event} failed after ${attempt} attempts`);
+    }
+  }
+
+  private log(makerId: string, delivery: WebhookDelivery): void {
+    const log = this.deliveryLogs.get(makerId) ?? [];
+    log.unshift(delivery);
+    if (log.length > MAX_LOG_ENTRIES) {
+      log.length = MAX_LOG_ENTRIES;
+    }
+    this.deliveryLogs.set(makerId, log);
+  }
+}
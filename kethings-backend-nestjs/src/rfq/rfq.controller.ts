@@ -0,0 +1,208 @@
+import { BadRequestException, Body, Controller, Delete, Get, HttpCode, Param, Post, Put, Query, Req, Res, UseGuards } from '@nestjs/common';
+import type { Request, Response } from 'express';
+
+import { keysetPage, respondWithEtag } from '../common/list-caching';
+
+import { RfqService } from './rfq.service';
+import { RfqMakersService } from './rfq-makers.service';
+import { RfqWebhooksService } from './rfq-webhooks.service';
+import { MakerAuthGuard } from './maker-auth.guard';
+import { AuthService } from '../auth/auth.service';
+import { RegisterMakerDto } from './dto/register-maker.dto';
+import { RegisterWebhookDto } from './dto/register-webhook.dto';
+import { CreateRfqOrderDto } from './dto/create-order.dto';
+import { FillRequestDto } from './dto/fill-request.dto';
+import { ApproveDeclarationDto, DeclareIntentionDto } from './dto/declaration.dto';
+import { CreateTwoWayQuoteDto, FillTwoWayQuoteDto, ReplaceTwoWayQuoteDto } from './dto/two-way-quote.dto';
+import { MakerRiskLimitsDto } from './dto/maker-risk-limits.dto';
+
+@Controller('rfq')
+export class RfqController {
+  constructor(
+    private readonly rfq: RfqService,
+    private readonly makers: RfqMakersService,
+    private readonly webhooks: RfqWebhooksService,
+    private readonly auth: AuthService,
+  ) {}
+
+  @Get('orders')
+  listOrders(
+    @Req() req: Request,
+    @Res({ passthrough: true }) res: Response,
+    @Query('pair') pair?: string,
+    @Query('after') after?: string,
+    @Query('limit') limit?: string,
+  ) {
+    const page = keysetPage(this.rfq.listOrders(pair), (order) => order.id, after, limit ? Number(limit) : undefined);
+    return respondWithEtag(req, res, { orders: page.items, next_cursor: page.next_cursor });
+  }
+
+  @Get('orders/:orderId')
+  getOrder(@Param('orderId') orderId: string) {
+    return this.rfq.getOrder(orderId);
+  }
+
+  @Post('orders')
+  @UseGuards(MakerAuthGuard)
+  createOrder(@Body() body: CreateRfqOrderDto) {
+    return this.rfq.createOrder({
+      id: body.id,
+      pair: body.pair,
+      side: body.side,
+      price: body.price,
+      size: body.size,
+      min_fill: body.min_fill,
+      expiry: body.expiry,
+      maker_id: body.maker.id,
+      unsigned_block: body.unsigned_block,
+      maker_signature: body.maker_signature,
+      storage_account: body.storage_account,
+      allowlisted: body.allowlisted,
+    });
+  }
+
+  @Delete('orders/:orderId')
+  @UseGuards(MakerAuthGuard)
+  @HttpCode(204)
+  cancelOrder(@Param('orderId') orderId: string, @Req() req: Request) {
+    // Ownership comes from the authenticated key, not the request body:
+    // the route has no body for the guard to cross-check.
+    this.rfq.cancelOrder(orderId, (req as Request & { makerId: string }).makerId);
+  }
+
+  @Post('orders/:orderId/fill-request')
+  requestFill(@Param('orderId') orderId: string, @Body() body: FillRequestDto) {
+    return this.rfq.requestFill(orderId, body.taker_address, body.taker_amount, body.accept_off_market ?? false);
+  }
+
+  @Post('orders/:orderId/declare')
+  async declare(@Param('orderId') orderId: string, @Body() body: DeclareIntentionDto) {
+    const declaration = await this.rfq.declareIntention(
+      orderId,
+      body.taker_address,
+      body.fill_amount,
+      body.unsigned_atomic_swap_block,
+      body.taker_signature,
+      body.taker_public_key,
+      body.accept_off_market ?? false,
+    );
+    return { declaration, status: 'declared' };
+  }
+
+  @Get('orders/:orderId/declarations')
+  listDeclarations(@Param('orderId') orderId: string) {
+    return this.rfq.listDeclarations(orderId);
+  }
+
+  @Post('orders/:orderId/approve-declaration')
+  @UseGuards(MakerAuthGuard)
+  approveDeclaration(@Param('orderId') orderId: string, @Body() body: ApproveDeclarationDto, @Req() req: Request) {
+    const declaration = this.rfq.resolveDeclaration(
+      orderId,
+      body.declaration_id,
+      body.approved,
+      (req as Request & { makerId: string }).makerId,
+    );
+    return { declaration, status: body.approved ? 'approved' : 'rejected' };
+  }
+
+  @Get('quotes')
+  listTwoWayQuotes(@Query('pair') pair?: string) {
+    return this.rfq.listTwoWayQuotes(pair);
+  }
+
+  @Get('quotes/:quoteId')
+  getTwoWayQuote(@Param('quoteId') quoteId: string) {
+    return this.rfq.getTwoWayQuote(quoteId);
+  }
+
+  @Post('quotes')
+  @UseGuards(MakerAuthGuard)
+  createTwoWayQuote(@Body() body: CreateTwoWayQuoteDto, @Req() req: Request) {
+    return this.rfq.createTwoWayQuote({ ...body, maker_id: (req as Request & { makerId: string }).makerId });
+  }
+
+  @Post('quotes/:quoteId/replace')
+  @UseGuards(MakerAuthGuard)
+  replaceTwoWayQuote(@Param('quoteId') quoteId: string, @Body() body: ReplaceTwoWayQuoteDto, @Req() req: Request) {
+    return this.rfq.replaceTwoWayQuote(quoteId, (req as Request & { makerId: string }).makerId, body);
+  }
+
+  @Delete('quotes/:quoteId')
+  @UseGuards(MakerAuthGuard)
+  @HttpCode(204)
+  cancelTwoWayQuote(@Param('quoteId') quoteId: string, @Req() req: Request) {
+    this.rfq.cancelTwoWayQuote(quoteId, (req as Request & { makerId: string }).makerId);
+  }
+
+  @Post('quotes/:quoteId/fill')
+  fillTwoWayQuote(@Param('quoteId') quoteId: string, @Body() body: FillTwoWayQuoteDto) {
+    return this.rfq.fillTwoWayQuote(quoteId, body.side, body.taker_address, body.amount);
+  }
+
+  @Get('makers')
+  listMakers() {
+    return this.makers.listMakers();
+  }
+
+  /**
+   * Maker onboarding. The wallet proof reuses the auth challenge flow: the
+   * maker signs a nonce from /api/auth/challenge with their wallet key, and
+   * a verified proof yields the maker's API key (returned only once).
+   */
+  @Post('makers/register')
+  async registerMaker(@Body() body: RegisterMakerDto) {
+    // Throws UnauthorizedException unless the signature verifies; the session
+    // itself is a harmless by-product of reusing the challenge flow.
+    await this.auth.createSession(body.wallet_address, body.nonce, body.signature, body.public_key);
+    try {
+      const { maker, api_key } = this.makers.register(body.maker_id, body.wallet_address, body.display_name, body.public_key);
+      return { maker: this.makers.toMeta(maker), api_key };
+    } catch (error) {
+      throw new BadRequestException(error instanceof Error ? error.message : 'Maker registration failed');
+    }
+  }
+
+  @Post('makers/:makerId/heartbeat')
+  heartbeat(@Param('makerId') makerId: string) {
+    return this.makers.heartbeat(makerId);
+  }
+
+  @Get('makers/:makerId/risk-limits')
+  riskLimits(@Param('makerId') makerId: string) {
+    return {
+      limits: this.makers.getRiskLimits(makerId),
+      exposure: this.rfq.exposure(makerId),
+    };
+  }
+
+  @Put('makers/:makerId/risk-limits')
+  setRiskLimits(@Param('makerId') makerId: string, @Body() body: MakerRiskLimitsDto) {
+    try {
+      this.makers.setRiskLimits(makerId, body);
+    } catch (error) {
+      throw new BadRequestException(error instanceof Error ? error.message : 'Invalid risk limits');
+    }
+    return {
+      limits: this.makers.getRiskLimits(makerId),
+      exposure: this.rfq.exposure(makerId),
+    };
+  }
+
+  @Post('makers/:makerId/webhook')
+  registerWebhook(@Param('makerId') makerId: string, @Body() body: RegisterWebhookDto) {
+    const { secret: _secret, ...webhook } = this.webhooks.register(makerId, body.url, body.secret);
+    return webhook;
+  }
+
+  @Delete('makers/:makerId/webhook')
+  @HttpCode(204)
+  unregisterWebhook(@Param('makerId') makerId: string) {
+    this.webhooks.unregister(makerId);
+  }
+
+  @Get('makers/:makerId/webhook/deliveries')
+  webhookDeliveries(@Param('makerId') makerId: string) {
+    return { deliveries: this.webhooks.getDeliveries(makerId) };
+  }
+}
@@ -0,0 +1,20 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+import { RfqService } from './rfq.service';
+import { RfqMakersService } from './rfq-makers.service';
+import { RfqWebhooksService } from './rfq-webhooks.service';
+import { RfqSignaturesService } from './rfq-signatures.service';
+import { RfqPricingService } from './rfq-pricing.service';
+import { MakerAuthGuard } from './maker-auth.guard';
+import { RfqController } from './rfq.controller';
+import { AuthModule } from '../auth/auth.module';
+import { PoolsModule } from '../pools/pools.module';
+import { KeetaModule } from '../keeta/keeta.module';
+
+@Module({
+  imports: [ConfigModule, AuthModule, PoolsModule, KeetaModule],
+  providers: [RfqService, RfqMakersService, RfqWebhooksService, RfqSignaturesService, RfqPricingService, MakerAuthGuard],
+  controllers: [RfqController],
+  exports: [RfqService, RfqMakersService],
+})
+export class RfqModule {}
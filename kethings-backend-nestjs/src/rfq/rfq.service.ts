@@ -0,0 +1,679 @@
+import { BadRequestException, ForbiddenException, Inject, Injectable, Logger, NotFoundException, OnModuleDestroy, OnModuleInit, forwardRef } from '@nestjs/common';
+import { randomUUID } from 'crypto';
+import { ConfigService } from '@nestjs/config';
+import { Subject } from 'rxjs';
+
+import { MakerExposure, RfqDeclaration, RfqFillRecord, RfqOrder, RfqSide, TwoWayFill, TwoWayQuote } from './rfq.types';
+import { RfqMakersService } from './rfq-makers.service';
+import { RfqWebhooksService } from './rfq-webhooks.service';
+import { RfqSignaturesService } from './rfq-signatures.service';
+import { RfqPricingService } from './rfq-pricing.service';
+import { KeetaSdkService } from '../keeta/keeta-sdk.service';
+
+const EXPIRY_SWEEP_INTERVAL_MS = 30_000;
+const DEFAULT_DECLARATION_TTL_MS = 120_000;
+
+export interface CreateRfqOrderInput {
+  id?: string;
+  pair: string;
+  side: RfqSide;
+  price: number;
+  size: number;
+  min_fill?: number;
+  expiry: string;
+  maker_id: string;
+  unsigned_block?: string;
+  maker_signature?: string;
+  storage_account?: string;
+  allowlisted?: boolean;
+}
+
+export interface TwoWayQuoteInput {
+  pair: string;
+  maker_id: string;
+  bid_price: number;
+  bid_size: number;
+  ask_price: number;
+  ask_size: number;
+  expiry: string;
+}
+
+export interface FillRequestResult {
+  order: RfqOrder;
+  status: 'initiated' | 'settled' | 'rejected';
+  latency_ms: number;
+}
+
+export interface RfqEvent {
+  type:
+    | 'order_created'
+    | 'order_cancelled'
+    | 'order_expired'
+    | 'order_filled'
+    | 'declaration_created'
+    | 'declaration_resolved'
+    | 'declaration_expired';
+  pair: string;
+  at: string;
+  data: Record<string, unknown>;
+}
+
+/**
+ * RFQ order, declaration and two-way quote store. All state is held on the
+ * injected service instance — never module-level — so handlers share one
+ * constructor-initialized store through DI and tests can stand up an
+ * isolated instance without global cleanup between cases.
+ */
+@Injectable()
+export class RfqService implements OnModuleInit, OnModuleDestroy {
+  private readonly logger = new Logger(RfqService.name);
+  private readonly orders = new Map<string, RfqOrder>();
+  private readonly declarations = new Map<string, RfqDeclaration[]>();
+  private readonly twoWayQuotes = new Map<string, TwoWayQuote>();
+  /** Live RFQ lifecycle events, fanned out on the `rfq:{pair}` WS channel. */
+  readonly events$ = new Subject<RfqEvent>();
+  private expirySweepTimer?: ReturnType<typeof setInterval>;
+
+  constructor(
+    private readonly config: ConfigService,
+    @Inject(forwardRef(() => RfqMakersService))
+    private readonly makers: RfqMakersService,
+    private readonly webhooks: RfqWebhooksService,
+    private readonly signatures: RfqSignaturesService,
+    private readonly pricing: RfqPricingService,
+    private readonly keeta: KeetaSdkService,
+  ) {}
+
+  onModuleInit(): void {
+    this.expirySweepTimer = setInterval(() => this.sweepExpiredOrders(), EXPIRY_SWEEP_INTERVAL_MS);
+  }
+
+  onModuleDestroy(): void {
+    if (this.expirySweepTimer) {
+      clearInterval(this.expirySweepTimer);
+    }
+  }
+
+  listOrders(pair?: string): RfqOrder[] {
+    const orders = Array.from(this.orders.values()).filter(
+      (order) => !order.suspended && (!pair || order.pair === pair),
+    );
+    for (const order of orders) {
+      order.off_market = this.pricing.check(order.pair, order.side, order.price).off_market;
+    }
+    return orders;
+  }
+
+  getOrder(orderId: string): RfqOrder {
+    const order = this.orders.get(orderId);
+    if (!order) {
+      throw new NotFoundException(`RFQ order ${orderId} not found`);
+    }
+    return order;
+  }
+
+  createOrder(input: CreateRfqOrderInput): RfqOrder {
+    const maker = this.makers.getOrRegister(input.maker_id);
+    this.verifyMakerSignature(input, maker.public_key);
+    this.assertWithinRiskLimits(input.maker_id, input.pair, input.price * input.size);
+    const now = new Date().toISOString();
+    const order: RfqOrder = {
+      id: input.id ?? randomUUID(),
+      pair: input.pair,
+      side: input.side,
+      price: input.price,
+      size: input.size,
+      remaining_size: input.size,
+      fills: [],
+      min_fill: input.min_fill,
+      expiry: input.expiry,
+      maker: this.makers.toMeta(maker),
+      unsigned_block: input.unsigned_block,
+      maker_signature: input.maker_signature,
+      storage_account: input.storage_account,
+      allowlisted: input.allowlisted ?? false,
+      status: 'open',
+      created_at: now,
+      updated_at: now,
+    };
+    this.orders.set(order.id, order);
+    this.emit('order_created', order.pair, {
+      order_id: order.id,
+      side: order.side,
+      price: order.price,
+      size: order.size,
+      maker_id: order.maker.id,
+    });
+    return order;
+  }
+
+  cancelOrder(orderId: string, makerId: string): void {
+    const order = this.getOrder(orderId);
+    this.assertOrderOwner(order, makerId);
+    order.status = 'cancelled';
+    order.updated_at = new Date().toISOString();
+    this.emit('order_cancelled', order.pair, { order_id: order.id });
+  }
+
+  requestFill(orderId: string, takerAddress: string | undefined, takerAmount: number, acceptOffMarket = false): FillRequestResult {
+    const order = this.getOrder(orderId);
+    if (order.status !== 'open' || order.suspended) {
+      throw new BadRequestException(`Order ${orderId} is not open for fills`);
+    }
+    this.assertFillAmount(order, takerAmount);
+    this.assertOnMarket(order, acceptOffMarket);
+    this.applyFill(order, takerAmount, takerAddress);
+    return { order, status: 'initiated', latency_ms: 0 };
+  }
+
+  /**
+   * Validate a fill against the unfilled remainder. min_fill is waived when
+   * the taker sweeps everything that is left, so a straggling tail below the
+   * minimum can still be cleared.
+   */
+  /** Maker-only actions must come from the maker that placed the order. */
+  private assertOrderOwner(order: RfqOrder, makerId: string): void {
+    if (order.maker.id !== makerId) {
+      throw new ForbiddenException({
+        code: 'MAKER_MISMATCH',
+        message: `Order ${order.id} belongs to maker ${order.maker.id}, not ${makerId}`,
+      });
+    }
+  }
+
+  private assertFillAmount(order: RfqOrder, amount: number): void {
+    if (!(amount > 0)) {
+      throw new BadRequestException(`Fill amount must be positive: ${amount}`);
+    }
+    if (amount > order.remaining_size) {
+      throw new BadRequestException(`Fill amount ${amount} exceeds remaining size ${order.remaining_size}`);
+    }
+    if (order.min_fill !== undefined && amount < order.min_fill && amount < order.remaining_size) {
+      throw new BadRequestException(`Fill amount ${amount} is below the order minimum ${order.min_fill}`);
+    }
+  }
+
+  /**
+   * Off-market orders can still be filled, but only with an explicit
+   * `accept_off_market` flag — the rejection carries the pool mid and the
+   * deviation so the taker can see exactly what they are agreeing to.
+   */
+  private assertOnMarket(order: RfqOrder, acceptOffMarket: boolean): void {
+    const check = this.pricing.check(order.pair, order.side, order.price);
+    order.off_market = check.off_market;
+    if (check.off_market && !acceptOffMarket) {
+      throw new BadRequestException({
+        code: 'OFF_MARKET_CONFIRMATION_REQUIRED',
+        message: `Order ${order.id} is priced ${check.deviation_pct}% worse than the pool mid ${check.pool_mid}; set accept_off_market to fill anyway`,
+        pool_mid: check.pool_mid,
+        deviation_pct: check.deviation_pct,
+        threshold_pct: check.threshold_pct,
+      });
+    }
+  }
+
+  /** Record a fill, keeping the order open until the remainder is exhausted. */
+  private applyFill(order: RfqOrder, amount: number, takerAddress?: string, declarationId?: string): RfqFillRecord {
+    const fill: RfqFillRecord = {
+      id: randomUUID(),
+      amount,
+      taker_address: takerAddress,
+      declaration_id: declarationId,
+      filled_at: new Date().toISOString(),
+    };
+    order.fills.push(fill);
+    order.remaining_size -= amount;
+    order.taker_fill_amount = amount;
+    order.taker_address = takerAddress;
+    order.updated_at = fill.filled_at;
+    if (order.remaining_size <= 0) {
+      order.remaining_size = 0;
+      order.status = 'filled';
+    }
+    this.makers.recordFillOutcome(order.maker.id, true);
+    this.webhooks.fire(order.maker.id, 'order.filled', {
+      order_id: order.id,
+      fill_id: fill.id,
+      fill_amount: amount,
+      remaining_size: order.remaining_size,
+      taker_address: takerAddress,
+      ...(declarationId ? { declaration_id: declarationId } : {}),
+    });
+    this.emit('order_filled', order.pair, {
+      order_id: order.id,
+      fill_id: fill.id,
+      amount,
+      remaining_size: order.remaining_size,
+      status: order.status,
+    });
+    return fill;
+  }
+
+  async declareIntention(
+    orderId: string,
+    takerAddress: string,
+    fillAmount: number,
+    unsignedAtomicSwapBlock?: string,
+    takerSignature?: string,
+    takerPublicKey?: string,
+    acceptOffMarket = false,
+  ): Promise<RfqDeclaration> {
+    const order = this.getOrder(orderId);
+    if (order.status !== 'open' || order.suspended) {
+      throw new BadRequestException(`Order ${orderId} is not accepting declarations`);
+    }
+    this.assertFillAmount(order, fillAmount);
+    this.assertOnMarket(order, acceptOffMarket);
+    await this.verifyTakerSignature(orderId, takerAddress, fillAmount, takerSignature, takerPublicKey);
+    if (unsignedAtomicSwapBlock !== undefined) {
+      this.signatures.assertSwapBlockMatches(unsignedAtomicSwapBlock, {
+        taker_address: takerAddress,
+        storage_account: order.storage_account,
+        amount: fillAmount,
+      });
+    }
+
+    const declaration: RfqDeclaration = {
+      id: randomUUID(),
+      order_id: orderId,
+      taker_address: takerAddress,
+      fill_amount: fillAmount,
+      declared_at: new Date().toISOString(),
+      status: 'pending',
+      unsigned_atomic_swap_block: unsignedAtomicSwapBlock,
+    };
+    const list = this.declarations.get(orderId) ?? [];
+    list.push(declaration);
+    this.declarations.set(orderId, list);
+    this.webhooks.fire(order.maker.id, 'declaration.created', {
+      order_id: orderId,
+      declaration_id: declaration.id,
+      taker_address: takerAddress,
+      fill_amount: fillAmount,
+    });
+    this.emit('declaration_created', order.pair, {
+      order_id: orderId,
+      declaration_id: declaration.id,
+      fill_amount: fillAmount,
+    });
+    return declaration;
+  }
+
+  listDeclarations(orderId: string): RfqDeclaration[] {
+    this.getOrder(orderId);
+    return this.declarations.get(orderId) ?? [];
+  }
+
+  resolveDeclaration(orderId: string, declarationId: string, approved: boolean, makerId: string): RfqDeclaration {
+    const order = this.getOrder(orderId);
+    this.assertOrderOwner(order, makerId);
+    const declaration = (this.declarations.get(orderId) ?? []).find((entry) => entry.id === declarationId);
+    if (!declaration) {
+      throw new NotFoundException(`Declaration ${declarationId} not found for order ${orderId}`);
+    }
+    if (declaration.status !== 'pending') {
+      throw new BadRequestException(`Declaration ${declarationId} has already been resolved`);
+    }
+
+    if (approved) {
+      if (order.status !== 'open' || order.suspended) {
+        throw new BadRequestException(`Order ${orderId} is no longer accepting fills`);
+      }
+      // Re-check against the current remainder: other declarations may have
+      // been approved since this one was created.
+      this.assertFillAmount(order, declaration.fill_amount);
+      // Last line of defence before the swap executes: the block must still
+      // reference the declaring taker and the approved amount.
+      if (declaration.unsigned_atomic_swap_block !== undefined) {
+        this.signatures.assertSwapBlockMatches(declaration.unsigned_atomic_swap_block, {
+          taker_address: declaration.taker_address,
+          storage_account: order.storage_account,
+          amount: declaration.fill_amount,
+        });
+      }
+      // Only now is the fill known to apply; flipping the status any earlier
+      // would strand the declaration at 'approved' if a re-check threw,
+      // unresolvable behind the pending-only guard above.
+      declaration.status = 'approved';
+      this.applyFill(order, declaration.fill_amount, declaration.taker_address, declaration.id);
+    } else {
+      declaration.status = 'rejected';
+    }
+    this.emit('declaration_resolved', order.pair, {
+      order_id: orderId,
+      declaration_id: declaration.id,
+      status: declaration.status,
+    });
+    return declaration;
+  }
+
+  listTwoWayQuotes(pair?: string): TwoWayQuote[] {
+    return Array.from(this.twoWayQuotes.values()).filter(
+      (quote) => quote.status === 'open' && (!pair || quote.pair === pair),
+    );
+  }
+
+  getTwoWayQuote(quoteId: string): TwoWayQuote {
+    const quote = this.twoWayQuotes.get(quoteId);
+    if (!quote) {
+      throw new NotFoundException(`Two-way quote ${quoteId} not found`);
+    }
+    return quote;
+  }
+
+  createTwoWayQuote(input: TwoWayQuoteInput): TwoWayQuote {
+    this.assertUncrossed(input.bid_price, input.ask_price);
+    this.assertWithinRiskLimits(
+      input.maker_id,
+      input.pair,
+      input.bid_price * input.bid_size + input.ask_price * input.ask_size,
+    );
+    const maker = this.makers.getOrRegister(input.maker_id);
+    const now = new Date().toISOString();
+    const quote: TwoWayQuote = {
+      id: randomUUID(),
+      pair: input.pair,
+      maker: this.makers.toMeta(maker),
+      bid: { price: input.bid_price, size: input.bid_size },
+      ask: { price: input.ask_price, size: input.ask_size },
+      expiry: input.expiry,
+      status: 'open',
+      version: 1,
+      created_at: now,
+      updated_at: now,
+    };
+    this.twoWayQuotes.set(quote.id, quote);
+    return quote;
+  }
+
+  /**
+   * Atomic reprice: both sides swap in one step and the version bumps, so a
+   * taker can never observe the new bid against the old ask.
+   */
+  replaceTwoWayQuote(quoteId: string, makerId: string, input: Omit<TwoWayQuoteInput, 'pair' | 'maker_id'>): TwoWayQuote {
+    const quote = this.getTwoWayQuote(quoteId);
+    if (quote.maker.id !== makerId) {
+      throw new BadRequestException(`Quote ${quoteId} does not belong to maker ${makerId}`);
+    }
+    if (quote.status !== 'open') {
+      throw new BadRequestException(`Quote ${quoteId} is ${quote.status} and cannot be replaced`);
+    }
+    this.assertUncrossed(input.bid_price, input.ask_price);
+    const oldNotional = quote.bid.price * quote.bid.size + quote.ask.price * quote.ask.size;
+    const newNotional = input.bid_price * input.bid_size + input.ask_price * input.ask_size;
+    this.assertWithinRiskLimits(makerId, quote.pair, newNotional - oldNotional);
+    quote.bid = { price: input.bid_price, size: input.bid_size };
+    quote.ask = { price: input.ask_price, size: input.ask_size };
+    quote.expiry = input.expiry;
+    quote.version += 1;
+    quote.updated_at = new Date().toISOString();
+    return quote;
+  }
+
+  cancelTwoWayQuote(quoteId: string, makerId: string): void {
+    const quote = this.getTwoWayQuote(quoteId);
+    if (quote.maker.id !== makerId) {
+      throw new BadRequestException(`Quote ${quoteId} does not belong to maker ${makerId}`);
+    }
+    quote.status = 'cancelled';
+    quote.updated_at = new Date().toISOString();
+  }
+
+  /** Taker hits the bid (sells base to the maker) or lifts the ask. */
+  fillTwoWayQuote(quoteId: string, side: 'bid' | 'ask', takerAddress: string, amount: number): TwoWayFill {
+    const quote = this.getTwoWayQuote(quoteId);
+    if (quote.status !== 'open') {
+      throw new BadRequestException(`Quote ${quoteId} is not open`);
+    }
+    const expiry = Date.parse(quote.expiry);
+    if (Number.isFinite(expiry) && expiry < Date.now()) {
+      quote.status = 'expired';
+      quote.updated_at = new Date().toISOString();
+      throw new BadRequestException(`Quote ${quoteId} has expired`);
+    }
+    const quoteSide = side === 'bid' ? quote.bid : quote.ask;
+    if (!(amount > 0) || amount > quoteSide.size) {
+      throw new BadRequestException(`Fill amount ${amount} exceeds remaining ${side} size ${quoteSide.size}`);
+    }
+
+    quoteSide.size -= amount;
+    quote.updated_at = new Date().toISOString();
+    if (quote.bid.size <= 0 && quote.ask.size <= 0) {
+      quote.status = 'exhausted';
+    }
+    this.webhooks.fire(quote.maker.id, 'quote.filled', {
+      quote_id: quote.id,
+      side,
+      amount,
+      taker_address: takerAddress,
+      version: quote.version,
+    });
+    return {
+      quote_id: quote.id,
+      side,
+      price: quoteSide.price,
+      amount,
+      taker_address: takerAddress,
+      filled_at: quote.updated_at,
+      quote_version: quote.version,
+    };
+  }
+
+  /**
+   * Open notional per pair for a maker: live one-way orders at price × size
+   * plus both sides of open two-way quotes. Used for risk-limit enforcement
+   * and surfaced to makers alongside rejections.
+   */
+  exposure(makerId: string): MakerExposure {
+    const perPair: Record<string, number> = {};
+    const add = (pair: string, notional: number) => {
+      perPair[pair] = (perPair[pair] ?? 0) + notional;
+    };
+    for (const order of this.orders.values()) {
+      if (order.maker.id !== makerId || order.status !== 'open') continue;
+      add(order.pair, order.price * order.remaining_size);
+    }
+    for (const quote of this.twoWayQuotes.values()) {
+      if (quote.maker.id !== makerId || quote.status !== 'open') continue;
+      add(quote.pair, quote.bid.price * quote.bid.size + quote.ask.price * quote.ask.size);
+    }
+    return {
+      maker_id: makerId,
+      open_notional: Object.values(perPair).reduce((sum, notional) => sum + notional, 0),
+      per_pair: perPair,
+    };
+  }
+
+  /**
+   * Reject additions that would push the maker past their configured caps.
+   * The rejection carries the current exposure so the maker can see exactly
+   * how much headroom remains — the fat-finger guard the limits exist for.
+   */
+  private assertWithinRiskLimits(makerId: string, pair: string, additionalNotional: number): void {
+    const limits = this.makers.getRiskLimits(makerId);
+    if (limits.max_open_notional === undefined && !limits.per_pair_notional) {
+      return;
+    }
+    const exposure = this.exposure(makerId);
+    if (
+      limits.max_open_notional !== undefined &&
+      exposure.open_notional + additionalNotional > limits.max_open_notional
+    ) {
+      throw new BadRequestException({
+        code: 'RISK_LIMIT_EXCEEDED',
+        message: `Open notional ${exposure.open_notional} + ${additionalNotional} exceeds limit ${limits.max_open_notional}`,
+        current_exposure: exposure,
+        limits,
+      });
+    }
+    const pairCap = limits.per_pair_notional?.[pair];
+    if (pairCap !== undefined && (exposure.per_pair[pair] ?? 0) + additionalNotional > pairCap) {
+      throw new BadRequestException({
+        code: 'RISK_LIMIT_EXCEEDED',
+        message: `Exposure on ${pair} (${exposure.per_pair[pair] ?? 0} + ${additionalNotional}) exceeds pair cap ${pairCap}`,
+        current_exposure: exposure,
+        limits,
+      });
+    }
+  }
+
+  /**
+   * Maker signatures verify against the public key captured at onboarding.
+   * Unsigned orders pass unless RFQ_REQUIRE_SIGNATURES is set; a supplied
+   * signature is always checked and a bad one always rejects.
+   */
+  private verifyMakerSignature(input: CreateRfqOrderInput, makerPublicKey?: string): void {
+    if (input.maker_signature === undefined) {
+      if (this.signatures.required()) {
+        throw new BadRequestException({
+          code: 'MAKER_SIGNATURE_REQUIRED',
+          message: 'maker_signature over the canonical order payload is required',
+        });
+      }
+      return;
+    }
+    if (!makerPublicKey) {
+      throw new BadRequestException({
+        code: 'MAKER_KEY_UNKNOWN',
+        message: `Maker ${input.maker_id} has no registered public key to verify against`,
+      });
+    }
+    const payload = this.signatures.orderPayload({
+      pair: input.pair,
+      side: input.side,
+      price: input.price,
+      size: input.size,
+      min_fill: input.min_fill,
+      expiry: input.expiry,
+      maker_id: input.maker_id,
+    });
+    this.signatures.assertValidSignature(payload, input.maker_signature, makerPublicKey, 'Maker');
+  }
+
+  /**
+   * Takers have no onboarding step to register a key against, so the binding
+   * comes from the address itself: the supplied key must be the one encoded
+   * in taker_address, mirroring how maker signatures verify against the key
+   * captured at registration.
+   */
+  private async verifyTakerSignature(
+    orderId: string,
+    takerAddress: string,
+    fillAmount: number,
+    signature?: string,
+    publicKey?: string,
+  ): Promise<void> {
+    if (signature === undefined) {
+      if (this.signatures.required()) {
+        throw new BadRequestException({
+          code: 'TAKER_SIGNATURE_REQUIRED',
+          message: 'taker_signature over the canonical declaration payload is required',
+        });
+      }
+      return;
+    }
+    if (!publicKey) {
+      throw new BadRequestException({
+        code: 'TAKER_KEY_REQUIRED',
+        message: 'taker_public_key is required alongside taker_signature',
+      });
+    }
+    const payload = this.signatures.declarationPayload(orderId, takerAddress, fillAmount);
+    this.signatures.assertValidSignature(payload, signature, publicKey, 'Taker');
+    let keyBound = false;
+    try {
+      keyBound = await this.keeta.addressMatchesPublicKey(takerAddress, publicKey);
+    } catch (error) {
+      this.logger.warn(
+        `Taker key binding check failed for ${takerAddress}: ${error instanceof Error ? error.message : 'unknown error'}`,
+      );
+    }
+    if (!keyBound) {
+      throw new BadRequestException({
+        code: 'TAKER_KEY_MISMATCH',
+        message: 'taker_public_key is not the key for taker_address',
+      });
+    }
+  }
+
+  private emit(type: RfqEvent['type'], pair: string, data: Record<string, unknown>): void {
+    this.events$.next({ type, pair, at: new Date().toISOString(), data });
+  }
+
+  private assertUncrossed(bidPrice: number, askPrice: number): void {
+    if (!(bidPrice < askPrice)) {
+      throw new BadRequestException(`Quote is crossed: bid ${bidPrice} must be below ask ${askPrice}`);
+    }
+  }
+
+  private sweepExpiredOrders(): void {
+    const now = Date.now();
+    for (const order of this.orders.values()) {
+      if (order.status !== 'open') continue;
+      const expiry = Date.parse(order.expiry);
+      if (Number.isFinite(expiry) && expiry < now) {
+        order.status = 'expired';
+        order.updated_at = new Date().toISOString();
+        this.webhooks.fire(order.maker.id, 'order.expired', { order_id: order.id, pair: order.pair });
+        this.emit('order_expired', order.pair, { order_id: order.id });
+      }
+    }
+    for (const quote of this.twoWayQuotes.values()) {
+      if (quote.status !== 'open') continue;
+      const expiry = Date.parse(quote.expiry);
+      if (Number.isFinite(expiry) && expiry < now) {
+        quote.status = 'expired';
+        quote.updated_at = new Date().toISOString();
+        this.webhooks.fire(quote.maker.id, 'quote.expired', { quote_id: quote.id, pair: quote.pair });
+      }
+    }
+    this.sweepStaleDeclarations(now);
+  }
+
+  /**
+   * Auto-expire declarations the maker has ignored past the TTL, so a
+   * silent maker cannot strand a taker in `pending` forever. The expiry is
+   * pushed on the order's rfq channel for the waiting taker, counted as an
+   * SLA miss against the maker, and the order stays open to other takers.
+   */
+  private sweepStaleDeclarations(now: number): void {
+    const ttlMs = Number(this.config.get<string>('RFQ_DECLARATION_TTL_MS')) || DEFAULT_DECLARATION_TTL_MS;
+    for (const [orderId, list] of this.declarations) {
+      const order = this.orders.get(orderId);
+      if (!order) continue;
+      for (const declaration of list) {
+        if (declaration.status !== 'pending') continue;
+        if (Date.parse(declaration.declared_at) + ttlMs >= now) continue;
+        declaration.status = 'expired';
+        this.makers.recordSlaMiss(order.maker.id);
+        this.webhooks.fire(order.maker.id, 'declaration.expired', {
+          order_id: orderId,
+          declaration_id: declaration.id,
+          taker_address: declaration.taker_address,
+        });
+        this.emit('declaration_expired', order.pair, {
+          order_id: orderId,
+          declaration_id: declaration.id,
+          taker_address: declaration.taker_address,
+        });
+        this.logger.log(`Expired stale declaration ${declaration.id} on order ${orderId}`);
+      }
+    }
+  }
+
+  /** Hide/show all of a maker's open orders, driven by liveness tracking. */
+  setMakerOrdersSuspended(makerId: string, suspended: boolean): number {
+    let changed = 0;
+    for (const order of this.orders.values()) {
+      if (order.maker.id !== makerId || order.status !== 'open') continue;
+      if ((order.suspended ?? false) === suspended) continue;
+      order.suspended = suspended;
+      order.updated_at = new Date().toISOString();
+      changed += 1;
+    }
+    if (changed > 0) {
+      this.logger.log(`${suspended ? 'Suspended' : 'Resumed'} ${changed} open orders for maker ${makerId}`);
+    }
+    return changed;
+  }
+}
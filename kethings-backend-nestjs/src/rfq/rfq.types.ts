@@ -0,0 +1,121 @@
+export type RfqSide = 'buy' | 'sell';
+
+export type RfqOrderStatus = 'open' | 'pending_fill' | 'filled' | 'expired' | 'cancelled' | 'failed';
+
+export type RfqDeclarationStatus = 'pending' | 'approved' | 'rejected' | 'expired';
+
+export interface RfqMakerMeta {
+  id: string;
+  display_name: string;
+  verified: boolean;
+  reputation_score: number;
+  auto_sign_sla_ms: number;
+  fills_completed: number;
+  failure_rate: number;
+  allowlist_label?: string;
+}
+
+export interface MakerRiskLimits {
+  /** Cap on total open notional across all pairs, in quote terms. */
+  max_open_notional?: number;
+  /** Per-pair notional caps, keyed by pair symbol. */
+  per_pair_notional?: Record<string, number>;
+}
+
+export interface MakerExposure {
+  maker_id: string;
+  open_notional: number;
+  per_pair: Record<string, number>;
+}
+
+export interface RfqMaker extends RfqMakerMeta {
+  online: boolean;
+  suspended: boolean;
+  last_heartbeat_at?: string;
+  missed_sla_count: number;
+  risk_limits?: MakerRiskLimits;
+  /** Set once the maker has onboarded with a wallet proof. */
+  wallet_address?: string;
+  /** ed25519 public key (base64) the maker signs order payloads with. */
+  public_key?: string;
+  registered_at?: string;
+}
+
+export interface RfqFillRecord {
+  id: string;
+  amount: number;
+  taker_address?: string;
+  declaration_id?: string;
+  filled_at: string;
+}
+
+export interface RfqOrder {
+  id: string;
+  pair: string;
+  side: RfqSide;
+  price: number;
+  size: number;
+  /** Unfilled size; the order stays open for further fills until this hits zero. */
+  remaining_size: number;
+  fills: RfqFillRecord[];
+  min_fill?: number;
+  expiry: string;
+  maker: RfqMakerMeta;
+  unsigned_block?: string;
+  maker_signature?: string;
+  storage_account?: string;
+  allowlisted: boolean;
+  status: RfqOrderStatus;
+  taker_fill_amount?: number;
+  taker_address?: string;
+  created_at: string;
+  updated_at: string;
+  /** Internal flag: order hidden from takers while the maker is offline. */
+  suspended?: boolean;
+  /** Recomputed against the current pool mid whenever the order is listed. */
+  off_market?: boolean;
+}
+
+export type TwoWayQuoteStatus = 'open' | 'cancelled' | 'expired' | 'exhausted';
+
+export interface QuoteSide {
+  price: number;
+  /** Remaining size on this side; fills draw it down independently. */
+  size: number;
+}
+
+export interface TwoWayQuote {
+  id: string;
+  pair: string;
+  maker: RfqMakerMeta;
+  /** Maker's buy side — takers hit it to sell the base. */
+  bid: QuoteSide;
+  /** Maker's sell side — takers lift it to buy the base. */
+  ask: QuoteSide;
+  expiry: string;
+  status: TwoWayQuoteStatus;
+  /** Bumped on each atomic replace so takers can detect repricing. */
+  version: number;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface TwoWayFill {
+  quote_id: string;
+  side: 'bid' | 'ask';
+  price: number;
+  amount: number;
+  taker_address: string;
+  filled_at: string;
+  quote_version: number;
+}
+
+export interface RfqDeclaration {
+  id: string;
+  order_id: string;
+  taker_address: string;
+  fill_amount: number;
+  declared_at: string;
+  status: RfqDeclarationStatus;
+  unsigned_atomic_swap_block?: string;
+}
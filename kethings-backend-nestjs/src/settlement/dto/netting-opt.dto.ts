@@ -0,0 +1,6 @@
+import { IsString } from 'class-validator';
+
+export class NettingOptDto {
+  @IsString()
+  user_address!: string;
+}
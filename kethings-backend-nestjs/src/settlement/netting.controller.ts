@@ -0,0 +1,37 @@
+import { Body, Controller, Get, HttpCode, Post, Query } from '@nestjs/common';
+
+import { NettingService } from './netting.service';
+import { NettingOptDto } from './dto/netting-opt.dto';
+
+@Controller('settlement/netting')
+export class NettingController {
+  constructor(private readonly netting: NettingService) {}
+
+  @Post('opt-in')
+  @HttpCode(204)
+  optIn(@Body() body: NettingOptDto) {
+    this.netting.optIn(body.user_address);
+  }
+
+  @Post('opt-out')
+  @HttpCode(204)
+  optOut(@Body() body: NettingOptDto) {
+    this.netting.optOut(body.user_address);
+  }
+
+  @Get('status')
+  status() {
+    return this.netting.status();
+  }
+
+  @Get('gross')
+  gross(@Query('limit') limit?: string) {
+    const n = Number(limit);
+    return { records: this.netting.listGrossRecords(Number.isFinite(n) ? n : undefined) };
+  }
+
+  @Post('flush')
+  flush() {
+    return this.netting.flush();
+  }
+}
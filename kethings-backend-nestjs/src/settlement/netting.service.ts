@@ -0,0 +1,155 @@
+import { Injectable, Logger, OnModuleDestroy, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { randomUUID } from 'crypto';
+
+import { SettlementQueueService } from './settlement-queue.service';
+
+export interface GrossObligation {
+  id: string;
+  from: string;
+  to: string;
+  token: string;
+  amount: number;
+  reference: string;
+  recorded_at: string;
+}
+
+export interface NettingStatus {
+  opted_in: string[];
+  pending_obligations: number;
+  gross_records: number;
+  window_ms: number;
+}
+
+const DEFAULT_WINDOW_MS = 30_000;
+const GROSS_RECORD_LIMIT = 10_000;
+
+/**
+ * Opt-in settlement netting between frequent counterparties. Obligations
+ * between two opted-in users accumulate over a short window instead of
+ * settling one on-chain transfer per fill; at the window boundary the net
+ * flow per counterparty pair per token is enqueued as a single transfer.
+ * Gross obligations are always retained so auditors can reconcile every net
+ * amount back to the individual fills that produced it.
+ */
+@Injectable()
+export class NettingService implements OnModuleInit, OnModuleDestroy {
+  private readonly logger = new Logger(NettingService.name);
+  private readonly optedIn = new Set<string>();
+  private pending: GrossObligation[] = [];
+  private readonly grossRecords: GrossObligation[] = [];
+  private timer?: ReturnType<typeof setInterval>;
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly settlementQueue: SettlementQueueService,
+  ) {}
+
+  onModuleInit(): void {
+    this.timer = setInterval(() => this.flush(), this.windowMs());
+  }
+
+  onModuleDestroy(): void {
+    if (this.timer) {
+      clearInterval(this.timer);
+    }
+  }
+
+  optIn(user: string): void {
+    this.optedIn.add(user);
+  }
+
+  optOut(user: string): void {
+    this.optedIn.delete(user);
+  }
+
+  /**
+   * Record the two legs of a fill as netting obligations. A no-op unless
+   * both counterparties opted in — users outside the scheme keep the
+   * internal-ledger-only settlement they have today.
+   */
+  recordTradeObligations(buyer: string, seller: string, base: string, quote: string, price: number, quantity: number, reference: string): void {
+    if (!this.optedIn.has(buyer) || !this.optedIn.has(seller)) {
+      return;
+    }
+    this.addObligation(seller, buyer, base, quantity, reference);
+    this.addObligation(buyer, seller, quote, price * quantity, reference);
+  }
+
+  /** Net all pending obligations and enqueue one transfer per pair/token. */
+  flush(): { netted: number; transfers: number } {
+    const window = this.pending;
+    if (window.length === 0) {
+      return { netted: 0, transfers: 0 };
+    }
+    this.pending = [];
+
+    // Net per unordered pair per token; sign tracks flow relative to the
+    // lexicographically first party so opposing legs cancel.
+    const nets = new Map<string, { a: string; b: string; token: string; net: number; count: number }>();
+    for (const obligation of window) {
+      const [a, b] = [obligation.from, obligation.to].sort();
+      const key = `${a}|${b}|${obligation.token}`;
+      let entry = nets.get(key);
+      if (!entry) {
+        entry = { a, b, token: obligation.token, net: 0, count: 0 };
+        nets.set(key, entry);
+      }
+      entry.net += obligation.from === a ? obligation.amount : -obligation.amount;
+      entry.count += 1;
+    }
+
+    let transfers = 0;
+    for (const entry of nets.values()) {
+      if (Math.abs(entry.net) < 1e-12) {
+        continue;
+      }
+      const from = entry.net > 0 ? entry.a : entry.b;
+      const to = entry.net > 0 ? entry.b : entry.a;
+      this.settlementQueue.enqueue('net_transfer', {
+        from,
+        to,
+        token: entry.token,
+        amount: Math.abs(entry.net).toString(),
+        gross_legs: entry.count,
+      });
+      transfers += 1;
+    }
+    this.logger.log(`Netted ${window.length} obligations into ${transfers} transfers`);
+    return { netted: window.length, transfers };
+  }
+
+  status(): NettingStatus {
+    return {
+      opted_in: Array.from(this.optedIn),
+      pending_obligations: this.pending.length,
+      gross_records: this.grossRecords.length,
+      window_ms: this.windowMs(),
+    };
+  }
+
+  listGrossRecords(limit = 100): GrossObligation[] {
+    return this.grossRecords.slice(-Math.max(1, Math.min(1000, limit))).reverse();
+  }
+
+  private addObligation(from: string, to: string, token: string, amount: number, reference: string): void {
+    const obligation: GrossObligation = {
+      id: randomUUID(),
+      from,
+      to,
+      token,
+      amount,
+      reference,
+      recorded_at: new Date().toISOString(),
+    };
+    this.pending.push(obligation);
+    this.grossRecords.push(obligation);
+    if (this.grossRecords.length > GROSS_RECORD_LIMIT) {
+      this.grossRecords.splice(0, this.grossRecords.length - GROSS_RECORD_LIMIT);
+    }
+  }
+
+  private windowMs(): number {
+    return Number(this.config.get<string>('NETTING_WINDOW_MS')) || DEFAULT_WINDOW_MS;
+  }
+}
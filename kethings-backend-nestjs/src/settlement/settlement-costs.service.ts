@@ -0,0 +1,82 @@
+import { Injectable } from '@nestjs/common';
+
+export interface SettlementCostRecord {
+  operation: string;
+  network_fee: number;
+  pool_id?: string;
+  user_address?: string;
+  recorded_at: string;
+}
+
+export interface CostSummaryLine {
+  key: string;
+  operations: number;
+  total_network_fees: string;
+}
+
+const MAX_RECORDS = 10_000;
+
+/**
+ * Tracks network fees incurred by settlement operations and attributes them
+ * to the pool or user that triggered them, so fee schedules can be calibrated
+ * against real settlement costs.
+ */
+@Injectable()
+export class SettlementCostsService {
+  private readonly records: SettlementCostRecord[] = [];
+
+  recordCost(input: Omit<SettlementCostRecord, 'recorded_at'>): SettlementCostRecord {
+    const record: SettlementCostRecord = { ...input, recorded_at: new Date().toISOString() };
+    this.records.push(record);
+    if (this.records.length > MAX_RECORDS) {
+      this.records.splice(0, this.records.length - MAX_RECORDS);
+    }
+    return record;
+  }
+
+  recordCount(): number {
+    return this.records.length;
+  }
+
+  /** Remove and return records older than the cutoff, for retention pruning. */
+  pruneBefore(cutoff: string): SettlementCostRecord[] {
+    const pruned = this.records.filter((record) => record.recorded_at < cutoff);
+    if (pruned.length > 0) {
+      const kept = this.records.filter((record) => record.recorded_at >= cutoff);
+      this.records.length = 0;
+      this.records.push(...kept);
+    }
+    return pruned;
+  }
+
+  summarizeByPool(): CostSummaryLine[] {
+    return this.summarize((record) => record.pool_id);
+  }
+
+  summarizeByUser(): CostSummaryLine[] {
+    return this.summarize((record) => record.user_address);
+  }
+
+  totalForPool(poolId: string): number {
+    return this.records
+      .filter((record) => record.pool_id === poolId)
+      .reduce((sum, record) => sum + record.network_fee, 0);
+  }
+
+  private summarize(keyOf: (record: SettlementCostRecord) => string | undefined): CostSummaryLine[] {
+    const byKey = new Map<string, { operations: number; total: number }>();
+    for (const record of this.records) {
+      const key = keyOf(record);
+      if (!key) continue;
+      const entry = byKey.get(key) ?? { operations: 0, total: 0 };
+      entry.operations += 1;
+      entry.total += record.network_fee;
+      byKey.set(key, entry);
+    }
+    return Array.from(byKey.entries()).map(([key, entry]) => ({
+      key,
+      operations: entry.operations,
+      total_network_fees: entry.total.toString(),
+    }));
+  }
+}
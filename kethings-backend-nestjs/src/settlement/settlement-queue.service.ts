@@ -0,0 +1,295 @@
+import { Injectable, Logger, NotFoundException, OnModuleInit, ServiceUnavailableException } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { randomUUID } from 'crypto';
+import { appendFileSync, existsSync, mkdirSync, readFileSync } from 'fs';
+import { dirname } from 'path';
+import { Subject } from 'rxjs';
+
+import { AuditHashService } from '../audit/audit-hash.service';
+import { AuditLogService } from '../audit/audit-log.service';
+
+export type SettlementOpKind = 'withdraw' | 'pool_deposit' | 'pool_withdraw' | 'net_transfer';
+export type SettlementOpStatus = 'pending' | 'in_flight' | 'complete' | 'failed';
+
+export interface SettlementOp {
+  id: string;
+  kind: SettlementOpKind;
+  payload: Record<string, unknown>;
+  status: SettlementOpStatus;
+  /** Priority tip paid by the user; tipped ops jump the settlement lanes. */
+  tip?: string;
+  enqueued_at: string;
+  completed_at?: string;
+  /** On-chain transaction reference recorded at confirmation. */
+  tx_ref?: string;
+  failure_reason?: string;
+}
+
+export type JournalEntry =
+  | { type: 'enqueue'; op: SettlementOp }
+  | { type: 'in_flight'; id: string }
+  | { type: 'complete'; id: string; at: string; tx_ref?: string }
+  | { type: 'failed'; id: string; reason: string };
+
+export interface SettlementResult {
+  op: SettlementOp;
+  outcome: 'complete' | 'failed';
+}
+
+const DEFAULT_JOURNAL_PATH = 'data/settlement-journal.log';
+const DEFAULT_QUEUE_CAPACITY = 1_000;
+
+/**
+ * Durable settlement queue: every op is appended to a write-ahead journal on
+ * enqueue and on each state transition, and the journal is replayed on
+ * startup so in-flight Withdraw/PoolDeposit/PoolWithdraw ops survive process
+ * crashes. Ops are only marked complete after on-chain confirmation.
+ */
+@Injectable()
+export class SettlementQueueService implements OnModuleInit {
+  private readonly logger = new Logger(SettlementQueueService.name);
+  private readonly ops = new Map<string, SettlementOp>();
+  /** Settlement-result channel: one event per confirmed or failed op, so
+   * upstream owners (pools, withdrawals) can react without polling. */
+  readonly results$ = new Subject<SettlementResult>();
+  private journalPath = DEFAULT_JOURNAL_PATH;
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly audit: AuditHashService,
+    private readonly auditLog: AuditLogService,
+  ) {}
+
+  onModuleInit(): void {
+    this.journalPath = this.config.get<string>('SETTLEMENT_JOURNAL_PATH') || DEFAULT_JOURNAL_PATH;
+    this.replay();
+  }
+
+  enqueue(kind: SettlementOpKind, payload: Record<string, unknown>, tip?: number): SettlementOp {
+    // Bounded queue: refusing new work here is the backpressure signal —
+    // callers surface it to users instead of letting the backlog grow
+    // without limit while settlement is degraded.
+    const capacity = Number(this.config.get<string>('SETTLEMENT_QUEUE_CAPACITY')) || DEFAULT_QUEUE_CAPACITY;
+    if (this.pendingOps().length >= capacity) {
+      throw new ServiceUnavailableException({
+        code: 'SETTLEMENT_QUEUE_FULL',
+        message: `Settlement queue is at capacity (${capacity} pending ops); retry shortly`,
+      });
+    }
+    const op: SettlementOp = {
+      id: randomUUID(),
+      kind,
+      payload,
+      status: 'pending',
+      ...(tip !== undefined && tip > 0 ? { tip: tip.toString() } : {}),
+      enqueued_at: new Date().toISOString(),
+    };
+    this.ops.set(op.id, op);
+    this.journal({ type: 'enqueue', op });
+    this.auditLog.record({
+      category: 'settlement',
+      action: 'op_enqueued',
+      actor: typeof payload.wallet_address === 'string' ? payload.wallet_address : undefined,
+      subject: op.id,
+      after: { kind, status: op.status },
+    });
+    return op;
+  }
+
+  markInFlight(opId: string): SettlementOp {
+    const op = this.getOp(opId);
+    const previous = op.status;
+    op.status = 'in_flight';
+    this.journal({ type: 'in_flight', id: opId });
+    this.auditLog.record({
+      category: 'settlement',
+      action: 'op_in_flight',
+      subject: opId,
+      before: { status: previous },
+      after: { status: op.status },
+    });
+    return op;
+  }
+
+  /** Called once on-chain confirmation for the op has been observed. */
+  confirm(opId: string, txRef?: string): SettlementOp {
+    const op = this.getOp(opId);
+    const previous = op.status;
+    op.status = 'complete';
+    op.completed_at = new Date().toISOString();
+    if (txRef) {
+      op.tx_ref = txRef;
+    }
+    this.journal({ type: 'complete', id: opId, at: op.completed_at, tx_ref: txRef });
+    this.auditLog.record({
+      category: 'settlement',
+      action: 'op_completed',
+      subject: opId,
+      before: { status: previous },
+      after: { status: op.status, tx_ref: txRef },
+    });
+    this.results$.next({ op, outcome: 'complete' });
+    return op;
+  }
+
+  fail(opId: string, reason: string): SettlementOp {
+    const op = this.getOp(opId);
+    const previous = op.status;
+    op.status = 'failed';
+    op.failure_reason = reason;
+    this.journal({ type: 'failed', id: opId, reason });
+    this.auditLog.record({
+      category: 'settlement',
+      action: 'op_failed',
+      subject: opId,
+      before: { status: previous },
+      after: { status: op.status, reason },
+    });
+    this.results$.next({ op, outcome: 'failed' });
+    return op;
+  }
+
+  getOpById(opId: string): SettlementOp {
+    return this.getOp(opId);
+  }
+
+  listOps(status?: SettlementOpStatus): SettlementOp[] {
+    const all = Array.from(this.ops.values());
+    return status ? all.filter((op) => op.status === status) : all;
+  }
+
+  /** A wallet's settlement ops, optionally narrowed to one pool, newest first. */
+  listByWallet(walletAddress: string, poolId?: string): SettlementOp[] {
+    return Array.from(this.ops.values())
+      .filter(
+        (op) =>
+          op.payload.wallet_address === walletAddress && (poolId === undefined || op.payload.pool_id === poolId),
+      )
+      .sort((a, b) => b.enqueued_at.localeCompare(a.enqueued_at));
+  }
+
+  pendingOps(): SettlementOp[] {
+    return this.listOps().filter((op) => op.status === 'pending' || op.status === 'in_flight');
+  }
+
+  /**
+   * Pending ops split into settlement lanes: tipped ops first, highest tip
+   * winning, ties and the standard lane in FIFO order. Workers should drain
+   * the priority lane before touching the standard one.
+   */
+  lanes(): { priority: SettlementOp[]; standard: SettlementOp[] } {
+    const pending = this.listOps('pending');
+    const priority = pending
+      .filter((op) => op.tip !== undefined)
+      .sort((a, b) => Number(b.tip) - Number(a.tip) || a.enqueued_at.localeCompare(b.enqueued_at));
+    const standard = pending.filter((op) => op.tip === undefined);
+    return { priority, standard };
+  }
+
+  /**
+   * Parse the on-disk journal as an event log, skipping corrupt lines. Used
+   * by integrity checks that need the full transition history rather than
+   * the collapsed per-op state in the ops map.
+   */
+  readJournal(): JournalEntry[] {
+    if (!existsSync(this.journalPath)) {
+      return [];
+    }
+    const entries: JournalEntry[] = [];
+    try {
+      for (const line of readFileSync(this.journalPath, 'utf8').split('\n').filter(Boolean)) {
+        try {
+          entries.push(JSON.parse(line) as JournalEntry);
+        } catch {
+          this.logger.warn(`Skipping corrupt settlement journal line`);
+        }
+      }
+    } catch (error) {
+      this.logger.error(`Failed to read settlement journal at ${this.journalPath}`, error);
+    }
+    return entries;
+  }
+
+  private getOp(opId: string): SettlementOp {
+    const op = this.ops.get(opId);
+    if (!op) {
+      throw new NotFoundException(`Settlement op ${opId} not found`);
+    }
+    return op;
+  }
+
+  private journal(entry: JournalEntry): void {
+    // Fold every journal entry into the tamper-evidence chain so auditors
+    // can check the on-disk log against the published chain head.
+    this.audit.append('settlement-journal', entry);
+    try {
+      const dir = dirname(this.journalPath);
+      if (dir && dir !== '.' && !existsSync(dir)) {
+        mkdirSync(dir, { recursive: true });
+      }
+      appendFileSync(this.journalPath, `${JSON.stringify(entry)}\n`);
+    } catch (error) {
+      // A journal write failure must not lose the in-memory op, but it does
+      // compromise crash recovery — make it loud.
+      this.logger.error(`Failed to append settlement journal entry`, error);
+    }
+  }
+
+  private replay(): void {
+    if (!existsSync(this.journalPath)) {
+      return;
+    }
+    let lines: string[];
+    try {
+      lines = readFileSync(this.journalPath, 'utf8').split('\n').filter(Boolean);
+    } catch (error) {
+      this.logger.error(`Failed to read settlement journal at ${this.journalPath}`, error);
+      return;
+    }
+
+    for (const line of lines) {
+      let entry: JournalEntry;
+      try {
+        entry = JSON.parse(line) as JournalEntry;
+      } catch {
+        this.logger.warn(`Skipping corrupt settlement journal line`);
+        continue;
+      }
+      // Rebuild the tamper-evidence chain over the replayed log so the head
+      // published after a restart still covers the full journal history.
+      this.audit.append('settlement-journal', entry);
+      switch (entry.type) {
+        case 'enqueue':
+          this.ops.set(entry.op.id, { ...entry.op });
+          break;
+        case 'in_flight': {
+          const op = this.ops.get(entry.id);
+          if (op) op.status = 'in_flight';
+          break;
+        }
+        case 'complete': {
+          const op = this.ops.get(entry.id);
+          if (op) {
+            op.status = 'complete';
+            op.completed_at = entry.at;
+            if (entry.tx_ref) {
+              op.tx_ref = entry.tx_ref;
+            }
+          }
+          break;
+        }
+        case 'failed': {
+          const op = this.ops.get(entry.id);
+          if (op) {
+            op.status = 'failed';
+            op.failure_reason = entry.reason;
+          }
+          break;
+        }
+      }
+    }
+
+    const pending = this.pendingOps().length;
+    this.logger.log(`Replayed settlement journal: ${this.ops.size} ops, ${pending} pending/in-flight`);
+  }
+}
@@ -0,0 +1,98 @@
+import { Injectable, Logger, OnModuleDestroy, OnModuleInit } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { createHash } from 'crypto';
+
+import { SettlementOp, SettlementQueueService } from './settlement-queue.service';
+
+export interface WorkerStatus {
+  worker_id: number;
+  dispatched: number;
+  last_dispatch_at?: string;
+}
+
+const DEFAULT_WORKER_COUNT = 4;
+const DEFAULT_TICK_MS = 1_000;
+
+/**
+ * Parallel settlement dispatch. Ops are partitioned across N workers by the
+ * account they settle for, so a slow withdrawal only ever blocks ops for the
+ * same account — pool deposits on other accounts keep flowing. Within a
+ * partition each worker dispatches in lane order (tipped first, then FIFO)
+ * and holds at most one op per account in flight, preserving per-account
+ * ordering end to end. Backpressure lives at enqueue time in the queue's
+ * bounded capacity.
+ */
+@Injectable()
+export class SettlementWorkersService implements OnModuleInit, OnModuleDestroy {
+  private readonly logger = new Logger(SettlementWorkersService.name);
+  private readonly timers: Array<ReturnType<typeof setInterval>> = [];
+  private readonly status: WorkerStatus[] = [];
+  private workerCount = DEFAULT_WORKER_COUNT;
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly queue: SettlementQueueService,
+  ) {}
+
+  onModuleInit(): void {
+    this.workerCount = Number(this.config.get<string>('SETTLEMENT_WORKER_COUNT')) || DEFAULT_WORKER_COUNT;
+    const tickMs = Number(this.config.get<string>('SETTLEMENT_WORKER_TICK_MS')) || DEFAULT_TICK_MS;
+    for (let workerId = 0; workerId < this.workerCount; workerId += 1) {
+      this.status.push({ worker_id: workerId, dispatched: 0 });
+      this.timers.push(setInterval(() => this.tick(workerId), tickMs));
+    }
+    this.logger.log(`Started ${this.workerCount} settlement workers (tick ${tickMs}ms)`);
+  }
+
+  onModuleDestroy(): void {
+    for (const timer of this.timers) {
+      clearInterval(timer);
+    }
+  }
+
+  workerStatus(): WorkerStatus[] {
+    return this.status;
+  }
+
+  /** Account whose on-chain state the op touches; the partitioning key. */
+  accountKey(op: SettlementOp): string {
+    const payload = op.payload;
+    for (const field of ['wallet_address', 'user_address', 'storage_account', 'pool_id']) {
+      const value = payload[field];
+      if (typeof value === 'string' && value) {
+        return value;
+      }
+    }
+    return op.id;
+  }
+
+  partitionOf(accountKey: string): number {
+    const digest = createHash('sha256').update(accountKey).digest();
+    return digest.readUInt32BE(0) % this.workerCount;
+  }
+
+  /**
+   * One dispatch pass for a worker: walk this partition's pending ops in
+   * lane order and move the first op whose account has nothing in flight to
+   * `in_flight`. One op per tick keeps dispatch fair across partitions.
+   */
+  private tick(workerId: number): void {
+    const inFlightAccounts = new Set(
+      this.queue
+        .listOps('in_flight')
+        .map((op) => this.accountKey(op)),
+    );
+    const lanes = this.queue.lanes();
+    for (const op of [...lanes.priority, ...lanes.standard]) {
+      const account = this.accountKey(op);
+      if (this.partitionOf(account) !== workerId) continue;
+      if (inFlightAccounts.has(account)) continue;
+      this.queue.markInFlight(op.id);
+      const status = this.status[workerId];
+      status.dispatched += 1;
+      status.last_dispatch_at = new Date().toISOString();
+      this.logger.debug(`Worker ${workerId} dispatched ${op.kind} op ${op.id} for ${account}`);
+      return;
+    }
+  }
+}
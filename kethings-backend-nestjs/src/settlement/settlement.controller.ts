@@ -0,0 +1,50 @@
+import { Body, Controller, Get, Param, Post, Query, UseGuards } from '@nestjs/common';
+
+import { AdminGuard } from '../common/admin.guard';
+import { SettlementCostsService } from './settlement-costs.service';
+import { SettlementOpStatus, SettlementQueueService } from './settlement-queue.service';
+import { SettlementWorkersService } from './settlement-workers.service';
+
+@Controller('settlement')
+export class SettlementController {
+  constructor(
+    private readonly costs: SettlementCostsService,
+    private readonly queue: SettlementQueueService,
+    private readonly workers: SettlementWorkersService,
+  ) {}
+
+  @Get('workers')
+  workerStatus() {
+    return { workers: this.workers.workerStatus() };
+  }
+
+  @Get('queue')
+  queueOps(@Query('status') status?: SettlementOpStatus) {
+    return { ops: this.queue.listOps(status) };
+  }
+
+  @Get('queue/lanes')
+  lanes() {
+    return this.queue.lanes();
+  }
+
+  /**
+   * Marking an op complete clears pending settlement state and writes the
+   * tx_ref into the journal chain, so only operators may do it.
+   */
+  @Post('queue/:opId/confirm')
+  @UseGuards(AdminGuard)
+  confirmOp(@Param('opId') opId: string, @Body() body?: { tx_ref?: string }) {
+    return this.queue.confirm(opId, body?.tx_ref);
+  }
+
+  @Get('costs/pools')
+  costsByPool() {
+    return { summaries: this.costs.summarizeByPool() };
+  }
+
+  @Get('costs/users')
+  costsByUser() {
+    return { summaries: this.costs.summarizeByUser() };
+  }
+}
@@ -0,0 +1,20 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+import { SettlementCostsService } from './settlement-costs.service';
+import { SettlementQueueService } from './settlement-queue.service';
+import { NettingService } from './netting.service';
+import { SettlementWorkersService } from './settlement-workers.service';
+import { SettlementController } from './settlement.controller';
+import { NettingController } from './netting.controller';
+import { UserSettlementsController } from './user-settlements.controller';
+import { SettlementsController } from './settlements.controller';
+import { AuditModule } from '../audit/audit.module';
+import { AdminGuard } from '../common/admin.guard';
+
+@Module({
+  imports: [ConfigModule, AuditModule],
+  providers: [SettlementCostsService, SettlementQueueService, NettingService, SettlementWorkersService, AdminGuard],
+  controllers: [SettlementController, NettingController, UserSettlementsController, SettlementsController],
+  exports: [SettlementCostsService, SettlementQueueService, NettingService],
+})
+export class SettlementModule {}
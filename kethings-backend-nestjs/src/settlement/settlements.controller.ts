@@ -0,0 +1,14 @@
+import { Controller, Get, Param } from '@nestjs/common';
+
+import { SettlementQueueService } from './settlement-queue.service';
+
+/** Settlement status by op id, for callers tracking a specific deposit or withdrawal. */
+@Controller('settlements')
+export class SettlementsController {
+  constructor(private readonly queue: SettlementQueueService) {}
+
+  @Get(':opId')
+  getSettlement(@Param('opId') opId: string) {
+    return this.queue.getOpById(opId);
+  }
+}
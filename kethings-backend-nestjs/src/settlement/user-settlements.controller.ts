@@ -0,0 +1,24 @@
+import { Controller, Get, Param } from '@nestjs/common';
+
+import { SettlementQueueService } from './settlement-queue.service';
+
+/**
+ * Per-wallet settlement visibility: an LP can check whether their own pool
+ * deposits and withdrawals have settled (with tx references once confirmed)
+ * instead of inferring from the pool-level pending_settlement flag.
+ */
+@Controller('users/:userAddress/pools/:poolId/settlements')
+export class UserSettlementsController {
+  constructor(private readonly queue: SettlementQueueService) {}
+
+  @Get()
+  settlements(@Param('userAddress') userAddress: string, @Param('poolId') poolId: string) {
+    const ops = this.queue.listByWallet(userAddress, poolId);
+    return {
+      wallet_address: userAddress,
+      pool_id: poolId,
+      pending: ops.filter((op) => op.status === 'pending' || op.status === 'in_flight'),
+      completed: ops.filter((op) => op.status === 'complete' || op.status === 'failed'),
+    };
+  }
+}
@@ -0,0 +1,11 @@
+import { Module } from '@nestjs/common';
+
+import { ShutdownService } from './shutdown.service';
+import { SettlementModule } from '../settlement/settlement.module';
+import { ReconciliationModule } from '../reconciliation/reconciliation.module';
+
+@Module({
+  imports: [SettlementModule, ReconciliationModule],
+  providers: [ShutdownService],
+})
+export class ShutdownModule {}
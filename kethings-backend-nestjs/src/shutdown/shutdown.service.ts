@@ -0,0 +1,49 @@
+import { BeforeApplicationShutdown, Injectable, Logger } from '@nestjs/common';
+
+import { NettingService } from '../settlement/netting.service';
+import { SettlementQueueService } from '../settlement/settlement-queue.service';
+import { DriftArchiveService } from '../reconciliation/drift-archive.service';
+
+/**
+ * Graceful shutdown coordinator. Nest stops accepting HTTP requests and
+ * tears down module timers once shutdown hooks fire; this service runs
+ * before that teardown to drain state that would otherwise be dropped:
+ * pending netting obligations are flushed into the settlement queue (whose
+ * journal already survives restarts) and a final reconciliation cycle is
+ * attempted so the archive reflects the state the process died with.
+ */
+@Injectable()
+export class ShutdownService implements BeforeApplicationShutdown {
+  private readonly logger = new Logger(ShutdownService.name);
+
+  constructor(
+    private readonly netting: NettingService,
+    private readonly settlementQueue: SettlementQueueService,
+    private readonly driftArchive: DriftArchiveService,
+  ) {}
+
+  async beforeApplicationShutdown(signal?: string): Promise<void> {
+    this.logger.log(`Received ${signal ?? 'shutdown'}; draining before exit`);
+
+    const { netted, transfers } = this.netting.flush();
+    if (netted > 0) {
+      this.logger.log(`Flushed ${netted} netting obligations into ${transfers} settlement transfers`);
+    }
+
+    const pending = this.settlementQueue.pendingOps();
+    if (pending.length > 0) {
+      // Pending ops are already journaled; log them so operators know what
+      // will resume when the process comes back.
+      this.logger.warn(`${pending.length} settlement ops remain queued and will resume on restart`);
+    }
+
+    try {
+      await this.driftArchive.runCycle();
+      this.logger.log('Final reconciliation cycle archived');
+    } catch (error) {
+      this.logger.warn(`Final reconciliation cycle failed: ${error instanceof Error ? error.message : 'unknown error'}`);
+    }
+
+    this.logger.log('Shutdown drain complete');
+  }
+}
@@ -0,0 +1,37 @@
+import { IsArray, IsInt, IsOptional, IsString, Matches, Max, Min } from 'class-validator';
+
+export class RegisterTenantDto {
+  @Matches(/^[a-z0-9][a-z0-9-]{1,62}[a-z0-9]$/, {
+    message: 'id must be a lowercase slug (letters, digits, hyphens)',
+  })
+  id!: string;
+
+  @IsString()
+  name!: string;
+
+  @IsOptional()
+  @IsArray()
+  @IsString({ each: true })
+  origins?: string[];
+
+  @IsOptional()
+  @IsArray()
+  @IsString({ each: true })
+  api_keys?: string[];
+
+  @IsOptional()
+  @IsArray()
+  @IsString({ each: true })
+  markets?: string[];
+
+  @IsOptional()
+  @IsArray()
+  @IsString({ each: true })
+  pools?: string[];
+
+  @IsOptional()
+  @IsInt()
+  @Min(0)
+  @Max(10_000)
+  fee_markup_bps?: number;
+}
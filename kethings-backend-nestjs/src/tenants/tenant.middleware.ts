@@ -0,0 +1,29 @@
+import { Injectable, NestMiddleware } from '@nestjs/common';
+
+import { TenantsService } from './tenants.service';
+
+/**
+ * Resolves the tenant for every request and stamps it on the request object
+ * so downstream controllers can scope listings without re-resolving. Also
+ * feeds the per-tenant usage stats keyed by top-level API area.
+ */
+@Injectable()
+export class TenantMiddleware implements NestMiddleware {
+  constructor(private readonly tenants: TenantsService) {}
+
+  use(req: any, _res: any, next: () => void): void {
+    const apiKey = req.headers?.['x-tenant-key'];
+    const origin = req.headers?.origin;
+    const tenantId = this.tenants.resolve(
+      typeof apiKey === 'string' ? apiKey : undefined,
+      typeof origin === 'string' ? origin : undefined,
+    );
+    req.tenantId = tenantId;
+    const path: string = req.originalUrl ?? req.url ?? '';
+    const segments = path.split('?')[0].split('/').filter(Boolean);
+    // Paths look like /api/{area}/...; fall back to the first segment.
+    const area = segments[0] === 'api' ? segments[1] : segments[0];
+    this.tenants.recordRequest(tenantId, area ?? 'root');
+    next();
+  }
+}
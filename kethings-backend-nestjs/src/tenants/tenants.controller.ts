@@ -0,0 +1,55 @@
+import { Body, Controller, Delete, Get, HttpCode, Param, Post, Req, UseGuards } from '@nestjs/common';
+import type { Request } from 'express';
+
+import { DEFAULT_TENANT_ID, TenantsService } from './tenants.service';
+import { AdminGuard } from '../common/admin.guard';
+import { RegisterTenantDto } from './dto/register-tenant.dto';
+
+@Controller('tenants')
+export class TenantsController {
+  constructor(private readonly tenants: TenantsService) {}
+
+  /**
+   * Config for the venue the request resolved to. White-label frontends call
+   * this on boot to learn their name, namespace and fee markup; API keys are
+   * never included.
+   */
+  @Get('current')
+  current(@Req() req: Request) {
+    const tenantId = (req as { tenantId?: string }).tenantId ?? DEFAULT_TENANT_ID;
+    return this.tenants.getTenant(tenantId);
+  }
+}
+
+@Controller('admin/tenants')
+@UseGuards(AdminGuard)
+export class AdminTenantsController {
+  constructor(private readonly tenants: TenantsService) {}
+
+  @Get()
+  list() {
+    return { tenants: this.tenants.listTenants() };
+  }
+
+  @Post()
+  register(@Body() body: RegisterTenantDto) {
+    return this.tenants.register(body.id, body.name, {
+      origins: body.origins,
+      apiKeys: body.api_keys,
+      markets: body.markets,
+      pools: body.pools,
+      feeMarkupBps: body.fee_markup_bps,
+    });
+  }
+
+  @Delete(':tenantId')
+  @HttpCode(204)
+  remove(@Param('tenantId') tenantId: string): void {
+    this.tenants.remove(tenantId);
+  }
+
+  @Get(':tenantId/stats')
+  stats(@Param('tenantId') tenantId: string) {
+    return this.tenants.statsFor(tenantId);
+  }
+}
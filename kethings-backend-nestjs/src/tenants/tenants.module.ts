@@ -0,0 +1,11 @@
+import { Module } from '@nestjs/common';
+import { TenantsService } from './tenants.service';
+import { AdminTenantsController, TenantsController } from './tenants.controller';
+import { AdminGuard } from '../common/admin.guard';
+
+@Module({
+  providers: [TenantsService, AdminGuard],
+  controllers: [TenantsController, AdminTenantsController],
+  exports: [TenantsService],
+})
+export class TenantsModule {}
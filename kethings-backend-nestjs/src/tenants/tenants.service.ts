@@ -0,0 +1,153 @@
+import { ConflictException, Injectable, Logger, NotFoundException } from '@nestjs/common';
+
+export interface Tenant {
+  id: string;
+  name: string;
+  /** Origins (e.g. https://trade.example.com) resolved to this tenant. */
+  origins: string[];
+  /** Markets visible to this venue; undefined means all markets. */
+  markets?: string[];
+  /** Pools visible to this venue; undefined means all pools. */
+  pools?: string[];
+  /** Venue fee markup in basis points, applied by the white-label frontend. */
+  fee_markup_bps?: number;
+  created_at: string;
+}
+
+export interface TenantStats {
+  tenant_id: string;
+  total_requests: number;
+  /** Request counts keyed by top-level API area (engine, pools, rfq, ...). */
+  by_area: Record<string, number>;
+}
+
+export interface RegisterTenantOptions {
+  origins?: string[];
+  apiKeys?: string[];
+  markets?: string[];
+  pools?: string[];
+  feeMarkupBps?: number;
+}
+
+export const DEFAULT_TENANT_ID = 'default';
+
+/**
+ * Tenant registry for white-label deployments. One engine can host several
+ * branded venues: each tenant is resolved per request from its API key
+ * (`x-tenant-key`) or origin, and carries its own market/pool allow-lists,
+ * fee configuration and usage stats. The implicit `default` tenant is
+ * unrestricted, so a single-brand deployment behaves exactly as before.
+ */
+@Injectable()
+export class TenantsService {
+  private readonly logger = new Logger(TenantsService.name);
+  private readonly tenants = new Map<string, Tenant>();
+  /** API key -> tenant id. Keys are write-only: never echoed back to clients. */
+  private readonly apiKeys = new Map<string, string>();
+  private readonly stats = new Map<string, Map<string, number>>();
+
+  register(id: string, name: string, options: RegisterTenantOptions = {}): Tenant {
+    if (id === DEFAULT_TENANT_ID || this.tenants.has(id)) {
+      throw new ConflictException(`Tenant ${id} already exists`);
+    }
+    const tenant: Tenant = {
+      id,
+      name,
+      origins: options.origins ?? [],
+      ...(options.markets !== undefined ? { markets: options.markets } : {}),
+      ...(options.pools !== undefined ? { pools: options.pools } : {}),
+      ...(options.feeMarkupBps !== undefined ? { fee_markup_bps: options.feeMarkupBps } : {}),
+      created_at: new Date().toISOString(),
+    };
+    this.tenants.set(id, tenant);
+    for (const key of options.apiKeys ?? []) {
+      this.apiKeys.set(key, id);
+    }
+    this.logger.log(`Registered tenant ${id} (${name})`);
+    return tenant;
+  }
+
+  remove(id: string): void {
+    if (!this.tenants.delete(id)) {
+      throw new NotFoundException(`Tenant ${id} not found`);
+    }
+    for (const [key, tenantId] of this.apiKeys) {
+      if (tenantId === id) {
+        this.apiKeys.delete(key);
+      }
+    }
+    this.stats.delete(id);
+  }
+
+  listTenants(): Tenant[] {
+    return Array.from(this.tenants.values());
+  }
+
+  getTenant(id: string): Tenant {
+    if (id === DEFAULT_TENANT_ID) {
+      return this.defaultTenant();
+    }
+    const tenant = this.tenants.get(id);
+    if (!tenant) {
+      throw new NotFoundException(`Tenant ${id} not found`);
+    }
+    return tenant;
+  }
+
+  /** Tenant for a request; the API key wins over the origin claim. */
+  resolve(apiKey?: string, origin?: string): string {
+    if (apiKey) {
+      const byKey = this.apiKeys.get(apiKey);
+      if (byKey && this.tenants.has(byKey)) {
+        return byKey;
+      }
+    }
+    if (origin) {
+      for (const tenant of this.tenants.values()) {
+        if (tenant.origins.includes(origin)) {
+          return tenant.id;
+        }
+      }
+    }
+    return DEFAULT_TENANT_ID;
+  }
+
+  /** Whether a market is inside the tenant's namespace. */
+  scopesMarket(tenantId: string, market: string): boolean {
+    const allowList = this.tenants.get(tenantId)?.markets;
+    return allowList === undefined || allowList.includes(market);
+  }
+
+  /** Whether a pool is inside the tenant's namespace. */
+  scopesPool(tenantId: string, poolId: string): boolean {
+    const allowList = this.tenants.get(tenantId)?.pools;
+    return allowList === undefined || allowList.includes(poolId);
+  }
+
+  recordRequest(tenantId: string, area: string): void {
+    const byArea = this.stats.get(tenantId) ?? new Map<string, number>();
+    byArea.set(area, (byArea.get(area) ?? 0) + 1);
+    this.stats.set(tenantId, byArea);
+  }
+
+  statsFor(tenantId: string): TenantStats {
+    this.getTenant(tenantId);
+    const byArea = this.stats.get(tenantId) ?? new Map<string, number>();
+    let total = 0;
+    const breakdown: Record<string, number> = {};
+    for (const [area, count] of byArea) {
+      breakdown[area] = count;
+      total += count;
+    }
+    return { tenant_id: tenantId, total_requests: total, by_area: breakdown };
+  }
+
+  private defaultTenant(): Tenant {
+    return {
+      id: DEFAULT_TENANT_ID,
+      name: 'Default venue',
+      origins: [],
+      created_at: new Date(0).toISOString(),
+    };
+  }
+}
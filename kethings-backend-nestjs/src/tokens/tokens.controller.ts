@@ -0,0 +1,22 @@
+import { Controller, Get, NotFoundException, Param } from '@nestjs/common';
+
+import { TokensService } from './tokens.service';
+
+@Controller('tokens/v1')
+export class TokensController {
+  constructor(private readonly tokens: TokensService) {}
+
+  @Get('registry')
+  listRegistry() {
+    return { tokens: this.tokens.listTokens() };
+  }
+
+  @Get('registry/:address')
+  getRegistryEntry(@Param('address') address: string) {
+    const entry = this.tokens.getToken(address);
+    if (!entry) {
+      throw new NotFoundException(`Token ${address} is not in the registry`);
+    }
+    return entry;
+  }
+}
@@ -0,0 +1,12 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+import { TokensService } from './tokens.service';
+import { TokensController } from './tokens.controller';
+
+@Module({
+  imports: [ConfigModule],
+  providers: [TokensService],
+  controllers: [TokensController],
+  exports: [TokensService],
+})
+export class TokensModule {}
@@ -0,0 +1,117 @@
+import { Injectable, Logger } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { z } from 'zod';
+
+export interface TokenDisplayMetadata {
+  decimals: number;
+  display_decimals: number;
+  symbol_position: 'prefix' | 'suffix';
+  thousands_separator: string;
+  decimal_separator: string;
+}
+
+export interface TokenRegistryEntry {
+  address: string;
+  symbol: string;
+  name: string;
+  decimals: number;
+  display: TokenDisplayMetadata;
+}
+
+const registryEntrySchema = z.object({
+  address: z.string().min(1),
+  symbol: z.string().min(1),
+  name: z.string().min(1),
+  decimals: z.number().int().min(0).max(18),
+  display: z
+    .object({
+      display_decimals: z.number().int().min(0).max(18).optional(),
+      symbol_position: z.enum(['prefix', 'suffix']).optional(),
+      thousands_separator: z.string().optional(),
+      decimal_separator: z.string().optional(),
+    })
+    .optional(),
+});
+
+const DEFAULT_DISPLAY: Omit<TokenDisplayMetadata, 'decimals'> = {
+  display_decimals: 4,
+  symbol_position: 'suffix',
+  thousands_separator: ',',
+  decimal_separator: '.',
+};
+
+const BUILTIN_TOKENS: Array<z.infer<typeof registryEntrySchema>> = [
+  { address: 'KTA', symbol: 'KTA', name: 'Keeta', decimals: 9 },
+  { address: 'USDT', symbol: 'USDT', name: 'Tether USD', decimals: 6, display: { display_decimals: 2 } },
+];
+
+@Injectable()
+export class TokensService {
+  private readonly logger = new Logger(TokensService.name);
+  private readonly registry = new Map<string, TokenRegistryEntry>();
+
+  constructor(private readonly config: ConfigService) {
+    this.loadRegistry();
+  }
+
+  private loadRegistry(): void {
+    for (const entry of BUILTIN_TOKENS) {
+      this.register(entry);
+    }
+
+    const raw = this.config.get<string>('TOKEN_REGISTRY');
+    if (!raw) {
+      return;
+    }
+
+    try {
+      const parsed = z.array(registryEntrySchema).safeParse(JSON.parse(raw));
+      if (!parsed.success) {
+        this.logger.warn(`TOKEN_REGISTRY failed validation; keeping built-in tokens only`);
+        return;
+      }
+      for (const entry of parsed.data) {
+        this.register(entry);
+      }
+      this.logger.log(`Loaded ${parsed.data.length} token registry entries from TOKEN_REGISTRY`);
+    } catch (error) {
+      this.logger.warn(`Failed to parse TOKEN_REGISTRY JSON`, error);
+    }
+  }
+
+  private register(entry: z.infer<typeof registryEntrySchema>): void {
+    this.registry.set(entry.address, {
+      address: entry.address,
+      symbol: entry.symbol,
+      name: entry.name,
+      decimals: entry.decimals,
+      display: {
+        decimals: entry.decimals,
+        display_decimals: entry.display?.display_decimals ?? Math.min(entry.decimals, DEFAULT_DISPLAY.display_decimals),
+        symbol_position: entry.display?.symbol_position ?? DEFAULT_DISPLAY.symbol_position,
+        thousands_separator: entry.display?.thousands_separator ?? DEFAULT_DISPLAY.thousands_separator,
+        decimal_separator: entry.display?.decimal_separator ?? DEFAULT_DISPLAY.decimal_separator,
+      },
+    });
+  }
+
+  listTokens(): TokenRegistryEntry[] {
+    return Array.from(this.registry.values());
+  }
+
+  getToken(address: string): TokenRegistryEntry | undefined {
+    return this.registry.get(address);
+  }
+
+  /**
+   * Display hints for an arbitrary token. Unknown tokens fall back to a
+   * conservative default so frontends never have to hard-code token knowledge.
+   */
+  getDisplayMetadata(address: string): TokenDisplayMetadata {
+    const entry = this.registry.get(address);
+    if (entry) {
+      return entry.display;
+    }
+    return { decimals: 9, ...DEFAULT_DISPLAY };
+  }
+}
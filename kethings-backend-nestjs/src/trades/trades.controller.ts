@@ -0,0 +1,36 @@
+import { Controller, Get, Param, Query, Req, Res } from '@nestjs/common';
+import type { Request, Response } from 'express';
+
+import { TradesService } from './trades.service';
+import { keysetPage, respondWithEtag } from '../common/list-caching';
+
+@Controller('trades')
+export class TradesController {
+  constructor(private readonly trades: TradesService) {}
+
+  @Get(':base/:quote')
+  marketTrades(
+    @Param('base') base: string,
+    @Param('quote') quote: string,
+    @Req() req: Request,
+    @Res({ passthrough: true }) res: Response,
+    @Query('limit') limit?: string,
+    @Query('offset') offset?: string,
+    @Query('after') after?: string,
+  ) {
+    // Keyset mode (?after=<trade id>) keeps pages stable under new fills;
+    // limit/offset remains for clients that only want the most recent page.
+    if (after !== undefined) {
+      const all = this.trades.allByMarket(`${base}/${quote}`);
+      const page = keysetPage(all, (trade) => trade.id, after, this.toInt(limit));
+      return respondWithEtag(req, res, { trades: page.items, next_cursor: page.next_cursor });
+    }
+    return respondWithEtag(req, res, this.trades.listByMarket(`${base}/${quote}`, this.toInt(limit), this.toInt(offset)));
+  }
+
+  private toInt(value?: string): number | undefined {
+    if (value === undefined) return undefined;
+    const n = Number(value);
+    return Number.isFinite(n) ? Math.floor(n) : undefined;
+  }
+}
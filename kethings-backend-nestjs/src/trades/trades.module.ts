@@ -0,0 +1,14 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+
+import { TradesService } from './trades.service';
+import { TradesController } from './trades.controller';
+import { UserTradesController } from './user-trades.controller';
+
+@Module({
+  imports: [ConfigModule],
+  providers: [TradesService],
+  controllers: [TradesController, UserTradesController],
+  exports: [TradesService],
+})
+export class TradesModule {}
@@ -0,0 +1,85 @@
+import { Injectable } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { randomUUID } from 'crypto';
+import { Subject } from 'rxjs';
+
+export interface TradeRecord {
+  id: string;
+  market: string;
+  price: number;
+  quantity: number;
+  /** Pool id when the fill came from AMM liquidity rather than a resting order. */
+  maker_user: string;
+  taker_user: string;
+  taker_side: 'buy' | 'sell';
+  source: 'book' | 'pool';
+  executed_at: string;
+}
+
+export interface TradePage {
+  trades: TradeRecord[];
+  total: number;
+  limit: number;
+  offset: number;
+}
+
+const DEFAULT_STORE_LIMIT = 10_000;
+const MAX_PAGE_SIZE = 100;
+
+/**
+ * Append-only record of executed fills. The engine reports every match here;
+ * queries page newest-first. The store is bounded so an active market cannot
+ * grow memory without limit — older trades roll off once the cap is hit.
+ */
+@Injectable()
+export class TradesService {
+  private readonly trades: TradeRecord[] = [];
+  /** Every recorded fill, for downstream accounting (cost basis, statements). */
+  readonly events$ = new Subject<TradeRecord>();
+
+  constructor(private readonly config: ConfigService) {}
+
+  record(trade: Omit<TradeRecord, 'id' | 'executed_at'>): TradeRecord {
+    const entry: TradeRecord = {
+      ...trade,
+      id: randomUUID(),
+      executed_at: new Date().toISOString(),
+    };
+    this.trades.push(entry);
+    this.events$.next(entry);
+    const cap = Number(this.config.get<string>('TRADE_STORE_LIMIT')) || DEFAULT_STORE_LIMIT;
+    if (this.trades.length > cap) {
+      this.trades.splice(0, this.trades.length - cap);
+    }
+    return entry;
+  }
+
+  listByMarket(market: string, limit?: number, offset?: number): TradePage {
+    return this.page(this.trades.filter((trade) => trade.market === market), limit, offset);
+  }
+
+  /** Full newest-first history for a market, for keyset pagination. */
+  allByMarket(market: string): TradeRecord[] {
+    return this.trades.filter((trade) => trade.market === market).reverse();
+  }
+
+  listByUser(user: string, limit?: number, offset?: number): TradePage {
+    return this.page(
+      this.trades.filter((trade) => trade.maker_user === user || trade.taker_user === user),
+      limit,
+      offset,
+    );
+  }
+
+  private page(matched: TradeRecord[], limit?: number, offset?: number): TradePage {
+    const pageSize = Math.max(1, Math.min(MAX_PAGE_SIZE, limit ?? 50));
+    const start = Math.max(0, offset ?? 0);
+    const newestFirst = matched.slice().reverse();
+    return {
+      trades: newestFirst.slice(start, start + pageSize),
+      total: matched.length,
+      limit: pageSize,
+      offset: start,
+    };
+  }
+}
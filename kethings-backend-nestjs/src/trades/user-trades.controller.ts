@@ -0,0 +1,23 @@
+import { Controller, Get, Param, Query } from '@nestjs/common';
+
+import { TradesService } from './trades.service';
+
+@Controller('users/:userAddress/trades')
+export class UserTradesController {
+  constructor(private readonly trades: TradesService) {}
+
+  @Get()
+  userTrades(
+    @Param('userAddress') userAddress: string,
+    @Query('limit') limit?: string,
+    @Query('offset') offset?: string,
+  ) {
+    return this.trades.listByUser(userAddress, this.toInt(limit), this.toInt(offset));
+  }
+
+  private toInt(value?: string): number | undefined {
+    if (value === undefined) return undefined;
+    const n = Number(value);
+    return Number.isFinite(n) ? Math.floor(n) : undefined;
+  }
+}
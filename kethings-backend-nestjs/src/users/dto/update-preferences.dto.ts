@@ -0,0 +1,27 @@
+import { Type } from 'class-transformer';
+import { IsIn, IsNumber, IsObject, IsOptional, IsString, Max, Min } from 'class-validator';
+
+export class UpdatePreferencesDto {
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @Min(0)
+  @Max(1)
+  default_slippage?: number;
+
+  @IsOptional()
+  @IsString()
+  preferred_quote_token?: string;
+
+  @IsOptional()
+  @IsIn(['fifo', 'average'])
+  cost_basis_method?: 'fifo' | 'average';
+
+  @IsOptional()
+  @IsObject()
+  notifications?: Record<string, boolean>;
+
+  @IsOptional()
+  @IsObject()
+  ui_flags?: Record<string, boolean>;
+}
@@ -0,0 +1,19 @@
+import { Body, Controller, Get, Param, Put } from '@nestjs/common';
+
+import { PreferencesService } from './preferences.service';
+import { UpdatePreferencesDto } from './dto/update-preferences.dto';
+
+@Controller('users/:userAddress/preferences')
+export class PreferencesController {
+  constructor(private readonly preferences: PreferencesService) {}
+
+  @Get()
+  getPreferences(@Param('userAddress') userAddress: string) {
+    return this.preferences.get(userAddress);
+  }
+
+  @Put()
+  updatePreferences(@Param('userAddress') userAddress: string, @Body() body: UpdatePreferencesDto) {
+    return this.preferences.update(userAddress, body);
+  }
+}
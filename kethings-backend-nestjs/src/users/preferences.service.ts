@@ -0,0 +1,81 @@
+import { Injectable } from '@nestjs/common';
+
+export type CostBasisMethod = 'fifo' | 'average';
+
+export interface UserPreferences {
+  /** Applied when an order request omits max_slippage (0.01 = 1%). */
+  default_slippage: number;
+  preferred_quote_token?: string;
+  /** Lot-matching method used when computing realized PnL for tax reporting. */
+  cost_basis_method: CostBasisMethod;
+  notifications: {
+    order_filled: boolean;
+    withdrawal_settled: boolean;
+    price_alerts: boolean;
+  };
+  ui_flags: Record<string, boolean>;
+  updated_at?: string;
+}
+
+export interface PreferencesUpdate {
+  default_slippage?: number;
+  preferred_quote_token?: string;
+  cost_basis_method?: CostBasisMethod;
+  notifications?: Partial<UserPreferences['notifications']>;
+  ui_flags?: Record<string, boolean>;
+}
+
+const DEFAULT_PREFERENCES: UserPreferences = {
+  default_slippage: 0.01,
+  cost_basis_method: 'fifo',
+  notifications: {
+    order_filled: true,
+    withdrawal_settled: true,
+    price_alerts: false,
+  },
+  ui_flags: {},
+};
+
+/**
+ * Server-side user preferences so settings roam across devices. Reads always
+ * return a fully-populated object (defaults merged under stored values) and
+ * updates are partial merges, so clients never have to round-trip fields
+ * they are not changing.
+ */
+@Injectable()
+export class PreferencesService {
+  private readonly preferences = new Map<string, UserPreferences>();
+
+  get(user: string): UserPreferences {
+    const stored = this.preferences.get(user);
+    if (!stored) {
+      return { ...DEFAULT_PREFERENCES, notifications: { ...DEFAULT_PREFERENCES.notifications }, ui_flags: {} };
+    }
+    return stored;
+  }
+
+  update(user: string, update: PreferencesUpdate): UserPreferences {
+    const current = this.get(user);
+    const next: UserPreferences = {
+      ...current,
+      ...(update.default_slippage !== undefined ? { default_slippage: update.default_slippage } : {}),
+      ...(update.preferred_quote_token !== undefined ? { preferred_quote_token: update.preferred_quote_token } : {}),
+      ...(update.cost_basis_method !== undefined ? { cost_basis_method: update.cost_basis_method } : {}),
+      notifications: { ...current.notifications, ...(update.notifications ?? {}) },
+      ui_flags: { ...current.ui_flags, ...(update.ui_flags ?? {}) },
+      updated_at: new Date().toISOString(),
+    };
+    this.preferences.set(user, next);
+    return next;
+  }
+
+  /** Default slippage for a user, for callers filling in omitted fields. */
+  defaultSlippage(user: string): number {
+    return this.get(user).default_slippage;
+  }
+
+  /** Lot-matching method for a user's realized-PnL calculations. */
+  costBasisMethod(user: string): CostBasisMethod {
+    return this.get(user).cost_basis_method;
+  }
+}
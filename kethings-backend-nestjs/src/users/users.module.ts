@@ -0,0 +1,11 @@
+import { Module } from '@nestjs/common';
+
+import { PreferencesService } from './preferences.service';
+import { PreferencesController } from './preferences.controller';
+
+@Module({
+  providers: [PreferencesService],
+  controllers: [PreferencesController],
+  exports: [PreferencesService],
+})
+export class UsersModule {}
@@ -0,0 +1,13 @@
+import { IsString, MaxLength } from 'class-validator';
+
+export class CreateAddressBookEntryDto {
+  @IsString()
+  user_address!: string;
+
+  @IsString()
+  @MaxLength(64)
+  label!: string;
+
+  @IsString()
+  address!: string;
+}
@@ -0,0 +1,25 @@
+import { Type } from 'class-transformer';
+import { IsNumber, IsOptional, IsPositive, IsString } from 'class-validator';
+
+export class CreateWithdrawalDto {
+  @IsString()
+  user_address!: string;
+
+  @IsString()
+  token!: string;
+
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  amount!: number;
+
+  @IsString()
+  to!: string;
+
+  /** Optional priority tip, paid in the withdrawn token, for faster settlement. */
+  @IsOptional()
+  @Type(() => Number)
+  @IsNumber()
+  @IsPositive()
+  tip?: number;
+}
@@ -0,0 +1,31 @@
+import { Body, Controller, Delete, Get, HttpCode, Param, Post, Query } from '@nestjs/common';
+
+import { WithdrawalsService } from './withdrawals.service';
+import { CreateWithdrawalDto } from './dto/create-withdrawal.dto';
+import { CreateAddressBookEntryDto } from './dto/create-address-book-entry.dto';
+
+@Controller('withdrawals')
+export class WithdrawalsController {
+  constructor(private readonly withdrawals: WithdrawalsService) {}
+
+  @Post()
+  requestWithdrawal(@Body() body: CreateWithdrawalDto) {
+    return this.withdrawals.requestWithdrawal(body.user_address, body.token, body.amount, body.to, body.tip);
+  }
+
+  @Get('address-book')
+  listAddressBook(@Query('user_address') userAddress: string) {
+    return { entries: this.withdrawals.listAddressBook(userAddress) };
+  }
+
+  @Post('address-book')
+  addAddressBookEntry(@Body() body: CreateAddressBookEntryDto) {
+    return this.withdrawals.addAddressBookEntry(body.user_address, body.label, body.address);
+  }
+
+  @Delete('address-book/:entryId')
+  @HttpCode(204)
+  removeAddressBookEntry(@Param('entryId') entryId: string, @Query('user_address') userAddress: string) {
+    this.withdrawals.removeAddressBookEntry(userAddress, entryId);
+  }
+}
@@ -0,0 +1,16 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from '@nestjs/config';
+
+import { WithdrawalsService } from './withdrawals.service';
+import { WithdrawalsController } from './withdrawals.controller';
+import { BalancesModule } from '../balances/balances.module';
+import { SettlementModule } from '../settlement/settlement.module';
+import { KeetaModule } from '../keeta/keeta.module';
+
+@Module({
+  imports: [ConfigModule, BalancesModule, SettlementModule, KeetaModule],
+  providers: [WithdrawalsService],
+  controllers: [WithdrawalsController],
+  exports: [WithdrawalsService],
+})
+export class WithdrawalsModule {}
@@ -0,0 +1,106 @@
+import { BadRequestException, Injectable, Logger, NotFoundException } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+import { randomUUID } from 'crypto';
+
+import { BalancesService } from '../balances/balances.service';
+import { SettlementQueueService } from '../settlement/settlement-queue.service';
+import { KeetaAddressService } from '../keeta/keeta-address.service';
+
+export interface AddressBookEntry {
+  id: string;
+  user: string;
+  label: string;
+  address: string;
+  created_at: string;
+}
+
+/**
+ * Withdrawal intake: validates the destination against Keeta address rules
+ * before any funds move, debits the internal ledger, and hands the transfer
+ * to the settlement queue. The address book stores pre-validated
+ * destinations so repeat withdrawals cannot introduce a fresh typo.
+ */
+@Injectable()
+export class WithdrawalsService {
+  private readonly logger = new Logger(WithdrawalsService.name);
+  private readonly addressBook = new Map<string, AddressBookEntry[]>();
+
+  constructor(
+    private readonly config: ConfigService,
+    private readonly balances: BalancesService,
+    private readonly settlementQueue: SettlementQueueService,
+    private readonly addresses: KeetaAddressService,
+  ) {}
+
+  async requestWithdrawal(user: string, token: string, amount: number, to: string, tip?: number, relayedBy?: string) {
+    if (!(amount > 0)) {
+      throw new BadRequestException('amount must be positive');
+    }
+    const checkExistence = this.config.get<string>('WITHDRAWAL_VERIFY_DESTINATION') === 'true';
+    const validation = await this.addresses.assertValidDestination(to, { checkExistence });
+
+    // The tip is paid in the withdrawn token and debited with the principal
+    // so an urgent withdrawal cannot be queued without covering its fee.
+    this.balances.transaction((tx) => {
+      tx.debit(user, token, amount);
+      if (tip !== undefined && tip > 0) {
+        tx.debit(user, token, tip);
+      }
+    });
+    const op = this.settlementQueue.enqueue(
+      'withdraw',
+      {
+        user_address: user,
+        token,
+        amount: amount.toString(),
+        to,
+        ...(tip !== undefined && tip > 0 ? { tip: tip.toString() } : {}),
+        // Settlement workers charge network fees to this account instead of
+        // the user when a withdrawal arrives through the gasless relay.
+        ...(relayedBy ? { relayed_by: relayedBy } : {}),
+      },
+      tip,
+    );
+    this.logger.log(`Withdrawal ${op.id} queued: ${amount} ${token} from ${user} to ${to.substring(0, 20)}...${op.tip ? ` (tip ${op.tip})` : ''}`);
+    return { op_id: op.id, status: op.status, destination: validation, fee_paid: (tip ?? 0).toString() };
+  }
+
+  async addAddressBookEntry(user: string, label: string, address: string): Promise<AddressBookEntry> {
+    await this.addresses.assertValidDestination(address);
+    const entries = this.entriesFor(user);
+    if (entries.some((entry) => entry.address === address)) {
+      throw new BadRequestException(`Address is already in the address book for ${user}`);
+    }
+    const entry: AddressBookEntry = {
+      id: randomUUID(),
+      user,
+      label,
+      address,
+      created_at: new Date().toISOString(),
+    };
+    entries.push(entry);
+    return entry;
+  }
+
+  listAddressBook(user: string): AddressBookEntry[] {
+    return this.entriesFor(user);
+  }
+
+  removeAddressBookEntry(user: string, entryId: string): void {
+    const entries = this.entriesFor(user);
+    const index = entries.findIndex((entry) => entry.id === entryId);
+    if (index === -1) {
+      throw new NotFoundException(`Address book entry ${entryId} not found`);
+    }
+    entries.splice(index, 1);
+  }
+
+  private entriesFor(user: string): AddressBookEntry[] {
+    let entries = this.addressBook.get(user);
+    if (!entries) {
+      entries = [];
+      this.addressBook.set(user, entries);
+    }
+    return entries;
+  }
+}
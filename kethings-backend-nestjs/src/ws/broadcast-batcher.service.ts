@@ -0,0 +1,192 @@
+import { Injectable, Logger, OnModuleDestroy } from '@nestjs/common';
+import { ConfigService } from '@nestjs/config';
+
+export type ConflationMode = 'latest' | 'batch';
+
+export interface TopicMetrics {
+  topic: string;
+  window_ms: number;
+  mode: ConflationMode;
+  events_in: number;
+  messages_out: number;
+  conflated: number;
+  conflation_rate: string;
+}
+
+export interface BatcherMetrics {
+  topics: TopicMetrics[];
+  lag_disconnects: number;
+  max_buffered_bytes: number;
+}
+
+interface PendingChannel {
+  latest?: Record<string, unknown>;
+  batch: Record<string, unknown>[];
+  timer: ReturnType<typeof setTimeout>;
+  deliver: (data: Record<string, unknown>) => void;
+}
+
+interface TopicCounters {
+  eventsIn: number;
+  messagesOut: number;
+}
+
+/**
+ * Snapshot topics (orderbook, analytics) conflate to the latest payload —
+ * each message fully supersedes the last, so intermediate states carry no
+ * information. Tape-style topics (trades, pools, rfq) keep every event and
+ * flush them as one `batch` message. Executions are never batched: they are
+ * private, low-volume, and every report matters.
+ */
+const TOPIC_MODES: Record<string, ConflationMode> = {
+  orderbook: 'latest',
+  analytics: 'latest',
+  trades: 'batch',
+  pools: 'batch',
+  rfq: 'batch',
+};
+
+/** Default flush windows; topics absent here (window 0) send immediately. */
+const DEFAULT_WINDOWS_MS: Record<string, number> = {
+  orderbook: 50,
+  analytics: 250,
+};
+
+const DEFAULT_MAX_BUFFERED_BYTES = 1_048_576;
+
+/**
+ * Per-channel batching and conflation for the broadcast fan-out. Under heavy
+ * trading a busy book can emit hundreds of depth snapshots a second; sending
+ * each one per client overwhelms slow consumers and buys them nothing. Each
+ * channel buffers within its topic's window and flushes once per window —
+ * either the latest payload or the accumulated batch, by topic. Windows come
+ * from `WS_BATCH_WINDOWS_MS` (JSON, per topic, milliseconds; 0 disables),
+ * and per-topic conflation rates are exported for capacity monitoring.
+ */
+@Injectable()
+export class BroadcastBatcherService implements OnModuleDestroy {
+  private readonly logger = new Logger(BroadcastBatcherService.name);
+  private readonly pending = new Map<string, PendingChannel>();
+  private readonly counters = new Map<string, TopicCounters>();
+  private windows?: Record<string, number>;
+  private lagDisconnects = 0;
+
+  constructor(private readonly config: ConfigService) {}
+
+  /** Deliver now or buffer into the channel's window; `deliver` runs the actual fan-out. */
+  submit(channel: string, data: Record<string, unknown>, deliver: (data: Record<string, unknown>) => void): void {
+    const topic = channel.split(':', 1)[0];
+    const counter = this.countersFor(topic);
+    counter.eventsIn += 1;
+
+    const windowMs = this.windowFor(topic);
+    if (windowMs <= 0) {
+      counter.messagesOut += 1;
+      deliver(data);
+      return;
+    }
+
+    const existing = this.pending.get(channel);
+    if (existing) {
+      // The delivery callback is refreshed so the flush uses the most
+      // recent subscriber set, not the one captured at window start.
+      existing.deliver = deliver;
+      if (this.modeFor(topic) === 'latest') {
+        existing.latest = data;
+      } else {
+        existing.batch.push(data);
+      }
+      return;
+    }
+
+    const entry: PendingChannel = {
+      ...(this.modeFor(topic) === 'latest' ? { latest: data } : {}),
+      batch: this.modeFor(topic) === 'batch' ? [data] : [],
+      timer: setTimeout(() => this.flush(channel, topic), windowMs),
+      deliver,
+    };
+    this.pending.set(channel, entry);
+  }
+
+  /** True when a client's send buffer says it cannot keep up. */
+  lagExceeded(bufferedAmount: number): boolean {
+    return bufferedAmount > this.maxBufferedBytes();
+  }
+
+  recordLagDisconnect(): void {
+    this.lagDisconnects += 1;
+  }
+
+  metrics(): BatcherMetrics {
+    const topics: TopicMetrics[] = [];
+    for (const [topic, counter] of this.counters) {
+      const conflated = counter.eventsIn - counter.messagesOut;
+      topics.push({
+        topic,
+        window_ms: this.windowFor(topic),
+        mode: this.modeFor(topic),
+        events_in: counter.eventsIn,
+        messages_out: counter.messagesOut,
+        conflated,
+        conflation_rate: (counter.eventsIn > 0 ? conflated / counter.eventsIn : 0).toString(),
+      });
+    }
+    topics.sort((a, b) => a.topic.localeCompare(b.topic));
+    return { topics, lag_disconnects: this.lagDisconnects, max_buffered_bytes: this.maxBufferedBytes() };
+  }
+
+  onModuleDestroy(): void {
+    for (const entry of this.pending.values()) {
+      clearTimeout(entry.timer);
+    }
+    this.pending.clear();
+  }
+
+  private flush(channel: string, topic: string): void {
+    const entry = this.pending.get(channel);
+    if (!entry) return;
+    this.pending.delete(channel);
+    const counter = this.countersFor(topic);
+    counter.messagesOut += 1;
+    if (this.modeFor(topic) === 'latest') {
+      entry.deliver(entry.latest!);
+    } else {
+      entry.deliver(
+        entry.batch.length === 1 ? entry.batch[0] : { type: 'batch', count: entry.batch.length, events: entry.batch },
+      );
+    }
+  }
+
+  private modeFor(topic: string): ConflationMode {
+    return TOPIC_MODES[topic] ?? 'batch';
+  }
+
+  private windowFor(topic: string): number {
+    if (!this.windows) {
+      this.windows = { ...DEFAULT_WINDOWS_MS };
+      const raw = this.config.get<string>('WS_BATCH_WINDOWS_MS');
+      if (raw) {
+        try {
+          Object.assign(this.windows, JSON.parse(raw) as Record<string, number>);
+        } catch {
+          this.logger.warn('Failed to parse WS_BATCH_WINDOWS_MS JSON; using default windows');
+        }
+      }
+    }
+    const window = this.windows[topic];
+    return typeof window === 'number' && window > 0 ? window : 0;
+  }
+
+  private maxBufferedBytes(): number {
+    return Number(this.config.get<string>('WS_MAX_BUFFERED_BYTES')) || DEFAULT_MAX_BUFFERED_BYTES;
+  }
+
+  private countersFor(topic: string): TopicCounters {
+    let counter = this.counters.get(topic);
+    if (!counter) {
+      counter = { eventsIn: 0, messagesOut: 0 };
+      this.counters.set(topic, counter);
+    }
+    return counter;
+  }
+}
@@ -0,0 +1,69 @@
+import { Injectable } from '@nestjs/common';
+import { Subject, firstValueFrom } from 'rxjs';
+import { catchError, filter, map, take, timeout } from 'rxjs/operators';
+
+export interface StreamEvent {
+  seq: number;
+  channel: string;
+  at: string;
+  data: Record<string, unknown>;
+}
+
+const BUFFER_LIMIT = 5000;
+
+/**
+ * Broadcast hub behind both transports. The WS gateway publishes every
+ * channel message here before fanning out to sockets; the hub assigns a
+ * monotonic sequence number and keeps a bounded replay buffer so long-poll
+ * clients can resume from a cursor instead of missing events between polls.
+ */
+@Injectable()
+export class StreamHubService {
+  private readonly buffer: StreamEvent[] = [];
+  private readonly published$ = new Subject<StreamEvent>();
+  private seq = 0;
+
+  publish(channel: string, data: Record<string, unknown>): StreamEvent {
+    const event: StreamEvent = { seq: ++this.seq, channel, at: new Date().toISOString(), data };
+    this.buffer.push(event);
+    if (this.buffer.length > BUFFER_LIMIT) {
+      this.buffer.splice(0, this.buffer.length - BUFFER_LIMIT);
+    }
+    this.published$.next(event);
+    return event;
+  }
+
+  latestCursor(): number {
+    return this.seq;
+  }
+
+  /** Oldest sequence still replayable; cursors before this have been evicted. */
+  oldestCursor(): number {
+    return this.buffer.length > 0 ? this.buffer[0].seq : this.seq;
+  }
+
+  eventsAfter(cursor: number, channels: Set<string>): StreamEvent[] {
+    return this.buffer.filter((event) => event.seq > cursor && channels.has(event.channel));
+  }
+
+  /**
+   * Long-poll primitive: resolve with buffered events newer than the cursor,
+   * or hold the request until a matching event arrives or the timeout lapses
+   * (resolving empty so the client simply re-polls).
+   */
+  async wait(cursor: number, channels: Set<string>, timeoutMs: number): Promise<StreamEvent[]> {
+    const ready = this.eventsAfter(cursor, channels);
+    if (ready.length > 0) {
+      return ready;
+    }
+    return firstValueFrom(
+      this.published$.pipe(
+        filter((event) => channels.has(event.channel)),
+        take(1),
+        map((event) => [event]),
+        timeout({ first: timeoutMs }),
+        catchError(() => [[] as StreamEvent[]]),
+      ),
+    );
+  }
+}
@@ -0,0 +1,65 @@
+import { BadRequestException, Controller, Get, Query } from '@nestjs/common';
+
+import { StreamHubService } from './stream-hub.service';
+import { BroadcastBatcherService } from './broadcast-batcher.service';
+
+const DEFAULT_TIMEOUT_MS = 25_000;
+const MAX_TIMEOUT_MS = 55_000;
+
+/**
+ * Long-poll fallback for clients whose environment blocks WebSockets
+ * (embedded webviews, restrictive proxies). Same channels as the WS gateway;
+ * clients pass the cursor from the previous response to resume without gaps.
+ * A cursor older than the replay buffer gets `cursor_expired` so the client
+ * knows to resnapshot instead of silently missing events.
+ */
+@Controller('stream')
+export class StreamController {
+  constructor(
+    private readonly hub: StreamHubService,
+    private readonly batcher: BroadcastBatcherService,
+  ) {}
+
+  /** Conflation and slow-consumer metrics for the broadcast fan-out. */
+  @Get('metrics')
+  metrics() {
+    return this.batcher.metrics();
+  }
+
+  @Get()
+  async poll(
+    @Query('channels') channels?: string,
+    @Query('cursor') cursor?: string,
+    @Query('timeout_ms') timeoutMs?: string,
+  ) {
+    const channelSet = new Set(
+      (channels ?? '')
+        .split(',')
+        .map((channel) => channel.trim())
+        .filter(Boolean),
+    );
+    if (channelSet.size === 0) {
+      throw new BadRequestException('channels query parameter is required, e.g. channels=trades:KTA/USDT');
+    }
+
+    const from = cursor !== undefined ? Number(cursor) : this.hub.latestCursor();
+    if (!Number.isInteger(from) || from < 0) {
+      throw new BadRequestException(`cursor is not a non-negative integer: ${cursor}`);
+    }
+    if (from < this.hub.oldestCursor() && from < this.hub.latestCursor()) {
+      return {
+        cursor: this.hub.latestCursor().toString(),
+        cursor_expired: true,
+        events: [],
+      };
+    }
+
+    const waitMs = Math.min(Number(timeoutMs) || DEFAULT_TIMEOUT_MS, MAX_TIMEOUT_MS);
+    const events = await this.hub.wait(from, channelSet, waitMs);
+    const last = events.length > 0 ? events[events.length - 1].seq : from;
+    return {
+      cursor: last.toString(),
+      events: events.map((event) => ({ seq: event.seq, channel: event.channel, at: event.at, ...event.data })),
+    };
+  }
+}
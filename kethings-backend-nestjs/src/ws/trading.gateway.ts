@@ -0,0 +1,261 @@
+import { Logger } from '@nestjs/common';
+import {
+  MessageBody,
+  ConnectedSocket,
+  OnGatewayConnection,
+  OnGatewayDisconnect,
+  SubscribeMessage,
+  WebSocketGateway,
+} from '@nestjs/websockets';
+import type { WebSocket } from 'ws';
+
+import { EngineService, EngineEvent, OrderSide } from '../engine/engine.service';
+import { ExecutionReportsService, ExecutionReport } from '../engine/execution-reports.service';
+import { MarketsService } from '../engine/markets.service';
+import { AuthService } from '../auth/auth.service';
+import { CURRENT_API_VERSION, SUPPORTED_API_VERSIONS } from '../common/api-version.middleware';
+import { PoolsService, PoolEvent } from '../pools/pools.service';
+import { RfqService, RfqEvent } from '../rfq/rfq.service';
+import { AnalyticsService, AnalyticsEvent } from '../analytics/analytics.service';
+import { StreamHubService } from './stream-hub.service';
+import { BroadcastBatcherService } from './broadcast-batcher.service';
+
+const DEPTH_LEVELS = 20;
+
+interface SubscribePayload {
+  channel: string;
+  /** Viewer identity for token-gated markets; the session token wins. */
+  user_address?: string;
+  session_token?: string;
+}
+
+interface CancelAllPayload {
+  user_address: string;
+  market?: string;
+  side?: OrderSide;
+}
+
+/**
+ * Streams live engine state over WebSocket. Channels follow the
+ * `{topic}:{market}` convention: `orderbook:KTA/USDT` pushes a depth snapshot
+ * on subscribe and after every book change, `trades:KTA/USDT` pushes each
+ * fill as it happens, and `pools:{pool_id}` pushes pool lifecycle events
+ * (created, reserves updated, paused/unpaused, swap confirmed), and
+ * `rfq:{pair}` pushes RFQ order lifecycle events (created, cancelled,
+ * declarations, fills) so takers do not have to poll the REST listing, and
+ * `analytics:{market}` pushes derived imbalance/micro-price/volatility
+ * metrics whenever the book or tape changes. `executions:{user_address}`
+ * pushes FIX-style execution reports and requires a verified session for
+ * that address. A
+ * `cancel_all` message gives traders the panic button without an HTTP round
+ * trip. High-frequency channels are conflated per-channel before fan-out
+ * (see BroadcastBatcherService); clients that stop reading are disconnected
+ * once their send buffer exceeds the lag threshold.
+ */
+@WebSocketGateway({ path: '/ws' })
+export class TradingGateway implements OnGatewayConnection, OnGatewayDisconnect {
+  private readonly logger = new Logger(TradingGateway.name);
+  private readonly subscriptions = new Map<WebSocket, Set<string>>();
+
+  constructor(
+    private readonly engine: EngineService,
+    private readonly execReports: ExecutionReportsService,
+    private readonly pools: PoolsService,
+    private readonly rfq: RfqService,
+    private readonly analytics: AnalyticsService,
+    private readonly hub: StreamHubService,
+    private readonly batcher: BroadcastBatcherService,
+    private readonly markets: MarketsService,
+    private readonly auth: AuthService,
+  ) {
+    this.engine.events$.subscribe((event) => this.onEngineEvent(event));
+    this.pools.events$.subscribe((event) => this.onPoolEvent(event));
+    this.rfq.events$.subscribe((event) => this.onRfqEvent(event));
+    this.analytics.events$.subscribe((event) => this.onAnalyticsEvent(event));
+    this.execReports.events$.subscribe((report) => this.onExecutionReport(report));
+  }
+
+  handleConnection(client: WebSocket): void {
+    this.subscriptions.set(client, new Set());
+  }
+
+  handleDisconnect(client: WebSocket): void {
+    this.subscriptions.delete(client);
+  }
+
+  /**
+   * Version negotiation hello. Clients should send this first; a missing
+   * hello means the current version. Unsupported versions get an actionable
+   * error listing what the server speaks.
+   */
+  @SubscribeMessage('hello')
+  hello(@MessageBody() payload: { version?: string }) {
+    const version = payload?.version ?? CURRENT_API_VERSION;
+    if (!SUPPORTED_API_VERSIONS.includes(version as (typeof SUPPORTED_API_VERSIONS)[number])) {
+      return {
+        event: 'error',
+        data: {
+          code: 'UNSUPPORTED_API_VERSION',
+          message: `WS protocol version ${version} is not supported; supported versions: ${SUPPORTED_API_VERSIONS.join(', ')}`,
+          supported_versions: SUPPORTED_API_VERSIONS,
+        },
+      };
+    }
+    return { event: 'hello_ack', data: { version } };
+  }
+
+  @SubscribeMessage('subscribe')
+  subscribe(@ConnectedSocket() client: WebSocket, @MessageBody() payload: SubscribePayload) {
+    const channels = this.subscriptions.get(client);
+    if (!channels || !payload?.channel) {
+      return { event: 'error', data: { message: 'channel is required' } };
+    }
+    // Execution reports are private: only a verified session for the same
+    // address may subscribe, a bare user_address claim is not enough.
+    if (payload.channel.startsWith('executions:')) {
+      const address = payload.channel.slice('executions:'.length);
+      const session = payload.session_token ? this.auth.getSession(payload.session_token) : undefined;
+      if (!session || session.user_address !== address) {
+        return {
+          event: 'error',
+          data: {
+            code: 'EXECUTIONS_FORBIDDEN',
+            message: 'Subscribing to an executions channel requires a verified session for that address',
+          },
+        };
+      }
+    }
+    const market = this.marketOf(payload.channel);
+    if (market !== undefined && !this.markets.canView(market, this.viewer(payload))) {
+      return {
+        event: 'error',
+        data: {
+          code: 'MARKET_RESTRICTED',
+          message: `Market ${market} is restricted; holding the gating token is required to subscribe`,
+        },
+      };
+    }
+    channels.add(payload.channel);
+    if (payload.channel.startsWith('orderbook:')) {
+      const market = payload.channel.slice('orderbook:'.length);
+      this.send(client, payload.channel, this.depthSnapshot(market));
+    }
+    return { event: 'subscribed', data: { channel: payload.channel } };
+  }
+
+  @SubscribeMessage('unsubscribe')
+  unsubscribe(@ConnectedSocket() client: WebSocket, @MessageBody() payload: SubscribePayload) {
+    this.subscriptions.get(client)?.delete(payload?.channel);
+    return { event: 'unsubscribed', data: { channel: payload?.channel } };
+  }
+
+  @SubscribeMessage('cancel_all')
+  cancelAll(@MessageBody() payload: CancelAllPayload) {
+    if (!payload?.user_address) {
+      return { event: 'error', data: { message: 'user_address is required' } };
+    }
+    const result = this.engine.cancelAllOrders(payload.user_address, payload.market, payload.side);
+    return { event: 'cancel_all_done', data: result };
+  }
+
+  private onEngineEvent(event: EngineEvent): void {
+    if (event.type === 'trade') {
+      this.broadcast(`trades:${event.market}`, {
+        type: 'trade',
+        price: event.price.toString(),
+        quantity: event.quantity.toString(),
+        taker_side: event.taker_side,
+        at: event.at,
+      });
+      return;
+    }
+    this.broadcast(`orderbook:${event.market}`, this.depthSnapshot(event.market));
+  }
+
+  private onPoolEvent(event: PoolEvent): void {
+    this.broadcast(`pools:${event.pool_id}`, { type: event.type, at: event.at, ...event.data });
+  }
+
+  private onRfqEvent(event: RfqEvent): void {
+    this.broadcast(`rfq:${event.pair}`, { type: event.type, at: event.at, ...event.data });
+  }
+
+  private onAnalyticsEvent(event: AnalyticsEvent): void {
+    this.broadcast(`analytics:${event.market}`, { type: 'analytics', ...event.metrics });
+  }
+
+  private onExecutionReport(report: ExecutionReport): void {
+    // Deliberately not published to the hub: the long-poll endpoint is
+    // unauthenticated and execution reports are private to the owner.
+    const channel = `executions:${report.user_address}`;
+    for (const [client, channels] of this.subscriptions) {
+      if (channels.has(channel)) {
+        this.send(client, channel, { type: 'execution_report', ...report });
+      }
+    }
+  }
+
+  /** Market referenced by a channel name, for channels carrying market data. */
+  private marketOf(channel: string): string | undefined {
+    if (channel.startsWith('orderbook:')) return channel.slice('orderbook:'.length);
+    if (channel.startsWith('trades:')) return channel.slice('trades:'.length);
+    if (channel.startsWith('analytics:')) return channel.slice('analytics:'.length);
+    return undefined;
+  }
+
+  private viewer(payload: SubscribePayload): string | undefined {
+    if (payload.session_token) {
+      const session = this.auth.getSession(payload.session_token);
+      if (session) {
+        return session.user_address;
+      }
+    }
+    return payload.user_address;
+  }
+
+  private depthSnapshot(market: string): Record<string, unknown> {
+    const book = this.engine.getBook(market);
+    const level = (orders: Array<{ price: number; remaining: number }>) => {
+      const levels = new Map<number, number>();
+      for (const order of orders.slice(0, DEPTH_LEVELS * 4)) {
+        levels.set(order.price, (levels.get(order.price) ?? 0) + order.remaining);
+      }
+      return Array.from(levels.entries())
+        .slice(0, DEPTH_LEVELS)
+        .map(([price, quantity]) => [price.toString(), quantity.toString()]);
+    };
+    return { type: 'snapshot', market, bids: level(book.bids), asks: level(book.asks) };
+  }
+
+  private broadcast(channel: string, data: Record<string, unknown>): void {
+    // Batching and conflation happen before the hub as well, so long-poll
+    // clients see the same (conflated) stream the sockets do.
+    this.batcher.submit(channel, data, (payload) => this.deliver(channel, payload));
+  }
+
+  private deliver(channel: string, data: Record<string, unknown>): void {
+    this.hub.publish(channel, data);
+    for (const [client, channels] of this.subscriptions) {
+      if (channels.has(channel)) {
+        this.send(client, channel, data);
+      }
+    }
+  }
+
+  private send(client: WebSocket, channel: string, data: Record<string, unknown>): void {
+    // A client whose send buffer keeps growing is not reading; dropping it
+    // protects server memory and the batching windows of everyone else.
+    if (this.batcher.lagExceeded(client.bufferedAmount)) {
+      this.batcher.recordLagDisconnect();
+      this.logger.warn(`Disconnecting lagging client (${client.bufferedAmount} bytes buffered)`);
+      this.subscriptions.delete(client);
+      client.terminate();
+      return;
+    }
+    try {
+      client.send(JSON.stringify({ channel, ...data }));
+    } catch (error) {
+      this.logger.warn(`Failed to send on channel ${channel}`, error);
+    }
+  }
+}
@@ -0,0 +1,18 @@
+import { Module } from '@nestjs/common';
+import { TradingGateway } from './trading.gateway';
+import { StreamHubService } from './stream-hub.service';
+import { BroadcastBatcherService } from './broadcast-batcher.service';
+import { StreamController } from './stream.controller';
+import { EngineModule } from '../engine/engine.module';
+import { PoolsModule } from '../pools/pools.module';
+import { RfqModule } from '../rfq/rfq.module';
+import { AuthModule } from '../auth/auth.module';
+import { AnalyticsModule } from '../analytics/analytics.module';
+
+@Module({
+  imports: [EngineModule, PoolsModule, RfqModule, AuthModule, AnalyticsModule],
+  providers: [TradingGateway, StreamHubService, BroadcastBatcherService],
+  controllers: [StreamController],
+  exports: [TradingGateway, StreamHubService],
+})
+export class WsModule {}
@@ -0,0 +1,244 @@
+/**
+ * End-to-end integration harness. Boots the full Nest application in-process
+ * with a scriptable mock in place of the Keeta SDK, then drives the two core
+ * flows over real HTTP:
+ *
+ *   1. deposit -> limit order -> market order match -> withdraw -> confirm
+ *   2. pool create -> add liquidity -> swap -> remove liquidity
+ *
+ * asserting ledger, settlement and reconciliation state at each step.
+ *
+ * Build first, then run:
+ *   npm run build && node test-integration.js
+ */
+const assert = require('assert');
+const { mkdtempSync } = require('fs');
+const { tmpdir } = require('os');
+const { join } = require('path');
+
+// Keep the settlement journal out of the working tree so replays start clean.
+process.env.SETTLEMENT_JOURNAL_PATH = join(mkdtempSync(join(tmpdir(), 'keythings-itest-')), 'journal.log');
+process.env.RATE_LIMIT_ENABLED = 'false';
+
+const { Test } = require('@nestjs/testing');
+const { ValidationPipe } = require('@nestjs/common');
+const { WsAdapter } = require('@nestjs/platform-ws');
+const { AppModule } = require('./dist/app.module');
+const { KeetaSdkService } = require('./dist/keeta/keeta-sdk.service');
+const { BalancesService } = require('./dist/balances/balances.service');
+const { SettlementQueueService } = require('./dist/settlement/settlement-queue.service');
+const { SolvencyService } = require('./dist/reconciliation/solvency.service');
+const { PoolsService } = require('./dist/pools/pools.service');
+
+/**
+ * Scriptable stand-in for KeetaSdkService. Tests assign on-chain balances per
+ * account; every read path the app exercises (allBalances, state, ACL
+ * listing) answers from that script instead of the network.
+ */
+class MockKeetaSdk {
+  constructor() {
+    /** account -> [{ token, balance }] */
+    this.onchainBalances = new Map();
+    /** account -> ACL entries as the SDK would return them */
+    this.acls = new Map();
+  }
+
+  setOnchainBalances(account, balances) {
+    this.onchainBalances.set(account, balances);
+  }
+
+  resolveNetwork() {
+    return 'test';
+  }
+
+  async accountFromPublicKey(publicKey) {
+    return { publicKeyString: publicKey };
+  }
+
+  async accountFromSeed(seed) {
+    return { publicKeyString: `seed:${seed}` };
+  }
+
+  async getClient() {
+    return this.clientFor('(signer)');
+  }
+
+  getReadClient(publicKey) {
+    return Promise.resolve(this.clientFor(publicKey));
+  }
+
+  clientFor(account) {
+    const self = this;
+    return {
+      async allBalances() {
+        return (self.onchainBalances.get(account) ?? []).map((entry) => ({
+          token: { publicKeyString: entry.token },
+          balance: BigInt(entry.balance),
+        }));
+      },
+      async state() {
+        return { balance: 0n };
+      },
+      async listACLsByEntity({ account: entity }) {
+        return self.acls.get(entity?.publicKeyString ?? '') ?? [];
+      },
+    };
+  }
+}
+
+const KEETA_ADDR = (suffix) => `keeta_${suffix.padEnd(61, '0')}`;
+
+async function main() {
+  const mockKeeta = new MockKeetaSdk();
+  const moduleRef = await Test.createTestingModule({ imports: [AppModule] })
+    .overrideProvider(KeetaSdkService)
+    .useValue(mockKeeta)
+    .compile();
+
+  const app = moduleRef.createNestApplication();
+  app.setGlobalPrefix('api');
+  app.useWebSocketAdapter(new WsAdapter(app));
+  app.useGlobalPipes(new ValidationPipe({ transform: true, whitelist: true, forbidNonWhitelisted: true }));
+  await app.listen(0);
+  const base = `http://127.0.0.1:${app.getHttpServer().address().port}/api`;
+
+  const api = async (method, path, body) => {
+    const response = await fetch(`${base}${path}`, {
+      method,
+      headers: { 'Content-Type': 'application/json' },
+      body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    const text = await response.text();
+    const json = text ? JSON.parse(text) : undefined;
+    if (!response.ok) {
+      throw new Error(`${method} ${path} -> ${response.status}: ${text}`);
+    }
+    return json;
+  };
+
+  const balances = app.get(BalancesService);
+  const queue = app.get(SettlementQueueService);
+  const pools = app.get(PoolsService);
+  const solvency = app.get(SolvencyService);
+
+  const alice = 'alice';
+  const bob = 'bob';
+
+  // ---- Flow 1: deposit -> order -> match -> withdraw --------------------
+  console.log('Flow 1: deposit -> order -> match -> withdraw');
+
+  // "Deposits": what the on-chain deposit observer would credit.
+  balances.credit(alice, 'USDT', 1000);
+  balances.credit(bob, 'KTA', 100);
+
+  const sell = await api('POST', '/engine/orders', {
+    user_address: bob,
+    market: 'KTA/USDT',
+    side: 'sell',
+    order_type: 'limit',
+    price: 2,
+    quantity: 10,
+  });
+  assert.strictEqual(sell.status, 'open', 'resting sell should be open');
+  assert.strictEqual(balances.getBalance(bob, 'KTA').reserved, 10, 'sell should reserve base');
+
+  const buy = await api('POST', '/engine/orders', {
+    user_address: alice,
+    market: 'KTA/USDT',
+    side: 'buy',
+    order_type: 'market',
+    quantity: 10,
+  });
+  assert.strictEqual(buy.filled_quantity, '10', 'market buy should fill fully');
+  assert.strictEqual(buy.average_price, '2', 'fill should execute at the resting price');
+
+  assert.strictEqual(balances.getBalance(alice, 'KTA').available, 10, 'buyer receives base');
+  assert.strictEqual(balances.getBalance(alice, 'USDT').available, 980, 'buyer pays quote');
+  assert.strictEqual(balances.getBalance(bob, 'USDT').available, 20, 'seller receives quote');
+  assert.strictEqual(balances.getBalance(bob, 'KTA').reserved, 0, 'reservation fully consumed');
+
+  const trades = await api('GET', '/trades/KTA/USDT');
+  assert.strictEqual(trades.trades.length, 1, 'trade tape records the match');
+
+  const withdrawal = await api('POST', '/withdrawals', {
+    user_address: bob,
+    token: 'USDT',
+    amount: 20,
+    to: KEETA_ADDR('bobexit'),
+  });
+  assert.strictEqual(balances.getBalance(bob, 'USDT').available, 0, 'withdrawal debits the ledger');
+
+  const pendingOps = queue.listOps('pending');
+  assert.strictEqual(pendingOps.length, 1, 'withdrawal sits in the settlement queue');
+  assert.strictEqual(pendingOps[0].id, withdrawal.op_id);
+
+  await api('POST', `/settlement/queue/${withdrawal.op_id}/confirm`, { tx_ref: 'tx_withdraw_1' });
+  const settled = await api('GET', `/settlements/${withdrawal.op_id}`);
+  assert.strictEqual(settled.status, 'complete', 'confirmed op reads back complete');
+  assert.strictEqual(settled.tx_ref, 'tx_withdraw_1', 'confirmation records the tx reference');
+
+  // ---- Flow 2: pool create -> add -> swap -> remove ---------------------
+  console.log('Flow 2: pool create -> add -> swap -> remove');
+
+  const storage = KEETA_ADDR('poolstorage');
+  const pool = await api('POST', '/pools/create', {
+    token_a: 'KTA',
+    token_b: 'USDT',
+    reserve_a: 1000,
+    reserve_b: 2000,
+    storage_account: storage,
+  });
+
+  balances.credit(alice, 'KTA', 100);
+  const position = await api('POST', '/pools/positions', {
+    pool_id: pool.id,
+    owner: alice,
+    amount_a: 100,
+    amount_b: 200,
+  });
+  const afterAdd = pools.getPool(pool.id);
+  assert.strictEqual(afterAdd.reserveA, 1100, 'add liquidity grows reserve A');
+  assert.strictEqual(afterAdd.reserveB, 2200, 'add liquidity grows reserve B');
+  assert.ok(balances.getBalance(alice, afterAdd.lpToken).available > 0, 'LP tokens minted to provider');
+  assert.ok(afterAdd.pendingSettlement, 'pool deposit awaits settlement confirmation');
+
+  const poolOps = queue.listByWallet(alice, pool.id);
+  assert.strictEqual(poolOps.length, 1, 'pool deposit op tracked for the wallet');
+  await api('POST', `/settlement/queue/${poolOps[0].id}/confirm`, { tx_ref: 'tx_pool_deposit_1' });
+  assert.ok(!pools.getPool(pool.id).pendingSettlement, 'confirmation clears pending_settlement');
+  assert.deepStrictEqual(pools.getPool(pool.id).settlementTxRefs, ['tx_pool_deposit_1']);
+
+  const swap = await api('POST', '/pools/route/swap', {
+    user_address: bob,
+    token_in: 'KTA',
+    token_out: 'USDT',
+    amount_in: 10,
+  });
+  assert.ok(Number(swap.amount_out) > 0, 'swap produces output');
+  assert.ok(pools.getPool(pool.id).reserveA > 1100, 'swap input lands in reserves');
+
+  const closed = await api('DELETE', `/pools/positions/${position.id}?owner=${alice}`);
+  assert.ok(Number(closed.amount_a) > 0 && Number(closed.amount_b) > 0, 'close returns both sides');
+  assert.strictEqual(balances.getBalance(alice, afterAdd.lpToken).available, 0, 'LP tokens burned on close');
+
+  // ---- Reconciliation: mock chain covers all internal claims ------------
+  // Liabilities span user balances plus pool reserves, so script the storage
+  // account holdings comfortably above both.
+  mockKeeta.setOnchainBalances(storage, [
+    { token: 'KTA', balance: 1_000_000 },
+    { token: 'USDT', balance: 1_000_000 },
+  ]);
+  const report = await solvency.generateReport();
+  assert.strictEqual(report.errors.length, 0, 'solvency report reads the mock chain cleanly');
+  for (const line of report.lines) {
+    assert.ok(Number(line.surplus) >= 0, `no deficit for ${line.token}: surplus ${line.surplus}`);
+  }
+
+  await app.close();
+  console.log('All integration flows passed.');
+}
+
+main().catch((error) => {
+  console.error('Integration harness failed:', error);
+  process.exit(1);
+});
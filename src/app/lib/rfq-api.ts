@@ -126,6 +126,8 @@ async function apiRequest<T>(path: string, init?: RequestInit): Promise<T> {
       ...init,
       headers: {
         'Content-Type': 'application/json',
+        // Correlate frontend calls with backend log lines.
+        'x-request-id': typeof crypto !== 'undefined' && crypto.randomUUID ? crypto.randomUUID() : `${Date.now()}`,
         ...(init?.headers ?? {}),
       },
     });
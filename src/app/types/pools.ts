@@ -1,3 +1,11 @@
+export interface TokenDisplayMetadata {
+  decimals: number;
+  display_decimals: number;
+  symbol_position: 'prefix' | 'suffix';
+  thousands_separator: string;
+  decimal_separator: string;
+}
+
 export interface PoolInfo {
   id: string;
   token_a: string;
@@ -11,6 +19,8 @@ export interface PoolInfo {
   storage_account: string;
   is_paused: boolean;
   pending_settlement: boolean;
+  token_a_display?: TokenDisplayMetadata;
+  token_b_display?: TokenDisplayMetadata;
 }
 
 export interface QuoteResponse {
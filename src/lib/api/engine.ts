@@ -0,0 +1,185 @@
+const ENGINE_API_BASE_URL = process.env.NEXT_PUBLIC_ENGINE_API_URL || 'http://localhost:8080/api';
+const ENGINE_WS_URL = process.env.NEXT_PUBLIC_ENGINE_WS_URL || 'ws://localhost:8080/ws';
+const REQUEST_TIMEOUT_MS = 10000;
+
+// Wire types mirror the backend's snake_case JSON with numerics as strings.
+export type OrderSide = 'buy' | 'sell';
+export type OrderType = 'limit' | 'market';
+export type OrderStatus = 'scheduled' | 'open' | 'partially_filled' | 'filled' | 'cancelled';
+
+export interface PlaceOrderRequest {
+  user_address: string;
+  market: string;
+  side: OrderSide;
+  order_type: OrderType;
+  quantity: number;
+  price?: number;
+  max_slippage?: number;
+  max_quote_spend?: number;
+  activate_at?: string;
+}
+
+export interface OrderResponse {
+  order_id: string;
+  user_address: string;
+  market: string;
+  side: OrderSide;
+  price: string;
+  quantity: string;
+  remaining: string;
+  filled_quantity: string;
+  status: OrderStatus;
+  created_at: string;
+  activate_at?: string;
+}
+
+export interface BookResponse {
+  market: string;
+  bids: Array<[string, string]>;
+  asks: Array<[string, string]>;
+}
+
+export interface DepthLevel {
+  price: string;
+  quantity: string;
+}
+
+export interface TickerResponse {
+  market: string;
+  last_price?: string;
+  best_bid?: string;
+  best_ask?: string;
+  spread?: string;
+  spread_bps?: string;
+  bid_depth: DepthLevel[];
+  ask_depth: DepthLevel[];
+  liquidity_score: string;
+}
+
+interface EngineRequestOptions extends RequestInit {
+  query?: Record<string, string | number | undefined>;
+}
+
+async function engineRequest<T = unknown>(path: string, options: EngineRequestOptions = {}): Promise<T> {
+  const { query, headers, ...init } = options;
+  const base = ENGINE_API_BASE_URL.endsWith('/') ? ENGINE_API_BASE_URL : `${ENGINE_API_BASE_URL}/`;
+  const url = new URL(path.replace(/^\//, ''), base);
+
+  if (query) {
+    Object.entries(query).forEach(([key, value]) => {
+      if (value !== undefined && value !== null) {
+        url.searchParams.set(key, String(value));
+      }
+    });
+  }
+
+  const controller = new AbortController();
+  const timeout = setTimeout(() => controller.abort(), REQUEST_TIMEOUT_MS);
+
+  try {
+    const response = await fetch(url, {
+      ...init,
+      headers: {
+        'Content-Type': 'application/json',
+        ...headers,
+      },
+      signal: controller.signal,
+    });
+
+    if (!response.ok) {
+      let message = response.statusText || 'An error occurred';
+      try {
+        const errorBody = await response.json();
+        if (typeof errorBody?.message === 'string') {
+          message = errorBody.message;
+        }
+      } catch {
+        // Fall back to status text when the error body is not JSON
+      }
+      throw new Error(message);
+    }
+
+    if (response.status === 204) {
+      return undefined as T;
+    }
+    return (await response.json()) as T;
+  } catch (error) {
+    if (error instanceof Error && error.name === 'AbortError') {
+      throw new Error('Request timed out');
+    }
+    throw error instanceof Error ? error : new Error('An error occurred');
+  } finally {
+    clearTimeout(timeout);
+  }
+}
+
+// Engine endpoints — one typed method per route, shared by app code and bots
+export const engineApi = {
+  placeOrder: (order: PlaceOrderRequest) =>
+    engineRequest<OrderResponse>('/engine/orders', {
+      method: 'POST',
+      body: JSON.stringify(order),
+    }),
+  getBook: (base: string, quote: string) =>
+    engineRequest<BookResponse>(`/engine/book/${base}/${quote}`),
+  getTicker: (base: string, quote: string) =>
+    engineRequest<TickerResponse>(`/engine/ticker/${base}/${quote}`),
+  getOrder: (orderId: string) => engineRequest<OrderResponse>(`/orders/${orderId}`),
+  listOrders: (userAddress: string, market?: string, status?: OrderStatus) =>
+    engineRequest<{ orders: OrderResponse[] }>('/orders', {
+      query: { user_address: userAddress, market, status },
+    }),
+  cancelOrder: (orderId: string, userAddress: string) =>
+    engineRequest<OrderResponse>(`/engine/orders/${orderId}`, {
+      method: 'DELETE',
+      query: { user_address: userAddress },
+    }),
+};
+
+export interface EngineChannelMessage {
+  channel: string;
+  [key: string]: unknown;
+}
+
+/**
+ * Subscribe to engine WS channels (`orderbook:{market}`, `trades:{market}`,
+ * `pools:{pool_id}`, `rfq:{pair}`). Returns an unsubscribe function that
+ * also closes the socket; messages for the requested channels are delivered
+ * to the handler already parsed.
+ */
+export function subscribeEngineChannels(
+  channels: string[],
+  onMessage: (message: EngineChannelMessage) => void,
+  onError?: (error: Event) => void,
+): () => void {
+  const socket = new WebSocket(ENGINE_WS_URL);
+  const wanted = new Set(channels);
+
+  socket.onopen = () => {
+    channels.forEach((channel) => {
+      socket.send(JSON.stringify({ event: 'subscribe', data: { channel } }));
+    });
+  };
+  socket.onmessage = (event) => {
+    try {
+      const message = JSON.parse(event.data as string) as EngineChannelMessage;
+      if (typeof message.channel === 'string' && wanted.has(message.channel)) {
+        onMessage(message);
+      }
+    } catch {
+      // Ignore malformed frames
+    }
+  };
+  if (onError) {
+    socket.onerror = onError;
+  }
+
+  return () => {
+    wanted.forEach((channel) => {
+      if (socket.readyState === WebSocket.OPEN) {
+        socket.send(JSON.stringify({ event: 'unsubscribe', data: { channel } }));
+      }
+    });
+    socket.close();
+  };
+}